@@ -15,13 +15,95 @@
 // limitations under the License.
 
 use std::env;
+use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
 
+/// Bindings file from the `bindings` folder selected through the `prebuilt-*` features, if any
+const PREBUILT_BINDINGS: Option<&str> = if cfg!(feature = "prebuilt-0_14_3") {
+    Some("dss_capi_0_14_3.rs")
+} else {
+    None
+};
+
 fn main() {
     let pwd_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
-    let inc_path = Path::new(&*pwd_dir).join("./dss_capi/include");
-    let lib_path = Path::new(&*pwd_dir).join("./dss_capi/lib/linux_x64");
+    println!("cargo:rerun-if-env-changed=DSS_CAPI_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=DSS_CAPI_INCLUDE_DIR");
+    println!("cargo:rerun-if-env-changed=ALTDSS_UPDATE_BINDINGS");
+    println!("cargo:rerun-if-env-changed=ALTDSS_SCHEMA");
+
+    let inc_paths = find_dss_capi(Path::new(&*pwd_dir));
+    let bindings = match PREBUILT_BINDINGS {
+        Some(file_name) => {
+            let prebuilt_path = Path::new(&*pwd_dir).join("bindings").join(file_name);
+            println!("cargo:rerun-if-changed={}", prebuilt_path.to_str().unwrap());
+            fs::read_to_string(&prebuilt_path).unwrap_or_else(|_| panic!(
+                "Couldn't read the prebuilt bindings from {}; use scripts/update_bindings.sh to generate them",
+                prebuilt_path.to_str().unwrap()
+            ))
+        },
+        None => {
+            let bindings = generate_bindings(&inc_paths);
+            // Used by the maintainers (through scripts/update_bindings.sh) to refresh the prebuilt bindings
+            if env::var_os("ALTDSS_UPDATE_BINDINGS").is_some() {
+                let version = env::var("CARGO_PKG_VERSION").unwrap().replace('.', "_");
+                let prebuilt_path = Path::new(&*pwd_dir).join("bindings").join(format!("dss_capi_{}.rs", version));
+                fs::write(prebuilt_path, &bindings).expect("Couldn't write the prebuilt bindings!");
+            }
+            bindings
+        },
+    };
+
+    // Write the bindings to the $OUT_DIR/bindings.rs file.
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    #[cfg(not(feature = "runtime-loading"))]
+    fs::write(out_path.join("bindings.rs"), bindings)
+        .expect("Couldn't write bindings!");
+
+    #[cfg(feature = "runtime-loading")]
+    fs::write(out_path.join("bindings.rs"), runtime_loading_bindings(&bindings))
+        .expect("Couldn't write bindings!");
+
+    #[cfg(feature = "schema-types")]
+    fs::write(out_path.join("schema_types.rs"), schema_types(Path::new(&*pwd_dir)))
+        .expect("Couldn't write the schema types!");
+}
+
+/// Locates the DSS C-API library, emitting the linker directives (unless the library
+/// is loaded at runtime), and returns the include paths for the headers.
+///
+/// The library is searched in this order:
+/// - the folder from the `DSS_CAPI_LIB_DIR` environment variable;
+/// - the `dss_capi` package, through pkg-config;
+/// - the `dss_capi` folder from a release archive, extracted in the crate folder.
+///
+/// `DSS_CAPI_INCLUDE_DIR` can be used to override the include path in all cases.
+fn find_dss_capi(pwd_dir: &Path) -> Vec<PathBuf> {
+    let inc_override = env::var_os("DSS_CAPI_INCLUDE_DIR").map(PathBuf::from);
+    if let Some(lib_path) = env::var_os("DSS_CAPI_LIB_DIR") {
+        let lib_path = PathBuf::from(lib_path);
+        link_dss_capi(&lib_path);
+        // Release archives place the headers in `include`, side by side with `lib`
+        let inc_path = lib_path.join("../../include");
+        return vec![inc_override.unwrap_or(inc_path)];
+    }
+
+    let pkg_config_result = pkg_config::Config::new()
+        .cargo_metadata(!cfg!(feature = "runtime-loading"))
+        .probe("dss_capi");
+    if let Ok(library) = pkg_config_result {
+        return inc_override.into_iter().chain(library.include_paths).collect();
+    }
+
+    let dss_capi_path = pwd_dir.join("./dss_capi");
+    link_dss_capi(&dss_capi_path.join("lib/linux_x64"));
+    vec![inc_override.unwrap_or(dss_capi_path.join("include"))]
+}
+
+#[cfg(not(feature = "runtime-loading"))]
+fn link_dss_capi(lib_path: &Path) {
     let profile = env::var("PROFILE").unwrap();
 
     println!("cargo:rustc-link-arg=-Wl,-rpath={}", lib_path.to_str().unwrap());
@@ -31,20 +113,249 @@ fn main() {
         _ => println!("cargo:rustc-link-lib=dylib=dss_capi")
     }
     println!("cargo:rustc-link-search=native={}", lib_path.to_str().unwrap());
+}
+
+// When the library is loaded at runtime, there is nothing to link to
+#[cfg(feature = "runtime-loading")]
+fn link_dss_capi(_lib_path: &Path) {
+}
+
+#[cfg(feature = "bindgen")]
+fn generate_bindings(inc_paths: &[PathBuf]) -> String {
     // println!("cargo:rerun-if-changed=src/dss_capi_wrapper.h");
 
     // https://rust-lang.github.io/rust-bindgen/tutorial-3.html
-    let bindings = bindgen::Builder::default()
-        .clang_arg(format!("-I{}", inc_path.to_str().unwrap()))
+    bindgen::Builder::default()
+        .clang_args(inc_paths.iter().map(|inc_path| format!("-I{}", inc_path.to_str().unwrap())))
         .header_contents("dss_capi_wrapper.h", "#include \"dss_capi_ctx.h\"")
         // .parse_callbacks(Box::new(bindgen::CargoCallbacks))
         .prepend_enum_name(false)
         .generate()
-        .expect("Unable to generate bindings");
+        .expect("Unable to generate bindings")
+        .to_string()
+}
 
-    // Write the bindings to the $OUT_DIR/bindings.rs file.
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    bindings
-        .write_to_file(out_path.join("bindings.rs"))
-        .expect("Couldn't write bindings!");    
+#[cfg(not(feature = "bindgen"))]
+fn generate_bindings(_inc_paths: &[PathBuf]) -> String {
+    panic!("No bindings available: enable either the `bindgen` feature or one of the `prebuilt-*` features");
+}
+
+/// Rewrites the `extern "C"` declarations from the bindings as a table of function
+/// pointers, filled from the library loaded at runtime, plus a free function for
+/// each entry. The free functions keep the same signatures as the linked version,
+/// so `dss_capi::ctx_*` can be used in the same way for both modes.
+///
+/// Extern statics (or any other extern item) cannot be resolved this way, so these
+/// stop the build instead of being left out of the bindings.
+#[cfg(feature = "runtime-loading")]
+fn runtime_loading_bindings(bindings: &str) -> String {
+    use quote::{format_ident, quote};
+
+    let file = syn::parse_file(bindings).expect("Unable to parse the generated bindings");
+    let mut items = Vec::new();
+    let mut fields = Vec::new();
+    let mut loaders = Vec::new();
+    let mut forwarders = Vec::new();
+    for item in file.items {
+        let syn::Item::ForeignMod(foreign_mod) = item else {
+            items.push(item);
+            continue;
+        };
+        for foreign_item in foreign_mod.items {
+            // Only functions can be forwarded to the library; anything else would be
+            // missing from the runtime-loading bindings, so it is better to stop here
+            let func = match foreign_item {
+                syn::ForeignItem::Fn(func) => func,
+                syn::ForeignItem::Static(item) => panic!(
+                    "Extern static \"{}\" in the bindings is not supported with the `runtime-loading` feature",
+                    item.ident
+                ),
+                item => panic!(
+                    "Unsupported extern item in the bindings with the `runtime-loading` feature: {}",
+                    quote!(#item)
+                ),
+            };
+            let name = &func.sig.ident;
+            let name_str = name.to_string();
+            let symbol = syn::LitByteStr::new(format!("{}\0", name_str).as_bytes(), name.span());
+            let output = &func.sig.output;
+            let docs = func.attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+            let arg_types: Vec<_> = func.sig.inputs.iter().map(|arg| match arg {
+                syn::FnArg::Typed(pat_type) => &*pat_type.ty,
+                syn::FnArg::Receiver(_) => panic!("Unexpected receiver in {}", name_str),
+            }).collect();
+            let arg_names: Vec<_> = (0..arg_types.len()).map(|i| format_ident!("arg{}", i)).collect();
+            let fn_type = quote! { unsafe extern "C" fn(#(#arg_types),*) #output };
+
+            fields.push(quote! { #name: #fn_type });
+            loaders.push(quote! {
+                #name: *library.get::<#fn_type>(#symbol).map_err(|err| missing_symbol(#name_str, err))?
+            });
+            forwarders.push(quote! {
+                #(#docs)*
+                #[inline]
+                pub unsafe fn #name(#(#arg_names: #arg_types),*) #output {
+                    (library().#name)(#(#arg_names),*)
+                }
+            });
+        }
+    }
+
+    quote! {
+        #(#items)*
+
+        pub struct DSSCApiLibrary {
+            _library: ::libloading::Library,
+            #(#fields,)*
+        }
+
+        impl DSSCApiLibrary {
+            unsafe fn load(library: ::libloading::Library) -> Result<Self, crate::common::DSSError> {
+                Ok(Self {
+                    #(#loaders,)*
+                    _library: library,
+                })
+            }
+        }
+
+        #(#forwarders)*
+    }.to_string()
+}
+
+/// Generates the Rust types for the DSS classes from the AltDSS-Schema JSON Schema.
+///
+/// Each object definition becomes a struct with an optional field per property, and
+/// each string enumeration becomes an enum. Properties without a direct Rust equivalent
+/// (e.g. alternatives through `oneOf`) are kept as `serde_json::Value`.
+#[cfg(feature = "schema-types")]
+fn schema_types(pwd_dir: &Path) -> String {
+    use serde_json::Value;
+    use std::fmt::Write;
+
+    let schema_path = env::var_os("ALTDSS_SCHEMA")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| pwd_dir.join("schema").join("altdss_schema.json"));
+    println!("cargo:rerun-if-changed={}", schema_path.to_str().unwrap());
+    let schema_text = fs::read_to_string(&schema_path).unwrap_or_else(|_| panic!(
+        "Couldn't read the AltDSS-Schema from {}; see schema/README.md",
+        schema_path.to_str().unwrap()
+    ));
+    let schema: Value = serde_json::from_str(&schema_text).expect("Invalid JSON in the AltDSS-Schema");
+    // AltDSS-Schema uses JSON Schema 2020-12, where the definitions are in `$defs`
+    let defs = schema.get("$defs")
+        .and_then(Value::as_object)
+        .expect("No `$defs` found in the AltDSS-Schema; see schema/README.md for the supported version");
+
+    // Key of the unit metadata of the properties
+    const UNITS_KEY: &str = "units";
+    const KEYWORDS: &[&str] = &[
+        "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+        "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+        "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+        "where", "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final",
+        "macro", "override", "priv", "typeof", "unsized", "virtual", "yield", "try",
+    ];
+    fn ident(name: &str) -> String {
+        let mut result = String::new();
+        for c in name.chars() {
+            match c {
+                '%' => result.push_str("pct"),
+                c if c.is_ascii_alphanumeric() => result.push(c),
+                _ => result.push('_'),
+            }
+        }
+        if result.is_empty() || result.starts_with(|c: char| c.is_ascii_digit()) {
+            result.insert(0, '_');
+        }
+        if KEYWORDS.contains(&result.as_str()) {
+            result.push('_');
+        }
+        result
+    }
+    fn ref_name(value: &Value) -> Option<String> {
+        let reference = value.get("$ref")?.as_str()?;
+        Some(ident(reference.rsplit('/').next()?))
+    }
+    fn rust_type(value: &Value) -> String {
+        if let Some(name) = ref_name(value) {
+            return name;
+        }
+        match value.get("type").and_then(Value::as_str) {
+            Some("number") => "f64".to_string(),
+            Some("integer") => "i32".to_string(),
+            Some("boolean") => "bool".to_string(),
+            Some("string") => "String".to_string(),
+            Some("array") => match value.get("items") {
+                Some(items) => format!("Vec<{}>", rust_type(items)),
+                None => "Vec<serde_json::Value>".to_string(),
+            },
+            _ => "serde_json::Value".to_string(),
+        }
+    }
+    fn doc_lines(out: &mut String, value: &Value, indent: &str) {
+        if let Some(description) = value.get("description").or_else(|| value.get("title")).and_then(Value::as_str) {
+            for line in description.lines() {
+                writeln!(out, "{}/// {}", indent, line.trim_end()).unwrap();
+            }
+        }
+    }
+
+    let mut out = String::new();
+    for (def_name, def) in defs {
+        let type_name = ident(def_name);
+        let variants = def.get("enum").and_then(Value::as_array);
+        let properties = def.get("properties").and_then(Value::as_object);
+        if let Some(variants) = variants.filter(|variants| variants.iter().all(Value::is_string)) {
+            doc_lines(&mut out, def, "");
+            writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]").unwrap();
+            writeln!(out, "pub enum {} {{", type_name).unwrap();
+            let mut used = Vec::new();
+            for variant in variants.iter().filter_map(Value::as_str) {
+                let mut variant_ident = ident(variant);
+                while used.contains(&variant_ident.to_lowercase()) {
+                    variant_ident.push('_');
+                }
+                used.push(variant_ident.to_lowercase());
+                writeln!(out, "    #[serde(rename = {:?})]", variant).unwrap();
+                writeln!(out, "    {},", variant_ident).unwrap();
+            }
+            writeln!(out, "}}\n").unwrap();
+        } else if let Some(properties) = properties {
+            doc_lines(&mut out, def, "");
+            writeln!(out, "#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]").unwrap();
+            writeln!(out, "pub struct {} {{", type_name).unwrap();
+            let mut units = Vec::new();
+            let mut field_names = Vec::new();
+            for (prop_name, prop) in properties {
+                let field = ident(prop_name);
+                if field == "extra" {
+                    continue;
+                }
+                doc_lines(&mut out, prop, "    ");
+                writeln!(out, "    #[serde(rename = {:?}, skip_serializing_if = \"Option::is_none\")]", prop_name).unwrap();
+                writeln!(out, "    pub {}: Option<{}>,", field, rust_type(prop)).unwrap();
+                if let Some(unit) = prop.get(UNITS_KEY).and_then(Value::as_str) {
+                    units.push((prop_name.clone(), unit.to_string()));
+                }
+                field_names.push(prop_name.clone());
+            }
+            writeln!(out, "    /// Properties not covered by the schema, kept for the round-trips").unwrap();
+            writeln!(out, "    #[serde(flatten)]").unwrap();
+            writeln!(out, "    pub extra: serde_json::Map<String, serde_json::Value>,").unwrap();
+            writeln!(out, "}}\n").unwrap();
+
+            // Only the definitions with a name correspond to DSS objects
+            if properties.contains_key("name") {
+                writeln!(out, "impl DSSClassType for {} {{", type_name).unwrap();
+                writeln!(out, "    const CLASS_NAME: &'static str = {:?};", def_name).unwrap();
+                writeln!(out, "    const PROPERTIES: &'static [&'static str] = &{:?};", field_names).unwrap();
+                writeln!(out, "    const UNITS: &'static [(&'static str, &'static str)] = &{:?};", units).unwrap();
+                writeln!(out, "}}\n").unwrap();
+            }
+        } else {
+            doc_lines(&mut out, def, "");
+            writeln!(out, "pub type {} = {};\n", type_name, rust_type(def)).unwrap();
+        }
+    }
+    out
 }
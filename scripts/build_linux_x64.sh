@@ -44,5 +44,53 @@ function build_and_test() {
 }
 
 
+########################
+# Check that the prebuilt
+# bindings are up to date
+# and usable.
+########################
+function check_prebuilt_bindings() {
+    # Regenerate the bindings from the
+    # headers downloaded above; the file
+    # in the repository must match them.
+    ./scripts/update_bindings.sh || return 1
+    if [[ -n "$(git status --porcelain -- bindings)" ]];
+    then
+        echo "The prebuilt bindings are missing or outdated, run scripts/update_bindings.sh and commit the result:"
+        git status --porcelain -- bindings
+        return 1
+    fi
+
+    # Build and test without bindgen.
+    cargo build --no-default-features --features prebuilt-0_14_3 && \
+    cargo test --no-default-features --features prebuilt-0_14_3
+}
+
+
+########################
+# Test loading the library
+# at runtime instead of
+# linking to it.
+########################
+function check_runtime_loading() {
+    DSS_CAPI_LIB_DIR="$PWD/dss_capi/lib/linux_x64" \
+        cargo test --features runtime-loading --test runtime_loading
+}
+
+
+########################
+# Build and test the typed
+# structs generated from the
+# pinned AltDSS-Schema.
+########################
+function check_schema_types() {
+    ./scripts/fetch_schema.sh || return 1
+    cargo test --features schema-types --test schema_types
+}
+
+
 install_dependencies
-build_and_test
\ No newline at end of file
+build_and_test && \
+check_runtime_loading && \
+check_prebuilt_bindings && \
+check_schema_types
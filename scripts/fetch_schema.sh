@@ -0,0 +1,40 @@
+########################
+# Download the AltDSS-Schema
+# JSON Schema used by the
+# `schema-types` feature, at
+# the version pinned below.
+#
+# The file is checked against
+# schema/altdss_schema.json.sha256;
+# run with --update after changing
+# the pinned version to record
+# the new checksum.
+########################
+
+# Pinned AltDSS-Schema version (git ref)
+# and path of the JSON Schema in it
+ALTDSS_SCHEMA_REF="0.14.3"
+ALTDSS_SCHEMA_FILE="altdss_schema.json"
+
+function fetch_schema() {
+    local url="https://raw.githubusercontent.com/dss-extensions/AltDSS-Schema/${ALTDSS_SCHEMA_REF}/${ALTDSS_SCHEMA_FILE}"
+    local checksum_file="schema/altdss_schema.json.sha256"
+
+    wget -qO schema/altdss_schema.json "$url" || return 1
+
+    if [[ "$1" == "--update" ]];
+    then
+        sha256sum schema/altdss_schema.json > "$checksum_file"
+        return 0
+    fi
+
+    if [[ ! -f "$checksum_file" ]];
+    then
+        echo "Missing $checksum_file, run scripts/fetch_schema.sh --update and commit the result"
+        return 1
+    fi
+    sha256sum --check "$checksum_file"
+}
+
+
+fetch_schema "$@"
@@ -0,0 +1,29 @@
+########################
+# Regenerate the prebuilt
+# bindings for the current
+# DSS C-API version.
+#
+# Requires libclang. The
+# output is written to
+# bindings/dss_capi_<version>.rs
+########################
+function update_bindings() {
+    # AltDSS-Rust version follows
+    # DSS CAPI version
+    DSS_CAPI_VERSION=$(cargo metadata --format-version=1 --no-deps | jq '.packages[0].version' | tr -d '"')
+    DSS_CAPI_PLATFORM="linux_x64"
+
+    # Get dss_capi headers if
+    # not already available
+    if [[ ! -d "$PWD/dss_capi/include" ]];
+    then
+        wget -qO- "https://github.com/dss-extensions/dss_capi/releases/download/${DSS_CAPI_VERSION}/dss_capi_${DSS_CAPI_VERSION}_${DSS_CAPI_PLATFORM}.tar.gz" | tar zxv
+    fi
+
+    # Only the build script needs to run,
+    # no need to link to the library.
+    ALTDSS_UPDATE_BINDINGS=1 cargo check --features runtime-loading
+}
+
+
+update_bindings
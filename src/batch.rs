@@ -0,0 +1,353 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bulk reads and writes of a property across many DSS objects, through the `Batch_*`
+//! functions of DSS C-API.
+//!
+//! A batch is a list of objects of the same class. Reading a property returns the values
+//! for all objects in a single array, in the same order as `DSSBatch::Names`, and writing
+//! a property updates all objects in a single call, without changing the active element.
+//!
+//! Like the object handles, a batch returns `DSSErrorKind::StaleReference` once the
+//! circuit is cleared.
+//!
+//! ```no_run
+//! use altdss::common::DSSContext;
+//! use altdss::obj::IObj;
+//!
+//! let ctx = DSSContext::prime();
+//! let obj = IObj::new(&ctx);
+//! let loads = obj.Class("Load").unwrap().Batch().unwrap();
+//! loads.Float64Operation("kW", altdss::batch::BatchOperation::Multiply, 1.1).unwrap();
+//! let kW = loads.GetFloat64("kW").unwrap();
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::dss_capi;
+use crate::callbacks::ClearEpoch;
+use crate::common::{DSSContext, DSSError, DSSErrorKind, DSSResultExt};
+use crate::obj::{DSSClass, DSSObject, DSSPropertyKey};
+use std::ffi::{c_char, c_void};
+use std::slice::from_raw_parts;
+
+/// Operations for the numeric batch setters
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(i32)]
+pub enum BatchOperation {
+    /// Replaces the current values
+    Set = 0,
+    /// Multiplies the current values
+    Multiply = 1,
+    /// Adds to the current values
+    Increment = 2,
+    /// Divides the current values
+    Divide = 3,
+}
+
+/// Property index that the batch string getters use for the object names
+const NAME_PROPERTY_INDEX: i32 = 0;
+
+/// Converts a pointer array allocated by the engine to a list of handles, disposing it
+fn take_handles(ctx: &DSSContext, mut data: *mut *mut c_void, cnt: [i32; 4]) -> Result<Box::<[*mut c_void]>, DSSError> {
+    let result = ctx.DSSError().map(|_| {
+        if data.is_null() || cnt[0] <= 0 {
+            return Box::default();
+        }
+        unsafe { from_raw_parts(data, cnt[0] as usize) }.into()
+    });
+    unsafe { dss_capi::DSS_Dispose_PPointer(&mut data) };
+    result
+}
+
+/// A list of DSS objects of the same class, for bulk reads and writes
+pub struct DSSBatch<'a> {
+    ctx: &'a DSSContext,
+    ptrs: Box::<[*mut c_void]>,
+    epoch: ClearEpoch,
+}
+
+unsafe impl<'a> Send for DSSBatch <'a> {
+}
+
+impl<'a> DSSClass<'a> {
+    /// Returns a batch with all the objects of this class
+    pub fn Batch(&self) -> Result<DSSBatch<'a>, DSSError> {
+        let mut cnt: [i32; 4] = [0, 0, 0, 0];
+        let mut data: *mut *mut c_void = 0 as *mut *mut c_void;
+        unsafe { dss_capi::Batch_CreateByClass(self.ctx.ctx_ptr as *mut c_void, &mut data, &mut cnt[0], self.Index()) };
+        let ptrs = take_handles(self.ctx, data, cnt).origin("DSSClass::Batch")?;
+        Ok(DSSBatch::from_handles(self.ctx, self.epoch.renew(), ptrs))
+    }
+
+    /// Returns a batch with the objects of this class with the given names, in the same
+    /// order. Fails if any of the objects does not exist.
+    pub fn BatchByNames(&self, names: &[impl AsRef<str>]) -> Result<DSSBatch<'a>, DSSError> {
+        let objs = names.iter().map(|name| self.Get(name)).collect::<Result<Vec<_>, _>>().origin("DSSClass::BatchByNames")?;
+        let ptrs = objs.iter().map(|obj| obj.checked_ptr()).collect::<Result<_, _>>().origin("DSSClass::BatchByNames")?;
+        Ok(DSSBatch::from_handles(self.ctx, self.epoch.renew(), ptrs))
+    }
+
+    /// Returns a batch with the objects of this class whose names match a regular expression
+    pub fn BatchByRegExp(&self, re: impl AsRef<str>) -> Result<DSSBatch<'a>, DSSError> {
+        let re_c = self.ctx.PrepareString(re).origin("DSSClass::BatchByRegExp")?;
+        let mut cnt: [i32; 4] = [0, 0, 0, 0];
+        let mut data: *mut *mut c_void = 0 as *mut *mut c_void;
+        unsafe { dss_capi::Batch_CreateByRegExp(self.ctx.ctx_ptr as *mut c_void, &mut data, &mut cnt[0], self.Index(), re_c.as_ptr()) };
+        let ptrs = take_handles(self.ctx, data, cnt).origin("DSSClass::BatchByRegExp")?;
+        Ok(DSSBatch::from_handles(self.ctx, self.epoch.renew(), ptrs))
+    }
+
+    /// Returns a batch with the objects of this class where an integer property has the
+    /// given value. The property must be passed by index.
+    pub fn BatchByInt32Property(&self, prop: i32, value: i32) -> Result<DSSBatch<'a>, DSSError> {
+        let mut cnt: [i32; 4] = [0, 0, 0, 0];
+        let mut data: *mut *mut c_void = 0 as *mut *mut c_void;
+        unsafe { dss_capi::Batch_CreateByInt32Property(self.ctx.ctx_ptr as *mut c_void, &mut data, &mut cnt[0], self.Index(), prop, value) };
+        let ptrs = take_handles(self.ctx, data, cnt).origin("DSSClass::BatchByInt32Property")?;
+        Ok(DSSBatch::from_handles(self.ctx, self.epoch.renew(), ptrs))
+    }
+}
+
+impl<'a> DSSBatch<'a> {
+    fn from_handles(ctx: &'a DSSContext, epoch: ClearEpoch, ptrs: Box::<[*mut c_void]>) -> Self {
+        Self {
+            ctx: ctx,
+            ptrs: ptrs,
+            epoch: epoch,
+        }
+    }
+
+    /// Creates a batch from a list of objects, which must be of the same class
+    pub fn from_objects(ctx: &'a DSSContext, objs: &[DSSObject]) -> Result<Self, DSSError> {
+        if let Some(first) = objs.first() {
+            let class_idx = first.ClassIndex().origin("DSSBatch::from_objects")?;
+            for obj in &objs[1..] {
+                if obj.ClassIndex().origin("DSSBatch::from_objects")? != class_idx {
+                    let (first_name, name) = (first.FullName().origin("DSSBatch::from_objects")?, obj.FullName().origin("DSSBatch::from_objects")?);
+                    return Err(DSSError::wrapper(
+                        DSSErrorKind::InvalidArgument,
+                        format!("The objects of a batch must be of the same class, got {} and {}", first_name, name)
+                    )).origin("DSSBatch::from_objects");
+                }
+            }
+        }
+        let ptrs = objs.iter().map(|obj| obj.checked_ptr()).collect::<Result<_, _>>().origin("DSSBatch::from_objects")?;
+        let epoch = ClearEpoch::current(ctx).origin("DSSBatch::from_objects")?;
+        Ok(DSSBatch::from_handles(ctx, epoch, ptrs))
+    }
+
+    /// Number of objects in the batch
+    pub fn Count(&self) -> usize {
+        self.ptrs.len()
+    }
+
+    /// Handles for the objects in the batch
+    pub fn Objects(&self) -> Vec<DSSObject<'a>> {
+        self.ptrs.iter().filter_map(|ptr| DSSObject::from_handle(self.ctx, self.epoch.clone(), *ptr)).collect()
+    }
+
+    /// Names of the objects in the batch; the arrays from the getters follow the same order
+    pub fn Names(&self) -> Result<Box::<[String]>, DSSError> {
+        let mut cnt: [i32; 4] = [0, 0, 0, 0];
+        let mut data: *mut *mut c_char = 0 as *mut *mut c_char;
+        let (ptrs, size) = self.handles().origin("DSSBatch::Names")?;
+        unsafe { dss_capi::Batch_GetAsString(&mut data, &mut cnt[0], ptrs, size, NAME_PROPERTY_INDEX) };
+        self.ctx.GetStringArray(data, cnt).origin("DSSBatch::Names")
+    }
+
+    /// Returns a new batch with the objects where an integer property has the given value
+    pub fn FilterByInt32Property(&self, prop: impl DSSPropertyKey, value: i32) -> Result<DSSBatch<'a>, DSSError> {
+        let Some(idx) = self.PropertyIndex(prop).origin("DSSBatch::FilterByInt32Property")? else {
+            return Ok(DSSBatch::from_handles(self.ctx, self.epoch.clone(), Box::default()));
+        };
+        let mut cnt: [i32; 4] = [0, 0, 0, 0];
+        let mut data: *mut *mut c_void = 0 as *mut *mut c_void;
+        let (ptrs, size) = self.handles().origin("DSSBatch::FilterByInt32Property")?;
+        unsafe { dss_capi::Batch_FilterByInt32Property(&mut data, &mut cnt[0], ptrs, size, idx, value) };
+        let ptrs = take_handles(self.ctx, data, cnt).origin("DSSBatch::FilterByInt32Property")?;
+        Ok(DSSBatch::from_handles(self.ctx, self.epoch.clone(), ptrs))
+    }
+
+    /// Returns a new batch with the objects for which the predicate returns true
+    pub fn Filter<F: FnMut(&DSSObject<'a>) -> Result<bool, DSSError>>(&self, mut predicate: F) -> Result<DSSBatch<'a>, DSSError> {
+        let mut ptrs = Vec::new();
+        for obj in self.Objects() {
+            if predicate(&obj)? {
+                ptrs.push(obj.checked_ptr()?);
+            }
+        }
+        Ok(DSSBatch::from_handles(self.ctx, self.epoch.clone(), ptrs.into()))
+    }
+
+    /// Starts editing all objects, see `DSSObject::BeginEdit`
+    pub fn BeginEdit(&self) -> Result<(), DSSError> {
+        let (ptrs, size) = self.handles().origin("DSSBatch::BeginEdit")?;
+        unsafe { dss_capi::Batch_BeginEdit(ptrs, size) };
+        self.ctx.DSSError().origin("DSSBatch::BeginEdit")
+    }
+
+    /// Finishes editing all objects, see `DSSObject::EndEdit`
+    pub fn EndEdit(&self, num_changes: i32) -> Result<(), DSSError> {
+        let (ptrs, size) = self.handles().origin("DSSBatch::EndEdit")?;
+        unsafe { dss_capi::Batch_EndEdit(ptrs, size, num_changes) };
+        self.ctx.DSSError().origin("DSSBatch::EndEdit")
+    }
+
+    pub fn GetFloat64(&self, prop: impl DSSPropertyKey) -> Result<Box::<[f64]>, DSSError> {
+        let Some(idx) = self.PropertyIndex(prop).origin("DSSBatch::GetFloat64")? else {
+            return Ok(Box::default());
+        };
+        self.ctx.CheckGRBuffers().origin("DSSBatch::GetFloat64")?;
+        let (data, cnt) = self.ctx.Float64ArrayGRPointers();
+        let (ptrs, size) = self.handles().origin("DSSBatch::GetFloat64")?;
+        unsafe { dss_capi::Batch_GetFloat64(data, cnt, ptrs, size, idx) };
+        self.ctx.GetFloat64ArrayGR().origin("DSSBatch::GetFloat64")
+    }
+
+    pub fn GetInt32(&self, prop: impl DSSPropertyKey) -> Result<Box::<[i32]>, DSSError> {
+        let Some(idx) = self.PropertyIndex(prop).origin("DSSBatch::GetInt32")? else {
+            return Ok(Box::default());
+        };
+        self.ctx.CheckGRBuffers().origin("DSSBatch::GetInt32")?;
+        let (data, cnt) = self.ctx.Int32ArrayGRPointers();
+        let (ptrs, size) = self.handles().origin("DSSBatch::GetInt32")?;
+        unsafe { dss_capi::Batch_GetInt32(data, cnt, ptrs, size, idx) };
+        self.ctx.GetInt32ArrayGR().origin("DSSBatch::GetInt32")
+    }
+
+    pub fn GetString(&self, prop: impl DSSPropertyKey) -> Result<Box::<[String]>, DSSError> {
+        let Some(idx) = self.PropertyIndex(prop).origin("DSSBatch::GetString")? else {
+            return Ok(Box::default());
+        };
+        let mut cnt: [i32; 4] = [0, 0, 0, 0];
+        let mut data: *mut *mut c_char = 0 as *mut *mut c_char;
+        let (ptrs, size) = self.handles().origin("DSSBatch::GetString")?;
+        unsafe { dss_capi::Batch_GetString(&mut data, &mut cnt[0], ptrs, size, idx) };
+        self.ctx.GetStringArray(data, cnt).origin("DSSBatch::GetString")
+    }
+
+    /// Returns the values of any property as text, see `DSSObject::GetAsString`
+    pub fn GetAsString(&self, prop: impl DSSPropertyKey) -> Result<Box::<[String]>, DSSError> {
+        let Some(idx) = self.PropertyIndex(prop).origin("DSSBatch::GetAsString")? else {
+            return Ok(Box::default());
+        };
+        let mut cnt: [i32; 4] = [0, 0, 0, 0];
+        let mut data: *mut *mut c_char = 0 as *mut *mut c_char;
+        let (ptrs, size) = self.handles().origin("DSSBatch::GetAsString")?;
+        unsafe { dss_capi::Batch_GetAsString(&mut data, &mut cnt[0], ptrs, size, idx) };
+        self.ctx.GetStringArray(data, cnt).origin("DSSBatch::GetAsString")
+    }
+
+    /// Sets the same value for all objects
+    pub fn SetFloat64(&self, prop: impl DSSPropertyKey, value: f64) -> Result<(), DSSError> {
+        self.Float64Operation(prop, BatchOperation::Set, value).origin("DSSBatch::SetFloat64")
+    }
+
+    /// Applies an operation with the same value to all objects, e.g. to scale a property
+    pub fn Float64Operation(&self, prop: impl DSSPropertyKey, operation: BatchOperation, value: f64) -> Result<(), DSSError> {
+        let Some(idx) = self.PropertyIndex(prop).origin("DSSBatch::Float64Operation")? else {
+            return Ok(());
+        };
+        let (ptrs, size) = self.handles().origin("DSSBatch::Float64Operation")?;
+        unsafe { dss_capi::Batch_Float64(ptrs, size, idx, operation as i32, value, 0) };
+        self.ctx.DSSError().origin("DSSBatch::Float64Operation")
+    }
+
+    /// Sets the same value for all objects
+    pub fn SetInt32(&self, prop: impl DSSPropertyKey, value: i32) -> Result<(), DSSError> {
+        self.Int32Operation(prop, BatchOperation::Set, value).origin("DSSBatch::SetInt32")
+    }
+
+    /// Applies an operation with the same value to all objects
+    pub fn Int32Operation(&self, prop: impl DSSPropertyKey, operation: BatchOperation, value: i32) -> Result<(), DSSError> {
+        let Some(idx) = self.PropertyIndex(prop).origin("DSSBatch::Int32Operation")? else {
+            return Ok(());
+        };
+        let (ptrs, size) = self.handles().origin("DSSBatch::Int32Operation")?;
+        unsafe { dss_capi::Batch_Int32(ptrs, size, idx, operation as i32, value, 0) };
+        self.ctx.DSSError().origin("DSSBatch::Int32Operation")
+    }
+
+    /// Sets the same value for all objects
+    pub fn SetString(&self, prop: impl DSSPropertyKey, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let Some(idx) = self.PropertyIndex(prop).origin("DSSBatch::SetString")? else {
+            return Ok(());
+        };
+        let value_c = self.ctx.PrepareString(value).origin("DSSBatch::SetString")?;
+        let (ptrs, size) = self.handles().origin("DSSBatch::SetString")?;
+        unsafe { dss_capi::Batch_SetString(ptrs, size, idx, value_c.as_ptr(), 0) };
+        self.ctx.DSSError().origin("DSSBatch::SetString")
+    }
+
+    /// Sets one value per object, in the same order as `Names`
+    pub fn SetFloat64Array(&self, prop: impl DSSPropertyKey, value: &[f64]) -> Result<(), DSSError> {
+        self.CheckSize(value.len()).origin("DSSBatch::SetFloat64Array")?;
+        let Some(idx) = self.PropertyIndex(prop).origin("DSSBatch::SetFloat64Array")? else {
+            return Ok(());
+        };
+        let (ptrs, size) = self.handles().origin("DSSBatch::SetFloat64Array")?;
+        unsafe { dss_capi::Batch_SetFloat64Array(ptrs, size, idx, value.as_ptr() as *mut f64, 0) };
+        self.ctx.DSSError().origin("DSSBatch::SetFloat64Array")
+    }
+
+    /// Sets one value per object, in the same order as `Names`
+    pub fn SetInt32Array(&self, prop: impl DSSPropertyKey, value: &[i32]) -> Result<(), DSSError> {
+        self.CheckSize(value.len()).origin("DSSBatch::SetInt32Array")?;
+        let Some(idx) = self.PropertyIndex(prop).origin("DSSBatch::SetInt32Array")? else {
+            return Ok(());
+        };
+        let (ptrs, size) = self.handles().origin("DSSBatch::SetInt32Array")?;
+        unsafe { dss_capi::Batch_SetInt32Array(ptrs, size, idx, value.as_ptr() as *mut i32, 0) };
+        self.ctx.DSSError().origin("DSSBatch::SetInt32Array")
+    }
+
+    /// Sets one value per object, in the same order as `Names`
+    pub fn SetStringArray(&self, prop: impl DSSPropertyKey, value: &[impl AsRef<str>]) -> Result<(), DSSError> {
+        self.CheckSize(value.len()).origin("DSSBatch::SetStringArray")?;
+        let Some(idx) = self.PropertyIndex(prop).origin("DSSBatch::SetStringArray")? else {
+            return Ok(());
+        };
+        let (_value_cstrs, value_c) = self.ctx.PrepareStringArray(value).origin("DSSBatch::SetStringArray")?;
+        let (ptrs, size) = self.handles().origin("DSSBatch::SetStringArray")?;
+        unsafe { dss_capi::Batch_SetStringArray(ptrs, size, idx, value_c.as_ptr() as *mut *const c_char, 0) };
+        self.ctx.DSSError().origin("DSSBatch::SetStringArray")
+    }
+
+    /// Resolves the property index through the first object; `None` for empty batches
+    fn PropertyIndex(&self, prop: impl DSSPropertyKey) -> Result<Option<i32>, DSSError> {
+        match self.ptrs.first().and_then(|ptr| DSSObject::from_handle(self.ctx, self.epoch.clone(), *ptr)) {
+            Some(obj) => Ok(Some(prop.PropertyIndex(&obj)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn CheckSize(&self, size: usize) -> Result<(), DSSError> {
+        if size != self.ptrs.len() {
+            return Err(DSSError::wrapper(
+                DSSErrorKind::InvalidArgument,
+                format!("Expected {} values for the batch, got {}", self.ptrs.len(), size)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Pointer and size of the handle array, checking that the objects still exist
+    fn handles(&self) -> Result<(*mut *mut c_void, i32), DSSError> {
+        self.epoch.check(|| "batch".to_string())?;
+        Ok((self.ptrs.as_ptr() as *mut *mut c_void, self.ptrs.len() as i32))
+    }
+}
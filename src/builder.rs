@@ -0,0 +1,223 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(non_snake_case)]
+
+use crate::dss_capi;
+use crate::classic::IDSS;
+use crate::common::{DSSContext, DSSError, DSSErrorKind, DSSResultExt};
+
+/// Creates or configures a DSS context with a declared set of engine options.
+///
+/// Options that are not set are left unchanged. The options are applied all at once:
+/// if setting any of them fails, the ones already applied are restored to their
+/// previous values before returning the error.
+///
+/// ```no_run
+/// use altdss::builder::DSSContextBuilder;
+/// use altdss::classic::IDSS;
+///
+/// let ctx = DSSContextBuilder::modern().AllowForms(true).Build().unwrap();
+/// let dss = IDSS::new(&ctx);
+/// ```
+///
+/// The compatibility flags (`IDSS::Set_CompatFlags`) are global, affecting every DSS
+/// context in the process. Setting them through the builder requires calling
+/// `AllowGlobalOptions(true)`; otherwise, applying the options fails with
+/// `DSSErrorKind::GlobalOption`.
+///
+/// The circuit settings from `ISettings` are not handled here since they belong to
+/// the active circuit and are reset for each new circuit.
+#[derive(Debug, Clone, Default)]
+pub struct DSSContextBuilder {
+    allow_change_dir: Option<bool>,
+    allow_editor: Option<bool>,
+    allow_forms: Option<bool>,
+    allow_doscmd: Option<bool>,
+    com_error_results: Option<bool>,
+    extended_errors: Option<bool>,
+    early_abort: Option<bool>,
+    data_path: Option<String>,
+    compat_flags: Option<u32>,
+    allow_global_options: bool,
+}
+
+/// Restores the options already applied, in reverse order, if a later one fails
+type UndoList = Vec<Box<dyn Fn(&IDSS)>>;
+
+/// Applies a boolean option, recording its previous value
+macro_rules! apply_option {
+    ($dss:expr, $undo:expr, $value:expr, $($iface:ident).*, $get:ident, $set:ident) => {
+        if let Some(value) = $value {
+            let previous = $dss$(.$iface)*.$get()?;
+            $dss$(.$iface)*.$set(value)?;
+            $undo.push(Box::new(move |dss: &IDSS| { let _ = dss$(.$iface)*.$set(previous); }));
+        }
+    };
+}
+
+impl DSSContextBuilder {
+    /// Returns a builder with no options set.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a builder with the defaults recommended for applications, following
+    /// https://github.com/dss-extensions/AltDSS-Go/issues/2 :
+    ///
+    /// - the working directory is never changed by the engine;
+    /// - no editor, forms or `DOScmd`;
+    /// - empty arrays on errors, instead of the COM-compatible results;
+    /// - extended errors, aborting scripts on the first error.
+    ///
+    /// The global options are not touched.
+    pub fn modern() -> Self {
+        Self::new()
+            .AllowChangeDir(false)
+            .AllowEditor(false)
+            .AllowForms(false)
+            .AllowDOScmd(false)
+            .COMErrorResults(false)
+            .ExtendedErrors(true)
+            .EarlyAbort(true)
+    }
+
+    /// See `IDSS::Set_AllowChangeDir`
+    pub fn AllowChangeDir(mut self, value: bool) -> Self {
+        self.allow_change_dir = Some(value);
+        self
+    }
+
+    /// See `IDSS::Set_AllowEditor`
+    pub fn AllowEditor(mut self, value: bool) -> Self {
+        self.allow_editor = Some(value);
+        self
+    }
+
+    /// See `IDSS::Set_AllowForms`
+    pub fn AllowForms(mut self, value: bool) -> Self {
+        self.allow_forms = Some(value);
+        self
+    }
+
+    /// See `IDSS::Set_AllowDOScmd`
+    pub fn AllowDOScmd(mut self, value: bool) -> Self {
+        self.allow_doscmd = Some(value);
+        self
+    }
+
+    /// See `IDSS::Set_COMErrorResults`
+    pub fn COMErrorResults(mut self, value: bool) -> Self {
+        self.com_error_results = Some(value);
+        self
+    }
+
+    /// See `IError::Set_ExtendedErrors`
+    pub fn ExtendedErrors(mut self, value: bool) -> Self {
+        self.extended_errors = Some(value);
+        self
+    }
+
+    /// See `IError::Set_EarlyAbort`
+    pub fn EarlyAbort(mut self, value: bool) -> Self {
+        self.early_abort = Some(value);
+        self
+    }
+
+    /// See `IDSS::Set_DataPath`. The engine creates the folder if it does not exist
+    /// (but not its parents), failing otherwise.
+    pub fn DataPath(mut self, value: impl Into<String>) -> Self {
+        self.data_path = Some(value.into());
+        self
+    }
+
+    /// See `IDSS::Set_CompatFlags`. **These flags are global**, so `AllowGlobalOptions(true)`
+    /// is also required.
+    pub fn CompatFlags(mut self, value: u32) -> Self {
+        self.compat_flags = Some(value);
+        self
+    }
+
+    /// Allows the builder to change the global options, which affect all DSS contexts
+    /// in the process.
+    pub fn AllowGlobalOptions(mut self, value: bool) -> Self {
+        self.allow_global_options = value;
+        self
+    }
+
+    /// Creates a new DSS context and applies the options to it.
+    pub fn Build(&self) -> Result<DSSContext, DSSError> {
+        dss_capi::ensure_library().origin("DSSContextBuilder::Build")?;
+        let ctx_ptr = unsafe { dss_capi::ctx_New() };
+        if ctx_ptr.is_null() {
+            return Err(DSSError::wrapper(
+                DSSErrorKind::Context,
+                "Could not create a new DSS Context".to_string()
+            )).origin("DSSContextBuilder::Build");
+        }
+        let ctx = DSSContext::try_new(ctx_ptr).origin("DSSContextBuilder::Build")?;
+        self.Apply(&ctx)?;
+        Ok(ctx)
+    }
+
+    /// Wraps the Prime (default) DSS context and applies the options to it.
+    pub fn BuildPrime(&self) -> Result<DSSContext, DSSError> {
+        let ctx = DSSContext::try_prime().origin("DSSContextBuilder::BuildPrime")?;
+        self.Apply(&ctx)?;
+        Ok(ctx)
+    }
+
+    /// Applies the options to an existing context. If any option fails, the
+    /// options already applied are restored.
+    pub fn Apply(&self, ctx: &DSSContext) -> Result<(), DSSError> {
+        if self.compat_flags.is_some() && !self.allow_global_options {
+            return Err(DSSError::wrapper(
+                DSSErrorKind::GlobalOption,
+                "CompatFlags affects all DSS contexts in the process; use AllowGlobalOptions(true) to change it".to_string()
+            )).origin("DSSContextBuilder::Apply");
+        }
+
+        let dss = IDSS::new(ctx);
+        let mut undo: UndoList = Vec::new();
+        let result = self.apply_options(&dss, &mut undo);
+        if result.is_err() {
+            for restore in undo.iter().rev() {
+                restore(&dss);
+            }
+        }
+        result.origin("DSSContextBuilder::Apply")
+    }
+
+    fn apply_options(&self, dss: &IDSS, undo: &mut UndoList) -> Result<(), DSSError> {
+        apply_option!(dss, undo, self.allow_change_dir, , Get_AllowChangeDir, Set_AllowChangeDir);
+        apply_option!(dss, undo, self.allow_editor, , Get_AllowEditor, Set_AllowEditor);
+        apply_option!(dss, undo, self.allow_forms, , Get_AllowForms, Set_AllowForms);
+        apply_option!(dss, undo, self.allow_doscmd, , Get_AllowDOScmd, Set_AllowDOScmd);
+        apply_option!(dss, undo, self.com_error_results, , Get_COMErrorResults, Set_COMErrorResults);
+        apply_option!(dss, undo, self.extended_errors, Error, Get_ExtendedErrors, Set_ExtendedErrors);
+        apply_option!(dss, undo, self.early_abort, Error, Get_EarlyAbort, Set_EarlyAbort);
+        if let Some(value) = &self.data_path {
+            let previous = dss.Get_DataPath()?;
+            dss.Set_DataPath(value)?;
+            undo.push(Box::new(move |dss: &IDSS| { let _ = dss.Set_DataPath(&previous); }));
+        }
+        if let Some(value) = self.compat_flags {
+            // Applied last, since it affects the other contexts too
+            dss.Set_CompatFlags(value)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,372 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Routes the messages and progress updates emitted by the DSS engine to Rust closures.
+//!
+//! DSS C-API accepts a single message callback for the whole process. This module
+//! registers it while any context has a handler, and dispatches each message to the
+//! closures registered for the context that emitted it, so each `DSSContext` can have
+//! its own handlers. The messages of the other contexts are written to the standard
+//! output, as the engine does without a callback.
+//!
+//! The event callbacks used by `classic::IDSSEvents` are dispatched in the same way.
+
+#![allow(non_snake_case)]
+
+use crate::dss_capi;
+use crate::classic::AltDSSEvent;
+use crate::common::{DSSContext, DSSError, DSSErrorKind};
+use std::collections::HashMap;
+use std::ffi::{c_char, c_void, CStr};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::io::Write;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Message types, from the DSSMessageType enum in the DSS C-API headers
+const MESSAGE_ERROR: i32 = -1;
+const MESSAGE_GENERAL: i32 = 0;
+const MESSAGE_INFO: i32 = 1;
+const MESSAGE_HELP: i32 = 2;
+const MESSAGE_PROGRESS: i32 = 3;
+const MESSAGE_PROGRESS_CAPTION: i32 = 4;
+const MESSAGE_PROGRESS_FORM_CAPTION: i32 = 5;
+const MESSAGE_PROGRESS_PERCENT: i32 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DSSMessageSeverity {
+    /// Error messages. Note that errors are also reported as `DSSError` by the API functions.
+    Error,
+    /// General messages, including most warnings
+    General,
+    /// Informative messages
+    Info,
+    /// Help text, e.g. from the "help" command
+    Help,
+}
+
+/// A message emitted by the DSS engine
+#[derive(Debug, Clone)]
+pub struct DSSMessage {
+    pub severity: DSSMessageSeverity,
+    pub text: String,
+}
+
+/// Progress updates emitted by the DSS engine, e.g. during long solutions.
+/// These correspond to the setters in `IDSSProgress`.
+#[derive(Debug, Clone)]
+pub enum DSSProgress {
+    /// Progress message
+    Message(String),
+    /// Caption for the current step
+    Caption(String),
+    /// Caption for the whole progress "form"
+    FormCaption(String),
+    /// Completion percentage
+    Percent(i32),
+}
+
+type MessageCallback = Arc<dyn Fn(&DSSMessage) + Send + Sync>;
+type ProgressCallback = Arc<dyn Fn(&DSSProgress) + Send + Sync>;
+
+#[derive(Default)]
+struct ContextCallbacks {
+    message: Option<MessageCallback>,
+    progress: Option<ProgressCallback>,
+}
+
+/// Callbacks per context, keyed by the context pointer. The message callback is
+/// registered in the engine while this is not empty.
+static CALLBACKS: OnceLock<Mutex<HashMap<usize, ContextCallbacks>>> = OnceLock::new();
+
+fn callbacks() -> &'static Mutex<HashMap<usize, ContextCallbacks>> {
+    CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Output for the messages of the contexts without handlers, while the callback is
+/// registered for other contexts. Progress updates are not shown by the engine either.
+fn default_output(messageType: i32, text: &str) {
+    if (MESSAGE_ERROR..=MESSAGE_HELP).contains(&messageType) {
+        let _ = writeln!(std::io::stdout(), "{}", text);
+    }
+}
+
+unsafe extern "C" fn message_callback(ctx: *mut c_void, messageStr: *mut c_char, messageType: i32, _messageSize: i64, _messageSubType: i32) -> i32 {
+    if messageStr.is_null() {
+        return 0;
+    }
+
+    // Copy the handlers so that the lock is not held while running them; they may
+    // call the API, which could emit other messages.
+    let handlers = match callbacks().lock() {
+        Ok(map) => map.get(&(ctx as usize)).map(|entry| (entry.message.clone(), entry.progress.clone())),
+        Err(_) => return 0,
+    };
+    let text = CStr::from_ptr(messageStr).to_string_lossy().into_owned();
+    let Some((message_cb, progress_cb)) = handlers else {
+        default_output(messageType, &text);
+        return 0;
+    };
+
+    // Panics cannot unwind into the engine
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        let severity = match messageType {
+            MESSAGE_ERROR => Some(DSSMessageSeverity::Error),
+            MESSAGE_GENERAL => Some(DSSMessageSeverity::General),
+            MESSAGE_INFO => Some(DSSMessageSeverity::Info),
+            MESSAGE_HELP => Some(DSSMessageSeverity::Help),
+            _ => None,
+        };
+        if let Some(severity) = severity {
+            if let Some(cb) = &message_cb {
+                cb(&DSSMessage { severity: severity, text: text });
+            }
+            return;
+        }
+        let progress = match messageType {
+            MESSAGE_PROGRESS => DSSProgress::Message(text),
+            MESSAGE_PROGRESS_CAPTION => DSSProgress::Caption(text),
+            MESSAGE_PROGRESS_FORM_CAPTION => DSSProgress::FormCaption(text),
+            MESSAGE_PROGRESS_PERCENT => DSSProgress::Percent(text.trim().parse().unwrap_or(0)),
+            // Other types, like the requests to run the editor, are not forwarded
+            _ => return,
+        };
+        if let Some(cb) = &progress_cb {
+            cb(&progress);
+        }
+    }));
+    0
+}
+
+fn update_callbacks(ctx: &DSSContext, update: impl FnOnce(&mut ContextCallbacks)) {
+    let mut map = callbacks().lock().unwrap_or_else(|err| err.into_inner());
+    if map.is_empty() {
+        unsafe { dss_capi::DSS_RegisterMessageCallback(Some(message_callback)) };
+    }
+    update(map.entry(ctx.ctx_ptr as usize).or_default());
+}
+
+/// Removes the message and progress callbacks of a context, unregistering the message
+/// callback from the engine if no context has handlers left.
+fn remove_callbacks(ctx_ptr: *const c_void) {
+    let Some(map) = CALLBACKS.get() else {
+        return;
+    };
+    let mut map = map.lock().unwrap_or_else(|err| err.into_inner());
+    if map.remove(&(ctx_ptr as usize)).is_some() && map.is_empty() {
+        unsafe { dss_capi::DSS_RegisterMessageCallback(None) };
+    }
+}
+
+/// Removes all the callbacks and event handlers of a context. Called when the
+/// context is disposed.
+pub(crate) fn remove_context(ctx_ptr: *const c_void) {
+    remove_callbacks(ctx_ptr);
+    if let Some(map) = EVENT_CALLBACKS.get() {
+        map.lock().unwrap_or_else(|err| err.into_inner()).retain(|(key_ptr, _), _| *key_ptr != ctx_ptr as usize);
+    }
+    if let Some(map) = CLEAR_COUNTERS.get() {
+        map.lock().unwrap_or_else(|err| err.into_inner()).remove(&(ctx_ptr as usize));
+    }
+}
+
+type EventCallback = Arc<dyn Fn(AltDSSEvent, i32) + Send + Sync>;
+type EventCallbackMap = HashMap<(usize, i32), Vec<(u64, EventCallback)>>;
+
+/// Event subscriptions, keyed by the context pointer and the event code
+static EVENT_CALLBACKS: OnceLock<Mutex<EventCallbackMap>> = OnceLock::new();
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(1);
+
+fn event_callbacks() -> &'static Mutex<EventCallbackMap> {
+    EVENT_CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+unsafe extern "C" fn event_callback(ctx: *mut c_void, eventCode: i32, step: i32, _ptr: *mut c_void) {
+    let Ok(event) = AltDSSEvent::try_from(eventCode) else {
+        return;
+    };
+    // As for the messages, the lock is not held while running the handlers
+    let handlers: Vec<EventCallback> = match event_callbacks().lock() {
+        Ok(map) => match map.get(&(ctx as usize, eventCode)) {
+            Some(entries) => entries.iter().map(|(_, cb)| cb.clone()).collect(),
+            None => return,
+        },
+        Err(_) => return,
+    };
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        for cb in handlers {
+            cb(event, step);
+        }
+    }));
+}
+
+/// Adds an event handler for a context, registering the event callback in the
+/// engine if this is the first handler for the event. Returns the subscription id.
+pub(crate) fn subscribe_event(ctx: &DSSContext, event: AltDSSEvent, callback: EventCallback) -> Result<u64, DSSError> {
+    let mut map = event_callbacks().lock().unwrap_or_else(|err| err.into_inner());
+    let key = (ctx.ctx_ptr as usize, event as i32);
+    if !map.contains_key(&key) {
+        let ok = unsafe { dss_capi::ctx_DSSEvents_RegisterAlt(ctx.ctx_ptr, event as i32, Some(event_callback)) != 0 };
+        ctx.DSSError()?;
+        if !ok {
+            return Err(DSSError::wrapper(
+                DSSErrorKind::Callback,
+                format!("Could not register the callback for the event {}", event)
+            ));
+        }
+    }
+    let id = NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed);
+    map.entry(key).or_default().push((id, callback));
+    Ok(id)
+}
+
+/// Removes an event handler, unregistering the event callback from the engine
+/// if there are no handlers left for the event.
+pub(crate) fn unsubscribe_event(ctx: &DSSContext, event: AltDSSEvent, id: u64) {
+    let mut map = event_callbacks().lock().unwrap_or_else(|err| err.into_inner());
+    let key = (ctx.ctx_ptr as usize, event as i32);
+    let Some(entries) = map.get_mut(&key) else {
+        return;
+    };
+    entries.retain(|(entry_id, _)| *entry_id != id);
+    if entries.is_empty() {
+        map.remove(&key);
+        unsafe { dss_capi::ctx_DSSEvents_UnregisterAlt(ctx.ctx_ptr, event as i32, Some(event_callback)) };
+        // Nothing useful to do with an error here; just make sure it is not left pending
+        let _ = ctx.DSSError();
+    }
+}
+
+/// Number of times the circuit of each context was cleared, keyed by the context pointer
+static CLEAR_COUNTERS: OnceLock<Mutex<HashMap<usize, Arc<AtomicU64>>>> = OnceLock::new();
+
+fn clear_counters() -> &'static Mutex<HashMap<usize, Arc<AtomicU64>>> {
+    CLEAR_COUNTERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Tracks whether the circuit of a context was cleared since a handle was created.
+///
+/// Clearing the circuit frees every DSS object, so handles that keep pointers or
+/// names of objects compare their epoch with the current one before each use. The
+/// counter of each context is updated by a single `Clear` event subscription, kept
+/// until the context is disposed.
+#[derive(Clone)]
+pub(crate) struct ClearEpoch {
+    counter: Arc<AtomicU64>,
+    value: u64,
+}
+
+impl ClearEpoch {
+    /// Current epoch of a context, subscribing to the `Clear` event on first use
+    pub(crate) fn current(ctx: &DSSContext) -> Result<Self, DSSError> {
+        let mut map = clear_counters().lock().unwrap_or_else(|err| err.into_inner());
+        let counter = match map.get(&(ctx.ctx_ptr as usize)) {
+            Some(counter) => counter.clone(),
+            None => {
+                let counter = Arc::new(AtomicU64::new(0));
+                let target = counter.clone();
+                subscribe_event(ctx, AltDSSEvent::Clear, Arc::new(move |_event, _step| {
+                    target.fetch_add(1, Ordering::SeqCst);
+                }))?;
+                map.insert(ctx.ctx_ptr as usize, counter.clone());
+                counter
+            }
+        };
+        let value = counter.load(Ordering::SeqCst);
+        Ok(Self { counter: counter, value: value })
+    }
+
+    /// Current epoch of the same context, for handles created from this one
+    pub(crate) fn renew(&self) -> Self {
+        Self {
+            counter: self.counter.clone(),
+            value: self.counter.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Returns a `DSSErrorKind::StaleReference` error if the circuit was cleared since
+    /// this epoch; `what` describes the handle for the message.
+    pub(crate) fn check(&self, what: impl FnOnce() -> String) -> Result<(), DSSError> {
+        if self.counter.load(Ordering::SeqCst) == self.value {
+            return Ok(());
+        }
+        Err(DSSError::wrapper(
+            DSSErrorKind::StaleReference,
+            format!("The circuit was cleared after the {} was created", what())
+        ))
+    }
+}
+
+impl DSSContext {
+    /// Registers a closure to receive the messages emitted by the engine for this context,
+    /// replacing any previous one. The closure can be called from any thread running
+    /// this context.
+    ///
+    /// Note that the engine only emits most messages when forms are allowed, see
+    /// `IDSS::Set_AllowForms`.
+    pub fn SetMessageCallback<F: Fn(&DSSMessage) + Send + Sync + 'static>(&self, callback: F) {
+        update_callbacks(self, |entry| entry.message = Some(Arc::new(callback)));
+    }
+
+    /// Registers a closure to receive the progress updates emitted by the engine for this
+    /// context, replacing any previous one.
+    pub fn SetProgressCallback<F: Fn(&DSSProgress) + Send + Sync + 'static>(&self, callback: F) {
+        update_callbacks(self, |entry| entry.progress = Some(Arc::new(callback)));
+    }
+
+    /// Removes the message and progress callbacks registered for this context.
+    /// This is also done automatically when the context is disposed, i.e. when a
+    /// context other than the Prime one is dropped. The callbacks of the Prime context
+    /// are shared by all its wrappers and stay registered until this is called.
+    pub fn ClearCallbacks(&self) {
+        remove_callbacks(self.ctx_ptr);
+    }
+}
+
+/// Forwards the messages and progress updates of a context to the `log` crate,
+/// using `altdss` as the target.
+#[cfg(feature = "log")]
+pub fn forward_to_log(ctx: &DSSContext) {
+    ctx.SetMessageCallback(|msg| {
+        let level = match msg.severity {
+            DSSMessageSeverity::Error => log::Level::Error,
+            DSSMessageSeverity::General => log::Level::Warn,
+            DSSMessageSeverity::Info => log::Level::Info,
+            DSSMessageSeverity::Help => log::Level::Debug,
+        };
+        log::log!(target: "altdss", level, "{}", msg.text);
+    });
+    ctx.SetProgressCallback(|progress| {
+        log::trace!(target: "altdss", "progress: {:?}", progress);
+    });
+}
+
+/// Forwards the messages and progress updates of a context to the `tracing` crate,
+/// using `altdss` as the target.
+#[cfg(feature = "tracing")]
+pub fn forward_to_tracing(ctx: &DSSContext) {
+    ctx.SetMessageCallback(|msg| {
+        match msg.severity {
+            DSSMessageSeverity::Error => tracing::error!(target: "altdss", "{}", msg.text),
+            DSSMessageSeverity::General => tracing::warn!(target: "altdss", "{}", msg.text),
+            DSSMessageSeverity::Info => tracing::info!(target: "altdss", "{}", msg.text),
+            DSSMessageSeverity::Help => tracing::debug!(target: "altdss", "{}", msg.text),
+        }
+    });
+    ctx.SetProgressCallback(|progress| {
+        tracing::trace!(target: "altdss", ?progress, "progress");
+    });
+}
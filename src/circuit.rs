@@ -0,0 +1,802 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed builder for circuits, as an alternative to writing DSS scripts by hand.
+//!
+//! The definitions are validated as a whole (duplicated names, unknown line codes,
+//! phase and node counts) before anything is sent to the engine:
+//!
+//! ```no_run
+//! use altdss::common::DSSContext;
+//! use altdss::classic::{IDSS, LineUnits};
+//! use altdss::circuit::*;
+//!
+//! let ctx = DSSContext::prime();
+//! let dss = IDSS::new(&ctx);
+//! Circuit::new("feeder", 12.47)
+//!     .LineCode(LineCodeDef::new("lc3", 3, LineUnits::km).Sequence(0.1, 0.3, 0.3, 0.9))
+//!     .Line(LineDef::new("l1", "sourcebus", "b1").LineCode("lc3").Length(2.0, LineUnits::km))
+//!     .Load(LoadDef::new("ld1", "b1.1.2.3", 12.47, 1200.0).kvar(400.0))
+//!     .VoltageBases(&[12.47])
+//!     .Apply(&dss)
+//!     .unwrap();
+//! ```
+//!
+//! Elements without a specific definition type can be added with `Circuit::Command`,
+//! which is not validated.
+
+#![allow(non_snake_case)]
+
+use std::collections::HashSet;
+use std::fmt;
+use crate::classic::{IDSS, LineUnits, LoadModels};
+use crate::common::{DSSError, DSSErrorKind, DSSResultExt};
+use crate::obj::IObj;
+
+/// Reference to a bus, optionally with the list of nodes, e.g. `"632.1.2.3"`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusRef {
+    pub bus: String,
+    pub nodes: Vec<u32>,
+}
+
+impl BusRef {
+    pub fn new(bus: impl Into<String>, nodes: &[u32]) -> Self {
+        Self {
+            bus: bus.into(),
+            nodes: nodes.to_vec(),
+        }
+    }
+}
+
+impl From<&str> for BusRef {
+    /// Parses the DSS notation. If any of the nodes is not a number, the whole text is
+    /// kept as the bus name, which is then rejected in the validation.
+    fn from(text: &str) -> Self {
+        let mut parts = text.split('.');
+        let bus = parts.next().unwrap_or_default();
+        match parts.map(|node| node.trim().parse::<u32>()).collect::<Result<Vec<_>, _>>() {
+            Ok(nodes) => Self::new(bus, &nodes),
+            Err(_) => Self::new(text, &[]),
+        }
+    }
+}
+
+impl From<String> for BusRef {
+    fn from(text: String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl fmt::Display for BusRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.bus)?;
+        for node in &self.nodes {
+            write!(f, ".{}", node)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Connection {
+    Wye,
+    Delta,
+}
+
+impl fmt::Display for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Connection::Wye => write!(f, "wye"),
+            Connection::Delta => write!(f, "delta"),
+        }
+    }
+}
+
+/// Impedance data of a line code, in ohms (and nF for the capacitance) per unit length
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineImpedance {
+    /// Positive and zero sequence values
+    Sequence { r1: f64, x1: f64, r0: f64, x0: f64, c1: Option<f64>, c0: Option<f64> },
+    /// Lower triangles of the matrices, by rows
+    Matrix { r: Vec<f64>, x: Vec<f64>, c: Option<Vec<f64>> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineCodeDef {
+    pub name: String,
+    pub phases: u32,
+    pub units: LineUnits,
+    pub impedance: Option<LineImpedance>,
+}
+
+impl LineCodeDef {
+    pub fn new(name: impl Into<String>, phases: u32, units: LineUnits) -> Self {
+        Self {
+            name: name.into(),
+            phases: phases,
+            units: units,
+            impedance: None,
+        }
+    }
+
+    pub fn Sequence(mut self, r1: f64, x1: f64, r0: f64, x0: f64) -> Self {
+        self.impedance = Some(LineImpedance::Sequence { r1: r1, x1: x1, r0: r0, x0: x0, c1: None, c0: None });
+        self
+    }
+
+    /// Sets the positive and zero sequence capacitances, in nF per unit length;
+    /// requires `Sequence`
+    pub fn SequenceC(mut self, c1: f64, c0: f64) -> Self {
+        if let Some(LineImpedance::Sequence { c1: ref mut value1, c0: ref mut value0, .. }) = self.impedance {
+            *value1 = Some(c1);
+            *value0 = Some(c0);
+        }
+        self
+    }
+
+    /// Sets the lower triangles of the resistance and reactance matrices, by rows
+    pub fn Matrix(mut self, r: &[f64], x: &[f64]) -> Self {
+        self.impedance = Some(LineImpedance::Matrix { r: r.to_vec(), x: x.to_vec(), c: None });
+        self
+    }
+
+    /// Sets the lower triangle of the capacitance matrix, in nF per unit length;
+    /// requires `Matrix`
+    pub fn MatrixC(mut self, c: &[f64]) -> Self {
+        if let Some(LineImpedance::Matrix { c: ref mut value, .. }) = self.impedance {
+            *value = Some(c.to_vec());
+        }
+        self
+    }
+
+    fn Properties(&self) -> Vec<(&'static str, String)> {
+        let mut props = vec![
+            ("nphases", self.phases.to_string()),
+            ("units", units_text(self.units).to_string()),
+        ];
+        match &self.impedance {
+            Some(LineImpedance::Sequence { r1, x1, r0, x0, c1, c0 }) => {
+                props.extend([("r1", r1.to_string()), ("x1", x1.to_string()), ("r0", r0.to_string()), ("x0", x0.to_string())]);
+                if let (Some(c1), Some(c0)) = (c1, c0) {
+                    props.extend([("c1", c1.to_string()), ("c0", c0.to_string())]);
+                }
+            },
+            Some(LineImpedance::Matrix { r, x, c }) => {
+                props.push(("rmatrix", matrix_text(r, self.phases)));
+                props.push(("xmatrix", matrix_text(x, self.phases)));
+                if let Some(c) = c {
+                    props.push(("cmatrix", matrix_text(c, self.phases)));
+                }
+            },
+            None => {},
+        }
+        props
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineDef {
+    pub name: String,
+    pub bus1: BusRef,
+    pub bus2: BusRef,
+    /// Number of phases; defaults to the phases of the line code, or to the number of nodes of the buses
+    pub phases: Option<u32>,
+    pub linecode: Option<String>,
+    pub length: Option<(f64, LineUnits)>,
+    pub switch: bool,
+}
+
+impl LineDef {
+    pub fn new(name: impl Into<String>, bus1: impl Into<BusRef>, bus2: impl Into<BusRef>) -> Self {
+        Self {
+            name: name.into(),
+            bus1: bus1.into(),
+            bus2: bus2.into(),
+            phases: None,
+            linecode: None,
+            length: None,
+            switch: false,
+        }
+    }
+
+    pub fn Phases(mut self, phases: u32) -> Self {
+        self.phases = Some(phases);
+        self
+    }
+
+    /// Name of a line code defined in the same builder
+    pub fn LineCode(mut self, linecode: impl Into<String>) -> Self {
+        self.linecode = Some(linecode.into());
+        self
+    }
+
+    pub fn Length(mut self, length: f64, units: LineUnits) -> Self {
+        self.length = Some((length, units));
+        self
+    }
+
+    pub fn Switch(mut self, switch: bool) -> Self {
+        self.switch = switch;
+        self
+    }
+
+    fn Properties(&self, phases: u32) -> Vec<(&'static str, String)> {
+        let mut props = vec![
+            ("phases", phases.to_string()),
+            ("bus1", self.bus1.to_string()),
+            ("bus2", self.bus2.to_string()),
+        ];
+        if let Some(linecode) = &self.linecode {
+            props.push(("linecode", linecode.clone()));
+        }
+        if let Some((length, units)) = self.length {
+            props.push(("length", length.to_string()));
+            props.push(("units", units_text(units).to_string()));
+        }
+        if self.switch {
+            props.push(("switch", "true".to_string()));
+        }
+        props
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadDef {
+    pub name: String,
+    pub bus1: BusRef,
+    pub phases: u32,
+    pub conn: Connection,
+    pub model: LoadModels,
+    pub kV: f64,
+    pub kW: f64,
+    pub kvar: Option<f64>,
+}
+
+impl LoadDef {
+    /// A three-phase, wye-connected, constant power load
+    pub fn new(name: impl Into<String>, bus1: impl Into<BusRef>, kV: f64, kW: f64) -> Self {
+        Self {
+            name: name.into(),
+            bus1: bus1.into(),
+            phases: 3,
+            conn: Connection::Wye,
+            model: LoadModels::ConstPQ,
+            kV: kV,
+            kW: kW,
+            kvar: None,
+        }
+    }
+
+    pub fn Phases(mut self, phases: u32) -> Self {
+        self.phases = phases;
+        self
+    }
+
+    pub fn Conn(mut self, conn: Connection) -> Self {
+        self.conn = conn;
+        self
+    }
+
+    pub fn Model(mut self, model: LoadModels) -> Self {
+        self.model = model;
+        self
+    }
+
+    pub fn kvar(mut self, kvar: f64) -> Self {
+        self.kvar = Some(kvar);
+        self
+    }
+
+    fn Properties(&self) -> Vec<(&'static str, String)> {
+        let mut props = vec![
+            ("bus1", self.bus1.to_string()),
+            ("phases", self.phases.to_string()),
+            ("conn", self.conn.to_string()),
+            ("model", (self.model as i32).to_string()),
+            ("kV", self.kV.to_string()),
+            ("kW", self.kW.to_string()),
+        ];
+        if let Some(kvar) = self.kvar {
+            props.push(("kvar", kvar.to_string()));
+        }
+        props
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapacitorDef {
+    pub name: String,
+    pub bus1: BusRef,
+    pub phases: u32,
+    pub conn: Connection,
+    pub kV: f64,
+    pub kvar: f64,
+}
+
+impl CapacitorDef {
+    /// A three-phase, wye-connected capacitor bank
+    pub fn new(name: impl Into<String>, bus1: impl Into<BusRef>, kV: f64, kvar: f64) -> Self {
+        Self {
+            name: name.into(),
+            bus1: bus1.into(),
+            phases: 3,
+            conn: Connection::Wye,
+            kV: kV,
+            kvar: kvar,
+        }
+    }
+
+    pub fn Phases(mut self, phases: u32) -> Self {
+        self.phases = phases;
+        self
+    }
+
+    pub fn Conn(mut self, conn: Connection) -> Self {
+        self.conn = conn;
+        self
+    }
+
+    fn Properties(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("bus1", self.bus1.to_string()),
+            ("phases", self.phases.to_string()),
+            ("conn", self.conn.to_string()),
+            ("kV", self.kV.to_string()),
+            ("kvar", self.kvar.to_string()),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindingDef {
+    pub bus: BusRef,
+    pub conn: Connection,
+    pub kV: f64,
+    pub kVA: f64,
+    pub pctR: Option<f64>,
+}
+
+impl WindingDef {
+    /// A wye-connected winding
+    pub fn new(bus: impl Into<BusRef>, kV: f64, kVA: f64) -> Self {
+        Self {
+            bus: bus.into(),
+            conn: Connection::Wye,
+            kV: kV,
+            kVA: kVA,
+            pctR: None,
+        }
+    }
+
+    pub fn Conn(mut self, conn: Connection) -> Self {
+        self.conn = conn;
+        self
+    }
+
+    /// Resistance of the winding, in percent of the base impedance
+    pub fn pctR(mut self, pctR: f64) -> Self {
+        self.pctR = Some(pctR);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransformerDef {
+    pub name: String,
+    pub phases: u32,
+    pub windings: Vec<WindingDef>,
+    /// Reactance between the first two windings, in percent
+    pub XHL: Option<f64>,
+}
+
+impl TransformerDef {
+    /// A three-phase transformer, with the windings added through `Winding`
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            phases: 3,
+            windings: Vec::new(),
+            XHL: None,
+        }
+    }
+
+    pub fn Phases(mut self, phases: u32) -> Self {
+        self.phases = phases;
+        self
+    }
+
+    pub fn Winding(mut self, winding: WindingDef) -> Self {
+        self.windings.push(winding);
+        self
+    }
+
+    pub fn XHL(mut self, XHL: f64) -> Self {
+        self.XHL = Some(XHL);
+        self
+    }
+
+    fn Properties(&self) -> Vec<(&'static str, String)> {
+        let mut props = vec![
+            ("phases", self.phases.to_string()),
+            ("windings", self.windings.len().to_string()),
+        ];
+        for (idx, winding) in self.windings.iter().enumerate() {
+            props.extend([
+                ("wdg", (idx + 1).to_string()),
+                ("bus", winding.bus.to_string()),
+                ("conn", winding.conn.to_string()),
+                ("kV", winding.kV.to_string()),
+                ("kVA", winding.kVA.to_string()),
+            ]);
+            if let Some(pctR) = winding.pctR {
+                props.push(("%R", pctR.to_string()));
+            }
+        }
+        if let Some(XHL) = self.XHL {
+            props.push(("XHL", XHL.to_string()));
+        }
+        props
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ElementDef {
+    LineCode(LineCodeDef),
+    Line(LineDef),
+    Load(LoadDef),
+    Capacitor(CapacitorDef),
+    Transformer(TransformerDef),
+    Command(String),
+}
+
+/// A circuit definition; see the module documentation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Circuit {
+    name: String,
+    base_kV: f64,
+    pu: Option<f64>,
+    angle: Option<f64>,
+    phases: u32,
+    source_bus: BusRef,
+    MVAsc3: Option<f64>,
+    MVAsc1: Option<f64>,
+    base_frequency: Option<f64>,
+    voltage_bases: Vec<f64>,
+    elements: Vec<ElementDef>,
+}
+
+/// A validated element, ready to be applied to the engine
+enum Definition {
+    Object(&'static str, String, Vec<(&'static str, String)>),
+    Command(String),
+}
+
+impl Circuit {
+    /// A circuit with a three-phase source at `sourcebus`
+    pub fn new(name: impl Into<String>, base_kV: f64) -> Self {
+        Self {
+            name: name.into(),
+            base_kV: base_kV,
+            pu: None,
+            angle: None,
+            phases: 3,
+            source_bus: BusRef::from("sourcebus"),
+            MVAsc3: None,
+            MVAsc1: None,
+            base_frequency: None,
+            voltage_bases: Vec::new(),
+            elements: Vec::new(),
+        }
+    }
+
+    /// Voltage of the source, in per unit
+    pub fn pu(mut self, pu: f64) -> Self {
+        self.pu = Some(pu);
+        self
+    }
+
+    pub fn Angle(mut self, angle: f64) -> Self {
+        self.angle = Some(angle);
+        self
+    }
+
+    pub fn Phases(mut self, phases: u32) -> Self {
+        self.phases = phases;
+        self
+    }
+
+    pub fn SourceBus(mut self, bus: impl Into<BusRef>) -> Self {
+        self.source_bus = bus.into();
+        self
+    }
+
+    /// Short-circuit powers of the source, three-phase and single-phase
+    pub fn ShortCircuit(mut self, MVAsc3: f64, MVAsc1: f64) -> Self {
+        self.MVAsc3 = Some(MVAsc3);
+        self.MVAsc1 = Some(MVAsc1);
+        self
+    }
+
+    /// Sets `DefaultBaseFrequency` before creating the circuit
+    pub fn BaseFrequency(mut self, frequency: f64) -> Self {
+        self.base_frequency = Some(frequency);
+        self
+    }
+
+    /// Voltage bases (line-to-line kV) used to compute the bus base voltages after
+    /// creating the elements
+    pub fn VoltageBases(mut self, kV: &[f64]) -> Self {
+        self.voltage_bases = kV.to_vec();
+        self
+    }
+
+    pub fn LineCode(mut self, linecode: LineCodeDef) -> Self {
+        self.elements.push(ElementDef::LineCode(linecode));
+        self
+    }
+
+    pub fn Line(mut self, line: LineDef) -> Self {
+        self.elements.push(ElementDef::Line(line));
+        self
+    }
+
+    pub fn Load(mut self, load: LoadDef) -> Self {
+        self.elements.push(ElementDef::Load(load));
+        self
+    }
+
+    pub fn Capacitor(mut self, capacitor: CapacitorDef) -> Self {
+        self.elements.push(ElementDef::Capacitor(capacitor));
+        self
+    }
+
+    pub fn Transformer(mut self, transformer: TransformerDef) -> Self {
+        self.elements.push(ElementDef::Transformer(transformer));
+        self
+    }
+
+    /// Adds a DSS command, run in order with the other elements. Commands are not validated.
+    pub fn Command(mut self, command: impl Into<String>) -> Self {
+        self.elements.push(ElementDef::Command(command.into()));
+        self
+    }
+
+    /// Checks the whole definition, returning the first problem found
+    pub fn Validate(&self) -> Result<(), DSSError> {
+        self.Definitions().map(|_| ())
+    }
+
+    /// Returns the DSS script for the circuit, one command per item, after validating it
+    pub fn Script(&self) -> Result<Vec<String>, DSSError> {
+        let mut script = self.Preamble();
+        for definition in self.Definitions().origin("Circuit::Script")? {
+            script.push(match definition {
+                Definition::Object(class_name, name, props) => {
+                    let props: Vec<String> = props.iter().map(|(prop, value)| format!("{}={}", prop, value)).collect();
+                    format!("new {}.{} {}", class_name, name, props.join(" "))
+                },
+                Definition::Command(command) => command,
+            });
+        }
+        script.extend(self.Postamble());
+        Ok(script)
+    }
+
+    /// Clears the context and creates the circuit, through `IText::Commands`
+    pub fn Apply(&self, dss: &IDSS) -> Result<(), DSSError> {
+        let script = self.Script().origin("Circuit::Apply")?;
+        dss.ClearAll().origin("Circuit::Apply")?;
+        dss.Text.Commands(&script).origin("Circuit::Apply")
+    }
+
+    /// Clears the context and creates the circuit. The elements are created through the
+    /// object API (`obj::IObj`), setting each property individually.
+    pub fn ApplyObjects(&self, dss: &IDSS) -> Result<(), DSSError> {
+        let definitions = self.Definitions().origin("Circuit::ApplyObjects")?;
+        dss.ClearAll().origin("Circuit::ApplyObjects")?;
+        dss.Text.Commands(&self.Preamble()).origin("Circuit::ApplyObjects")?;
+        let obj = IObj::new(dss.ctx);
+        for definition in definitions {
+            match definition {
+                Definition::Object(class_name, name, props) => {
+                    let element = obj.New(class_name, &name).origin("Circuit::ApplyObjects")?;
+                    element.BeginEdit().origin("Circuit::ApplyObjects")?;
+                    for (prop, value) in &props {
+                        if let Err(err) = element.SetAsString(*prop, value) {
+                            let _ = element.EndEdit(0);
+                            return Err(err).origin("Circuit::ApplyObjects");
+                        }
+                    }
+                    element.EndEdit(props.len() as i32).origin("Circuit::ApplyObjects")?;
+                },
+                Definition::Command(command) => dss.Command(command).origin("Circuit::ApplyObjects")?,
+            }
+        }
+        dss.Text.Commands(&self.Postamble()).origin("Circuit::ApplyObjects")
+    }
+
+    fn Preamble(&self) -> Vec<String> {
+        let mut script = Vec::new();
+        if let Some(frequency) = self.base_frequency {
+            script.push(format!("set DefaultBaseFrequency={}", frequency));
+        }
+        let mut source = format!("new circuit.{} basekV={} phases={} bus1={}", self.name, self.base_kV, self.phases, self.source_bus);
+        if let Some(pu) = self.pu {
+            source += &format!(" pu={}", pu);
+        }
+        if let Some(angle) = self.angle {
+            source += &format!(" angle={}", angle);
+        }
+        if let (Some(MVAsc3), Some(MVAsc1)) = (self.MVAsc3, self.MVAsc1) {
+            source += &format!(" MVAsc3={} MVAsc1={}", MVAsc3, MVAsc1);
+        }
+        script.push(source);
+        script
+    }
+
+    fn Postamble(&self) -> Vec<String> {
+        if self.voltage_bases.is_empty() {
+            return Vec::new();
+        }
+        let kV: Vec<String> = self.voltage_bases.iter().map(|kV| kV.to_string()).collect();
+        vec![format!("set voltagebases=[{}]", kV.join(" ")), "calcvoltagebases".to_string()]
+    }
+
+    /// Validates the elements and resolves their properties
+    fn Definitions(&self) -> Result<Vec<Definition>, DSSError> {
+        check_name("Circuit", &self.name)?;
+        check(self.base_kV > 0.0, "Circuit", &self.name, "the base voltage must be positive")?;
+        check_bus("Circuit", &self.name, &self.source_bus, self.phases, Connection::Wye)?;
+        for kV in &self.voltage_bases {
+            check(*kV > 0.0, "Circuit", &self.name, "the voltage bases must be positive")?;
+        }
+
+        let mut names: HashSet<(&'static str, String)> = HashSet::new();
+        let mut linecodes: Vec<&LineCodeDef> = Vec::new();
+        let mut definitions = Vec::with_capacity(self.elements.len());
+        for element in &self.elements {
+            let definition = match element {
+                ElementDef::LineCode(def) => {
+                    check_element(&mut names, "LineCode", &def.name, def.phases)?;
+                    if let Some(LineImpedance::Matrix { r, x, c }) = &def.impedance {
+                        let size = (def.phases * (def.phases + 1) / 2) as usize;
+                        let sizes_ok = r.len() == size && x.len() == size && c.iter().all(|c| c.len() == size);
+                        check(sizes_ok, "LineCode", &def.name, &format!("the matrices for {} phases need {} values (lower triangle)", def.phases, size))?;
+                    }
+                    linecodes.push(def);
+                    Definition::Object("LineCode", def.name.clone(), def.Properties())
+                },
+                ElementDef::Line(def) => {
+                    let linecode = match &def.linecode {
+                        Some(name) => Some(*linecodes.iter().find(|linecode| linecode.name.eq_ignore_ascii_case(name)).ok_or_else(||
+                            invalid("Line", &def.name, &format!("the line code \"{}\" is not defined before the line", name))
+                        )?),
+                        None => None,
+                    };
+                    let phases = def.phases
+                        .or(linecode.map(|linecode| linecode.phases))
+                        .unwrap_or_else(|| def.bus1.nodes.len().max(def.bus2.nodes.len()).max(1) as u32);
+                    check_element(&mut names, "Line", &def.name, phases)?;
+                    if let Some(linecode) = linecode {
+                        check(linecode.phases == phases, "Line", &def.name, &format!("the line has {} phases, but the line code \"{}\" has {}", phases, linecode.name, linecode.phases))?;
+                    }
+                    check(!def.bus1.bus.eq_ignore_ascii_case(&def.bus2.bus), "Line", &def.name, "both terminals are connected to the same bus")?;
+                    for bus in [&def.bus1, &def.bus2] {
+                        check_bus("Line", &def.name, bus, phases, Connection::Wye)?;
+                    }
+                    if let Some((length, _)) = def.length {
+                        check(length > 0.0, "Line", &def.name, "the length must be positive")?;
+                    }
+                    Definition::Object("Line", def.name.clone(), def.Properties(phases))
+                },
+                ElementDef::Load(def) => {
+                    check_element(&mut names, "Load", &def.name, def.phases)?;
+                    check_bus("Load", &def.name, &def.bus1, def.phases, def.conn)?;
+                    check(def.kV > 0.0, "Load", &def.name, "the voltage must be positive")?;
+                    let powers_ok = def.kW.is_finite() && def.kvar.is_none_or(f64::is_finite);
+                    check(powers_ok, "Load", &def.name, "the kW and kvar values must be finite numbers")?;
+                    Definition::Object("Load", def.name.clone(), def.Properties())
+                },
+                ElementDef::Capacitor(def) => {
+                    check_element(&mut names, "Capacitor", &def.name, def.phases)?;
+                    check_bus("Capacitor", &def.name, &def.bus1, def.phases, def.conn)?;
+                    check(def.kV > 0.0, "Capacitor", &def.name, "the voltage must be positive")?;
+                    check(def.kvar.is_finite(), "Capacitor", &def.name, "the kvar value must be a finite number")?;
+                    Definition::Object("Capacitor", def.name.clone(), def.Properties())
+                },
+                ElementDef::Transformer(def) => {
+                    check_element(&mut names, "Transformer", &def.name, def.phases)?;
+                    check(def.windings.len() >= 2, "Transformer", &def.name, "at least two windings are required")?;
+                    for winding in &def.windings {
+                        check_bus("Transformer", &def.name, &winding.bus, def.phases, winding.conn)?;
+                        check(winding.kV > 0.0 && winding.kVA > 0.0, "Transformer", &def.name, "the winding voltages and powers must be positive")?;
+                    }
+                    Definition::Object("Transformer", def.name.clone(), def.Properties())
+                },
+                ElementDef::Command(command) => Definition::Command(command.clone()),
+            };
+            definitions.push(definition);
+        }
+        Ok(definitions)
+    }
+}
+
+fn invalid(class_name: &str, name: &str, message: &str) -> DSSError {
+    DSSError::wrapper(DSSErrorKind::InvalidArgument, format!("{}.{}: {}", class_name, name, message))
+}
+
+fn check(condition: bool, class_name: &str, name: &str, message: &str) -> Result<(), DSSError> {
+    if condition { Ok(()) } else { Err(invalid(class_name, name, message)) }
+}
+
+fn check_name(class_name: &str, name: &str) -> Result<(), DSSError> {
+    let valid = !name.is_empty() && !name.contains(|c: char| c.is_whitespace() || c == '.' || c == '=');
+    check(valid, class_name, name, "invalid name")
+}
+
+fn check_element(names: &mut HashSet<(&'static str, String)>, class_name: &'static str, name: &str, phases: u32) -> Result<(), DSSError> {
+    check_name(class_name, name)?;
+    check(names.insert((class_name, name.to_lowercase())), class_name, name, "duplicated name")?;
+    check(phases > 0, class_name, name, "the number of phases must be positive")
+}
+
+/// Checks the nodes of a terminal. Single-phase delta elements connect two nodes; otherwise,
+/// a terminal can list one node per phase, plus the neutral for wye connections.
+fn check_bus(class_name: &str, name: &str, bus: &BusRef, phases: u32, conn: Connection) -> Result<(), DSSError> {
+    let valid_name = !bus.bus.is_empty() && !bus.bus.contains(|c: char| c.is_whitespace() || c == '.' || c == '=');
+    check(valid_name, class_name, name, &format!("invalid bus name \"{}\"", bus.bus))?;
+    if bus.nodes.is_empty() {
+        return Ok(());
+    }
+    let num_nodes = bus.nodes.len() as u32;
+    let valid_count = match conn {
+        Connection::Delta if phases == 1 => num_nodes == 2,
+        Connection::Delta => num_nodes == phases,
+        Connection::Wye => num_nodes == phases || num_nodes == phases + 1,
+    };
+    check(valid_count, class_name, name, &format!("the bus \"{}\" has {} nodes for {} phases ({})", bus, num_nodes, phases, conn))?;
+    let mut seen = HashSet::new();
+    let unique = bus.nodes.iter().filter(|node| **node != 0).all(|node| seen.insert(*node));
+    check(unique, class_name, name, &format!("repeated nodes in the bus \"{}\"", bus))
+}
+
+fn units_text(units: LineUnits) -> &'static str {
+    match units {
+        LineUnits::none => "none",
+        LineUnits::Miles => "mi",
+        LineUnits::kFt => "kft",
+        LineUnits::km => "km",
+        LineUnits::meter => "m",
+        LineUnits::ft => "ft",
+        LineUnits::inch => "in",
+        LineUnits::cm => "cm",
+        LineUnits::mm => "mm",
+    }
+}
+
+/// Formats the lower triangle of a matrix in the DSS notation, e.g. `[1 | 2 3]`
+fn matrix_text(values: &[f64], order: u32) -> String {
+    let mut rows = Vec::with_capacity(order as usize);
+    let mut start = 0;
+    for row in 1..=order as usize {
+        let end = (start + row).min(values.len());
+        let row: Vec<String> = values[start..end].iter().map(|v| v.to_string()).collect();
+        rows.push(row.join(" "));
+        start = end;
+    }
+    format!("[{}]", rows.join(" | "))
+}
@@ -19,247 +19,332 @@
 #![allow(unused_parens)]
 
 use crate::dss_capi;
-use crate::common::{DSSContext, DSSError};
-use std::ffi::{c_char, c_void, CStr, CString};
-use std::mem::transmute;
+use crate::callbacks;
+use crate::common::{DSSContext, DSSError, DSSErrorKind, DSSResultExt, GRView};
+use std::ffi::{c_char, c_void, CStr};
+use std::fmt;
+use std::sync::Arc;
 use num_complex::Complex;
 
-#[allow(non_snake_case)]
-
-//TODO: for enums, avoid transmute: https://stackoverflow.com/a/76785380
-
-#[repr(i32)]
-pub enum ActionCodes {
-	none = 0,
-	Open = 1,
-	Close = 2,
-	Reset = 3,
-	Lock = 4,
-	Unlock = 5,
-	TapUp = 6,
-	TapDown = 7,
-}
-
-/// Event codes used by the event callback system
-///
-/// Legacy events are the events present the classic OpenDSS COM implementation,
-/// while the rest are extensions added here.
-#[repr(i32)]
-pub enum AltDSSEvent {
-	Legacy_InitControls = 0,
-	Legacy_CheckControls = 1,
-	Legacy_StepControls = 2,
-	Clear = 3,
-	ReprocessBuses = 4,
-	BuildSystemY = 5,
-}
-
-#[repr(i32)]
-pub enum AutoAddTypes {
-	AddGen = 1,
-	AddCap = 2,
-}
-
-#[repr(i32)]
-pub enum CapControlModes {
-	Current = 0,
-	Voltage = 1,
-	KVAR = 2,
-	Time = 3,
-	PF = 4,
-}
-
-#[repr(i32)]
-pub enum CktModels {
-	Multiphase = 0,
-	PositiveSeq = 1,
-}
-
-#[repr(i32)]
-pub enum ControlModes {
-	Static = 0,
-	Event = 1,
-	Time = 2,
-	Multirate = 3,
-	Off = -1,
-}
-
-/// Transformer Core Type
-#[repr(i32)]
-pub enum CoreType {
-	shell = 0,
-	one_phase = 1,
-	three_leg = 3,
-	four_leg = 4,
-	five_leg = 5,
-	core_1_phase = 9,
-}
-
-#[repr(i32)]
-pub enum DSSCompatFlags {
-	NoSolverFloatChecks = 1,
-	BadPrecision = 2,
-	InvControl9611 = 4,
-	SaveCalcVoltageBases = 8,
-	ActiveLine = 16,
-	NoPropertyTracking = 32,
-	SkipSideEffects = 64,
-}
-
-#[repr(i32)]
-pub enum DSSJSONFlags {
-	Full = 1,
-	SkipRedundant = 2,
-	EnumAsInt = 4,
-	FullNames = 8,
-	Pretty = 16,
-	ExcludeDisabled = 32,
-	SkipDSSClass = 64,
-	LowercaseKeys = 128,
-	IncludeDefaultObjs = 256,
-	SkipTimestamp = 512,
-	SkipBuses = 1024,
-}
-
-/// This enum is used in the PropertyNameStyle property to control the naming convention.
-/// Currently, this only affects capitalization, i.e., if your software already uses case
-/// insensitive string comparisons for the property names, this is not useful. Otherwise,
-/// you can use `Legacy` to use the older names.
-#[repr(i32)]
-pub enum DSSPropertyNameStyle {
-	Modern = 0,
-	Lowercase = 1,
-	Legacy = 2,
-}
-
-#[repr(i32)]
-pub enum GeneratorStatus {
-	Variable = 0,
-	Fixed = 1,
-}
-
-#[repr(i32)]
-pub enum LineUnits {
-	none = 0,
-	Miles = 1,
-	kFt = 2,
-	km = 3,
-	meter = 4,
-	ft = 5,
-	inch = 6,
-	cm = 7,
-	mm = 8,
-}
-
-#[repr(i32)]
-pub enum LoadModels {
-	ConstPQ = 1,
-	ConstZ = 2,
-	Motor = 3,
-	CVR = 4,
-	ConstI = 5,
-	ConstPFixedQ = 6,
-	ConstPFixedX = 7,
-	ZIPV = 8,
-}
-
-#[repr(i32)]
-pub enum LoadStatus {
-	Variable = 0,
-	Fixed = 1,
-	Exempt = 2,
-}
-
-#[repr(i32)]
-pub enum MonitorModes {
-	VI = 0,
-	Power = 1,
-	Taps = 2,
-	States = 3,
-	Sequence = 16,
-	Magnitude = 32,
-	PosOnly = 64,
-}
-
-/// Overcurrent Protection Device Type
-#[repr(i32)]
-pub enum OCPDevType {
-	none = 0,
-	Fuse = 1,
-	Recloser = 2,
-	Relay = 3,
-}
-
-/// Deprecated. Please use instead:
-/// - AutoAddTypes
-/// - CktModels
-/// - ControlModes
-/// - SolutionLoadModels
-/// - SolutionAlgorithms
-/// - RandomModes
-#[repr(i32)]
-pub enum Options {
-	PowerFlow = 1,
-	Admittance = 2,
-	NormalSolve = 0,
-	LogNormal = 3,
-	ControlOFF = -1,
-}
-
-#[repr(i32)]
-pub enum RandomModes {
-	Gaussian = 1,
-	Uniform = 2,
-	LogNormal = 3,
-}
-
-#[repr(i32)]
-pub enum SolutionAlgorithms {
-	NormalSolve = 0,
-	NewtonSolve = 1,
-}
-
-#[repr(i32)]
-pub enum SolutionLoadModels {
-	PowerFlow = 1,
-	Admittance = 2,
-}
-
-#[repr(i32)]
-pub enum SolveModes {
-	SnapShot = 0,
-	Daily = 1,
-	Yearly = 2,
-	Monte1 = 3,
-	LD1 = 4,
-	PeakDay = 5,
-	DutyCycle = 6,
-	Direct = 7,
-	MonteFault = 8,
-	FaultStudy = 9,
-	Monte2 = 10,
-	Monte3 = 11,
-	LD2 = 12,
-	AutoAdd = 13,
-	Dynamic = 14,
-	Harmonic = 15,
-	Time = 16,
-	HarmonicT = 17,
-}
-
-#[repr(i32)]
-pub enum SparseSolverOptions {
-	ReuseNothing = 0,
-	ReuseCompressedMatrix = 1,
-	ReuseSymbolicFactorization = 2,
-	ReuseNumericFactorization = 3,
-	AlwaysResetYPrimInvalid = 268435456,
-}
-
-#[repr(i32)]
-pub enum YMatrixModes {
-	SeriesOnly = 1,
-	WholeMatrix = 2,
+/// Declares a `#[repr(i32)]` enum used by the DSS engine, together with a checked
+/// `TryFrom<i32>` conversion (used to decode the integer codes returned by the engine)
+/// and a `Display` implementation that outputs the variant name.
+macro_rules! dss_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident = $value:expr,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(i32)]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum $name {
+            $($variant = $value,)*
+        }
+
+        impl TryFrom<i32> for $name {
+            type Error = DSSError;
+
+            fn try_from(value: i32) -> Result<Self, Self::Error> {
+                match value {
+                    $(v if v == $name::$variant as i32 => Ok($name::$variant),)*
+                    _ => Err(DSSError::wrapper(
+                        DSSErrorKind::InvalidEnumValue,
+                        format!("Unknown value for enum {}: {}", stringify!($name), value),
+                    )),
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let name = match self {
+                    $($name::$variant => stringify!($variant),)*
+                };
+                write!(f, "{}", name)
+            }
+        }
+
+        impl From<$name> for i32 {
+            fn from(value: $name) -> i32 {
+                value as i32
+            }
+        }
+    };
+}
+
+dss_enum! {
+	pub enum ActionCodes {
+		none = 0,
+		Open = 1,
+		Close = 2,
+		Reset = 3,
+		Lock = 4,
+		Unlock = 5,
+		TapUp = 6,
+		TapDown = 7,
+	}
+}
+
+dss_enum! {
+	/// Event codes used by the event callback system
+	///
+	/// Legacy events are the events present the classic OpenDSS COM implementation,
+	/// while the rest are extensions added here.
+	pub enum AltDSSEvent {
+		Legacy_InitControls = 0,
+		Legacy_CheckControls = 1,
+		Legacy_StepControls = 2,
+		Clear = 3,
+		ReprocessBuses = 4,
+		BuildSystemY = 5,
+	}
+}
+
+dss_enum! {
+	pub enum AutoAddTypes {
+		AddGen = 1,
+		AddCap = 2,
+	}
+}
+
+dss_enum! {
+	pub enum CapControlModes {
+		Current = 0,
+		Voltage = 1,
+		KVAR = 2,
+		Time = 3,
+		PF = 4,
+	}
+}
+
+dss_enum! {
+	pub enum CktModels {
+		Multiphase = 0,
+		PositiveSeq = 1,
+	}
+}
+
+dss_enum! {
+	pub enum ControlModes {
+		Static = 0,
+		Event = 1,
+		Time = 2,
+		Multirate = 3,
+		Off = -1,
+	}
+}
+
+dss_enum! {
+	/// Transformer Core Type
+	pub enum CoreType {
+		shell = 0,
+		one_phase = 1,
+		three_leg = 3,
+		four_leg = 4,
+		five_leg = 5,
+		core_1_phase = 9,
+	}
+}
+
+dss_enum! {
+	pub enum DSSCompatFlags {
+		NoSolverFloatChecks = 1,
+		BadPrecision = 2,
+		InvControl9611 = 4,
+		SaveCalcVoltageBases = 8,
+		ActiveLine = 16,
+		NoPropertyTracking = 32,
+		SkipSideEffects = 64,
+	}
+}
+
+dss_enum! {
+	pub enum DSSJSONFlags {
+		Full = 1,
+		SkipRedundant = 2,
+		EnumAsInt = 4,
+		FullNames = 8,
+		Pretty = 16,
+		ExcludeDisabled = 32,
+		SkipDSSClass = 64,
+		LowercaseKeys = 128,
+		IncludeDefaultObjs = 256,
+		SkipTimestamp = 512,
+		SkipBuses = 1024,
+	}
+}
+
+dss_enum! {
+	/// Flags for `ICircuit::Save`
+	pub enum DSSSaveFlags {
+		CalcVoltageBases = 0x0001,
+		SetVoltageBases = 0x0002,
+		IncludeOptions = 0x0004,
+		IncludeDisabled = 0x0008,
+		ExcludeDefault = 0x0010,
+		SingleFile = 0x0020,
+		KeepOrder = 0x0040,
+		ExcludeMeterZones = 0x0080,
+		IsOpen = 0x0100,
+		ToString = 0x0200,
+	}
+}
+
+dss_enum! {
+	/// This enum is used in the PropertyNameStyle property to control the naming convention.
+	/// Currently, this only affects capitalization, i.e., if your software already uses case
+	/// insensitive string comparisons for the property names, this is not useful. Otherwise,
+	/// you can use `Legacy` to use the older names.
+	pub enum DSSPropertyNameStyle {
+		Modern = 0,
+		Lowercase = 1,
+		Legacy = 2,
+	}
+}
+
+dss_enum! {
+	pub enum GeneratorStatus {
+		Variable = 0,
+		Fixed = 1,
+	}
+}
+
+dss_enum! {
+	pub enum LineUnits {
+		none = 0,
+		Miles = 1,
+		kFt = 2,
+		km = 3,
+		meter = 4,
+		ft = 5,
+		inch = 6,
+		cm = 7,
+		mm = 8,
+	}
+}
+
+dss_enum! {
+	pub enum LoadModels {
+		ConstPQ = 1,
+		ConstZ = 2,
+		Motor = 3,
+		CVR = 4,
+		ConstI = 5,
+		ConstPFixedQ = 6,
+		ConstPFixedX = 7,
+		ZIPV = 8,
+	}
+}
+
+dss_enum! {
+	pub enum LoadStatus {
+		Variable = 0,
+		Fixed = 1,
+		Exempt = 2,
+	}
+}
+
+dss_enum! {
+	pub enum MonitorModes {
+		VI = 0,
+		Power = 1,
+		Taps = 2,
+		States = 3,
+		Sequence = 16,
+		Magnitude = 32,
+		PosOnly = 64,
+	}
+}
+
+dss_enum! {
+	/// Overcurrent Protection Device Type
+	pub enum OCPDevType {
+		none = 0,
+		Fuse = 1,
+		Recloser = 2,
+		Relay = 3,
+	}
+}
+
+dss_enum! {
+	/// Deprecated. Please use instead:
+	/// - AutoAddTypes
+	/// - CktModels
+	/// - ControlModes
+	/// - SolutionLoadModels
+	/// - SolutionAlgorithms
+	/// - RandomModes
+	pub enum Options {
+		PowerFlow = 1,
+		Admittance = 2,
+		NormalSolve = 0,
+		LogNormal = 3,
+		ControlOFF = -1,
+	}
+}
+
+dss_enum! {
+	pub enum RandomModes {
+		Gaussian = 1,
+		Uniform = 2,
+		LogNormal = 3,
+	}
+}
+
+dss_enum! {
+	pub enum SolutionAlgorithms {
+		NormalSolve = 0,
+		NewtonSolve = 1,
+	}
+}
+
+dss_enum! {
+	pub enum SolutionLoadModels {
+		PowerFlow = 1,
+		Admittance = 2,
+	}
+}
+
+dss_enum! {
+	pub enum SolveModes {
+		SnapShot = 0,
+		Daily = 1,
+		Yearly = 2,
+		Monte1 = 3,
+		LD1 = 4,
+		PeakDay = 5,
+		DutyCycle = 6,
+		Direct = 7,
+		MonteFault = 8,
+		FaultStudy = 9,
+		Monte2 = 10,
+		Monte3 = 11,
+		LD2 = 12,
+		AutoAdd = 13,
+		Dynamic = 14,
+		Harmonic = 15,
+		Time = 16,
+		HarmonicT = 17,
+	}
+}
+
+dss_enum! {
+	pub enum SparseSolverOptions {
+		ReuseNothing = 0,
+		ReuseCompressedMatrix = 1,
+		ReuseSymbolicFactorization = 2,
+		ReuseNumericFactorization = 3,
+		AlwaysResetYPrimInvalid = 268435456,
+	}
+}
+
+dss_enum! {
+	pub enum YMatrixModes {
+		SeriesOnly = 1,
+		WholeMatrix = 2,
+	}
 }
 
 fn bool_to_u16(v: bool) -> u16 {
@@ -290,7 +375,7 @@ impl<'a> IBus<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Bus_Get_AllPCEatBus(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IBus::AllPCEatBus")
     }
 
     /// Returns an array with the names of all PDE connected to the active bus
@@ -298,233 +383,417 @@ impl<'a> IBus<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Bus_Get_AllPDEatBus(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IBus::AllPDEatBus")
     }
 
     pub fn GetUniqueNodeNumber(&self, StartNumber: i32) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_GetUniqueNodeNumber(self.ctx_ptr, StartNumber) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::GetUniqueNodeNumber")?;
         Ok(result)
     }
 
     /// Refreshes the Zsc matrix for the active bus.
     pub fn ZscRefresh(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Bus_ZscRefresh(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::ZscRefresh")?;
         Ok(result)
     }
 
     /// Indicates whether a coordinate has been defined for this bus
     pub fn Coorddefined(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Bus_Get_Coorddefined(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::Coorddefined")?;
         Ok(result)
     }
 
     /// Complex Double array of Sequence Voltages (0, 1, 2) at this Bus.
     pub fn CplxSeqVoltages(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::CplxSeqVoltages")?;
+        unsafe { dss_capi::ctx_Bus_Get_CplxSeqVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("IBus::CplxSeqVoltages")
+    }
+
+    pub fn CplxSeqVoltages_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::CplxSeqVoltages_view")?;
         unsafe { dss_capi::ctx_Bus_Get_CplxSeqVoltages_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRView().origin("IBus::CplxSeqVoltages_view")
+    }
+
+    pub fn CplxSeqVoltages_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::CplxSeqVoltages_into")?;
+        unsafe { dss_capi::ctx_Bus_Get_CplxSeqVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("IBus::CplxSeqVoltages_into")
     }
 
     /// Accumulated customer outage durations
     pub fn Cust_Duration(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_Cust_Duration(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::Cust_Duration")?;
         Ok(result)
     }
 
     /// Annual number of customer-interruptions from this bus
     pub fn Cust_Interrupts(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_Cust_Interrupts(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::Cust_Interrupts")?;
         Ok(result)
     }
 
     /// Distance from energymeter (if non-zero)
     pub fn Distance(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_Distance(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::Distance")?;
         Ok(result)
     }
 
     /// Average interruption duration, hr.
     pub fn Int_Duration(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_Int_Duration(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::Int_Duration")?;
         Ok(result)
     }
 
     /// Short circuit currents at bus; Complex Array.
     pub fn Isc(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Isc")?;
+        unsafe { dss_capi::ctx_Bus_Get_Isc_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("IBus::Isc")
+    }
+
+    pub fn Isc_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Isc_view")?;
         unsafe { dss_capi::ctx_Bus_Get_Isc_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRView().origin("IBus::Isc_view")
+    }
+
+    pub fn Isc_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Isc_into")?;
+        unsafe { dss_capi::ctx_Bus_Get_Isc_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("IBus::Isc_into")
     }
 
     /// Accumulated failure rate downstream from this bus; faults per year
     pub fn Lambda(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_Lambda(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::Lambda")?;
         Ok(result)
     }
 
     /// Total numbers of customers served downline from this bus
     pub fn N_Customers(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_N_Customers(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::N_Customers")?;
         Ok(result)
     }
 
     /// Number of interruptions this bus per year
     pub fn N_interrupts(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_N_interrupts(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::N_interrupts")?;
         Ok(result)
     }
 
     /// Name of Bus
     pub fn Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Bus_Get_Name(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::Name")?;
         Ok(result)
     }
 
     /// Integer Array of Node Numbers defined at the bus in same order as the voltages.
     pub fn Nodes(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Nodes")?;
         unsafe { dss_capi::ctx_Bus_Get_Nodes_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGR().origin("IBus::Nodes")
+    }
+
+    pub fn Nodes_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Nodes_view")?;
+        unsafe { dss_capi::ctx_Bus_Get_Nodes_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRView().origin("IBus::Nodes_view")
+    }
+
+    pub fn Nodes_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Nodes_into")?;
+        unsafe { dss_capi::ctx_Bus_Get_Nodes_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRInto(out).origin("IBus::Nodes_into")
     }
 
     /// Number of Nodes this bus.
     pub fn NumNodes(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_NumNodes(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::NumNodes")?;
         Ok(result)
     }
 
     /// Integer ID of the feeder section in which this bus is located.
     pub fn SectionID(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_SectionID(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::SectionID")?;
         Ok(result)
     }
 
     /// Double Array of sequence voltages at this bus. Magnitudes only.
     pub fn SeqVoltages(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::SeqVoltages")?;
         unsafe { dss_capi::ctx_Bus_Get_SeqVoltages_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("IBus::SeqVoltages")
+    }
+
+    pub fn SeqVoltages_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::SeqVoltages_view")?;
+        unsafe { dss_capi::ctx_Bus_Get_SeqVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IBus::SeqVoltages_view")
+    }
+
+    pub fn SeqVoltages_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::SeqVoltages_into")?;
+        unsafe { dss_capi::ctx_Bus_Get_SeqVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IBus::SeqVoltages_into")
     }
 
     /// Total length of line downline from this bus, in miles. For recloser siting algorithm.
     pub fn TotalMiles(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_TotalMiles(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::TotalMiles")?;
         Ok(result)
     }
 
     /// For 2- and 3-phase buses, returns array of complex numbers represetin L-L voltages in volts. Returns -1.0 for 1-phase bus. If more than 3 phases, returns only first 3.
     pub fn VLL(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::VLL")?;
         unsafe { dss_capi::ctx_Bus_Get_VLL_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGR().origin("IBus::VLL")
+    }
+
+    pub fn VLL_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::VLL_view")?;
+        unsafe { dss_capi::ctx_Bus_Get_VLL_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("IBus::VLL_view")
+    }
+
+    pub fn VLL_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::VLL_into")?;
+        unsafe { dss_capi::ctx_Bus_Get_VLL_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("IBus::VLL_into")
     }
 
     /// Array of doubles containing voltages in Magnitude (VLN), angle (degrees)
     pub fn VMagAngle(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::VMagAngle")?;
+        unsafe { dss_capi::ctx_Bus_Get_VMagAngle_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IBus::VMagAngle")
+    }
+
+    pub fn VMagAngle_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::VMagAngle_view")?;
+        unsafe { dss_capi::ctx_Bus_Get_VMagAngle_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IBus::VMagAngle_view")
+    }
+
+    pub fn VMagAngle_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::VMagAngle_into")?;
         unsafe { dss_capi::ctx_Bus_Get_VMagAngle_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IBus::VMagAngle_into")
     }
 
     /// Open circuit voltage; Complex array.
     pub fn Voc(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Voc")?;
+        unsafe { dss_capi::ctx_Bus_Get_Voc_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("IBus::Voc")
+    }
+
+    pub fn Voc_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Voc_view")?;
         unsafe { dss_capi::ctx_Bus_Get_Voc_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRView().origin("IBus::Voc_view")
+    }
+
+    pub fn Voc_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Voc_into")?;
+        unsafe { dss_capi::ctx_Bus_Get_Voc_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("IBus::Voc_into")
     }
 
     /// Complex array of voltages at this bus.
     pub fn Voltages(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Voltages")?;
         unsafe { dss_capi::ctx_Bus_Get_Voltages_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGR().origin("IBus::Voltages")
+    }
+
+    pub fn Voltages_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Voltages_view")?;
+        unsafe { dss_capi::ctx_Bus_Get_Voltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("IBus::Voltages_view")
+    }
+
+    pub fn Voltages_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Voltages_into")?;
+        unsafe { dss_capi::ctx_Bus_Get_Voltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("IBus::Voltages_into")
     }
 
     /// Complex array of Ysc matrix at bus. Column by column.
     pub fn YscMatrix(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::YscMatrix")?;
+        unsafe { dss_capi::ctx_Bus_Get_YscMatrix_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("IBus::YscMatrix")
+    }
+
+    pub fn YscMatrix_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::YscMatrix_view")?;
+        unsafe { dss_capi::ctx_Bus_Get_YscMatrix_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("IBus::YscMatrix_view")
+    }
+
+    pub fn YscMatrix_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::YscMatrix_into")?;
         unsafe { dss_capi::ctx_Bus_Get_YscMatrix_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("IBus::YscMatrix_into")
     }
 
     /// Complex Zero-Sequence short circuit impedance at bus.
     pub fn Zsc0(&self) -> Result<Complex<f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Zsc0")?;
         unsafe { dss_capi::ctx_Bus_Get_Zsc0_GR(self.ctx_ptr) };
-        self.ctx.GetComplexSimpleGR()
+        self.ctx.GetComplexSimpleGR().origin("IBus::Zsc0")
     }
 
     /// Complex Positive-Sequence short circuit impedance at bus.
     pub fn Zsc1(&self) -> Result<Complex<f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::Zsc1")?;
         unsafe { dss_capi::ctx_Bus_Get_Zsc1_GR(self.ctx_ptr) };
-        self.ctx.GetComplexSimpleGR()
+        self.ctx.GetComplexSimpleGR().origin("IBus::Zsc1")
     }
 
     /// Complex array of Zsc matrix at bus. Column by column.
     pub fn ZscMatrix(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::ZscMatrix")?;
+        unsafe { dss_capi::ctx_Bus_Get_ZscMatrix_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("IBus::ZscMatrix")
+    }
+
+    pub fn ZscMatrix_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::ZscMatrix_view")?;
+        unsafe { dss_capi::ctx_Bus_Get_ZscMatrix_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("IBus::ZscMatrix_view")
+    }
+
+    pub fn ZscMatrix_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::ZscMatrix_into")?;
         unsafe { dss_capi::ctx_Bus_Get_ZscMatrix_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("IBus::ZscMatrix_into")
     }
 
     /// Base voltage at bus in kV
     pub fn kVBase(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_kVBase(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::kVBase")?;
         Ok(result)
     }
 
     /// Returns Complex array of pu L-L voltages for 2- and 3-phase buses. Returns -1.0 for 1-phase bus. If more than 3 phases, returns only 3 phases.
     pub fn puVLL(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::puVLL")?;
+        unsafe { dss_capi::ctx_Bus_Get_puVLL_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("IBus::puVLL")
+    }
+
+    pub fn puVLL_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::puVLL_view")?;
+        unsafe { dss_capi::ctx_Bus_Get_puVLL_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("IBus::puVLL_view")
+    }
+
+    pub fn puVLL_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::puVLL_into")?;
         unsafe { dss_capi::ctx_Bus_Get_puVLL_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("IBus::puVLL_into")
     }
 
     /// Array of doubles containing voltage magnitude, angle (degrees) pairs in per unit
     pub fn puVmagAngle(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::puVmagAngle")?;
         unsafe { dss_capi::ctx_Bus_Get_puVmagAngle_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("IBus::puVmagAngle")
+    }
+
+    pub fn puVmagAngle_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::puVmagAngle_view")?;
+        unsafe { dss_capi::ctx_Bus_Get_puVmagAngle_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IBus::puVmagAngle_view")
+    }
+
+    pub fn puVmagAngle_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::puVmagAngle_into")?;
+        unsafe { dss_capi::ctx_Bus_Get_puVmagAngle_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IBus::puVmagAngle_into")
     }
 
     /// Complex Array of pu voltages at the bus.
     pub fn puVoltages(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::puVoltages")?;
         unsafe { dss_capi::ctx_Bus_Get_puVoltages_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGR().origin("IBus::puVoltages")
+    }
+
+    pub fn puVoltages_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::puVoltages_view")?;
+        unsafe { dss_capi::ctx_Bus_Get_puVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("IBus::puVoltages_view")
+    }
+
+    pub fn puVoltages_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::puVoltages_into")?;
+        unsafe { dss_capi::ctx_Bus_Get_puVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("IBus::puVoltages_into")
     }
 
     /// Array of doubles (complex) containing the complete 012 Zsc matrix.
     /// Only available after Zsc is computed, either through the "ZscRefresh" command, or running a "FaultStudy" solution.
     /// Only available for buses with 3 nodes.
     pub fn ZSC012Matrix(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::ZSC012Matrix")?;
+        unsafe { dss_capi::ctx_Bus_Get_ZSC012Matrix_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("IBus::ZSC012Matrix")
+    }
+
+    pub fn ZSC012Matrix_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::ZSC012Matrix_view")?;
         unsafe { dss_capi::ctx_Bus_Get_ZSC012Matrix_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRView().origin("IBus::ZSC012Matrix_view")
+    }
+
+    pub fn ZSC012Matrix_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IBus::ZSC012Matrix_into")?;
+        unsafe { dss_capi::ctx_Bus_Get_ZSC012Matrix_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("IBus::ZSC012Matrix_into")
     }
 
     /// X Coordinate for bus (double)
     pub fn Get_x(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_x(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::Get_x")?;
         Ok(result)
     }
 
     pub fn Set_x(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Bus_Set_x(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IBus::Set_x")
     }
 
     /// Y coordinate for bus(double)
     pub fn Get_y(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Bus_Get_y(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IBus::Get_y")?;
         Ok(result)
     }
 
     pub fn Set_y(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Bus_Set_y(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IBus::Set_y")
     }
 
     /// List of strings: Full Names of LOAD elements connected to the active bus.
@@ -532,7 +801,7 @@ impl<'a> IBus<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Bus_Get_LoadList(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IBus::LoadList")
     }
 
     /// List of strings: Full Names of LINE elements connected to the active bus.
@@ -540,7 +809,7 @@ impl<'a> IBus<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Bus_Get_LineList(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IBus::LineList")
     }
 
 }
@@ -565,255 +834,255 @@ impl<'a> ICNData<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_CNData_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICNData::AllNames")
     }
 
     /// Number of CNData objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Count")?;
         Ok(result)
     }
 
     /// Sets the first CNData active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::First")?;
         Ok(result)
     }
 
     /// Sets the active CNData by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_CNData_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active CNData.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ICNData::Set_Name")?;
         unsafe { dss_capi::ctx_CNData_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_Name")
     }
 
     /// Sets the next CNData active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active CNData; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active CNData by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_idx")
     }
 
     /// Emergency ampere rating
     pub fn Get_EmergAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_EmergAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_EmergAmps")?;
         Ok(result)
     }
 
     pub fn Set_EmergAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_EmergAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_EmergAmps")
     }
 
     /// Normal Ampere rating
     pub fn Get_NormAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_NormAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_NormAmps")?;
         Ok(result)
     }
 
     pub fn Set_NormAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_NormAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_NormAmps")
     }
 
     pub fn Get_Rdc(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_Rdc(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_Rdc")?;
         Ok(result)
     }
 
     pub fn Set_Rdc(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_Rdc(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_Rdc")
     }
 
     pub fn Get_Rac(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_Rac(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_Rac")?;
         Ok(result)
     }
 
     pub fn Set_Rac(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_Rac(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_Rac")
     }
 
     pub fn Get_GMRac(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_GMRac(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_GMRac")?;
         Ok(result)
     }
 
     pub fn Set_GMRac(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_GMRac(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_GMRac")
     }
 
     pub fn Get_GMRUnits(&self) -> Result<LineUnits, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_GMRUnits(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ICNData::Get_GMRUnits")?;
+        result.try_into().origin("ICNData::Get_GMRUnits")
     }
 
     pub fn Set_GMRUnits(&self, value: LineUnits) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_GMRUnits(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_GMRUnits")
     }
 
     pub fn Get_Radius(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_Radius(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_Radius")?;
         Ok(result)
     }
 
     pub fn Set_Radius(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_Radius(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_Radius")
     }
 
     pub fn Get_RadiusUnits(&self) -> Result<LineUnits, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_RadiusUnits(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ICNData::Get_RadiusUnits")?;
+        result.try_into().origin("ICNData::Get_RadiusUnits")
     }
 
     pub fn Set_RadiusUnits(&self, value: LineUnits) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_RadiusUnits(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_RadiusUnits")
     }
 
     pub fn Get_ResistanceUnits(&self) -> Result<LineUnits, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_ResistanceUnits(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ICNData::Get_ResistanceUnits")?;
+        result.try_into().origin("ICNData::Get_ResistanceUnits")
     }
 
     pub fn Set_ResistanceUnits(&self, value: LineUnits) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_ResistanceUnits(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_ResistanceUnits")
     }
 
     pub fn Get_Diameter(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_Diameter(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_Diameter")?;
         Ok(result)
     }
 
     pub fn Set_Diameter(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_Diameter(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_Diameter")
     }
 
     pub fn Get_EpsR(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_EpsR(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_EpsR")?;
         Ok(result)
     }
 
     pub fn Set_EpsR(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_EpsR(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_EpsR")
     }
 
     pub fn Get_InsLayer(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_InsLayer(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_InsLayer")?;
         Ok(result)
     }
 
     pub fn Set_InsLayer(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_InsLayer(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_InsLayer")
     }
 
     pub fn Get_DiaIns(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_DiaIns(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_DiaIns")?;
         Ok(result)
     }
 
     pub fn Set_DiaIns(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_DiaIns(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_DiaIns")
     }
 
     pub fn Get_DiaCable(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_DiaCable(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_DiaCable")?;
         Ok(result)
     }
 
     pub fn Set_DiaCable(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_DiaCable(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_DiaCable")
     }
 
     pub fn Get_k(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_k(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_k")?;
         Ok(result)
     }
 
     pub fn Set_k(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_k(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_k")
     }
 
     pub fn Get_DiaStrand(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_DiaStrand(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_DiaStrand")?;
         Ok(result)
     }
 
     pub fn Set_DiaStrand(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_DiaStrand(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_DiaStrand")
     }
 
     pub fn Get_GmrStrand(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_GmrStrand(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_GmrStrand")?;
         Ok(result)
     }
 
     pub fn Set_GmrStrand(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_GmrStrand(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_GmrStrand")
     }
 
     pub fn Get_RStrand(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CNData_Get_RStrand(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICNData::Get_RStrand")?;
         Ok(result)
     }
 
     pub fn Set_RStrand(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CNData_Set_RStrand(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICNData::Set_RStrand")
     }
 }
 
@@ -837,143 +1106,156 @@ impl<'a> ICapacitors<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Capacitors_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICapacitors::AllNames")
     }
 
     /// Number of Capacitor objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Capacitors_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::Count")?;
         Ok(result)
     }
 
     /// Sets the first Capacitor active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Capacitors_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::First")?;
         Ok(result)
     }
 
     /// Sets the active Capacitor by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Capacitors_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Capacitor.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ICapacitors::Set_Name")?;
         unsafe { dss_capi::ctx_Capacitors_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapacitors::Set_Name")
     }
 
     /// Sets the next Capacitor active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Capacitors_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Capacitor; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Capacitors_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Capacitor by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Capacitors_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapacitors::Set_idx")
     }
 
     pub fn AddStep(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Capacitors_AddStep(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::AddStep")?;
         Ok(result)
     }
 
     pub fn Close(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Capacitors_Close(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapacitors::Close")
     }
 
     pub fn Open(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Capacitors_Open(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapacitors::Open")
     }
 
     pub fn SubtractStep(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Capacitors_SubtractStep(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::SubtractStep")?;
         Ok(result)
     }
 
     /// Number of Steps available in cap bank to be switched ON.
     pub fn AvailableSteps(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Capacitors_Get_AvailableSteps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::AvailableSteps")?;
         Ok(result)
     }
 
     /// Delta connection or wye?
     pub fn Get_IsDelta(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Capacitors_Get_IsDelta(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::Get_IsDelta")?;
         Ok(result)
     }
 
     pub fn Set_IsDelta(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Capacitors_Set_IsDelta(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapacitors::Set_IsDelta")
     }
 
     /// Number of steps (default 1) for distributing and switching the total bank kVAR.
     pub fn Get_NumSteps(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Capacitors_Get_NumSteps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::Get_NumSteps")?;
         Ok(result)
     }
 
     pub fn Set_NumSteps(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Capacitors_Set_NumSteps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapacitors::Set_NumSteps")
     }
 
     /// A array of  integer [0..numsteps-1] indicating state of each step. If the read value is -1 an error has occurred.
     pub fn Get_States(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICapacitors::Get_States")?;
+        unsafe { dss_capi::ctx_Capacitors_Get_States_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGR().origin("ICapacitors::Get_States")
+    }
+
+    pub fn Get_States_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICapacitors::Get_States_view")?;
         unsafe { dss_capi::ctx_Capacitors_Get_States_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGRView().origin("ICapacitors::Get_States_view")
+    }
+
+    pub fn Get_States_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICapacitors::Get_States_into")?;
+        unsafe { dss_capi::ctx_Capacitors_Get_States_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRInto(out).origin("ICapacitors::Get_States_into")
     }
 
     pub fn Set_States(&self, value: &[i32]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Capacitors_Set_States(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapacitors::Set_States")
     }
 
     /// Bank kV rating. Use LL for 2 or 3 phases, or actual can rating for 1 phase.
     pub fn Get_kV(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Capacitors_Get_kV(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::Get_kV")?;
         Ok(result)
     }
 
     pub fn Set_kV(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Capacitors_Set_kV(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapacitors::Set_kV")
     }
 
     /// Total bank KVAR, distributed equally among phases and steps.
     pub fn Get_kvar(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Capacitors_Get_kvar(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapacitors::Get_kvar")?;
         Ok(result)
     }
 
     pub fn Set_kvar(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Capacitors_Set_kvar(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapacitors::Set_kvar")
     }
 }
 
@@ -996,17 +1278,17 @@ impl<'a> ICktElement<'a> {
     }
     
     /// Value as return and error code in Code parameter. For PCElement, get the value of a variable by name. If Code>0 then no variable by this name or not a PCelement.
-    pub fn Get_Variable(&self, varName: String, Code: *mut i32) -> Result<f64, DSSError> {
-        let varName_c = CString::new(varName).unwrap();
+    pub fn Get_Variable(&self, varName: impl AsRef<str>, Code: *mut i32) -> Result<f64, DSSError> {
+        let varName_c = self.ctx.PrepareString(varName).origin("ICktElement::Get_Variable")?;
         let result = unsafe { dss_capi::ctx_CktElement_Get_Variable(self.ctx_ptr, varName_c.as_ptr(), Code) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::Get_Variable")?;
         Ok(result)
     }
 
     /// Value as return and error code in Code parameter. For PCElement, get the value of a variable by integer index. If Code>0 then no variable by this index or not a PCelement.
     pub fn Get_Variablei(&self, Idx: i32, Code: *mut i32) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CktElement_Get_Variablei(self.ctx_ptr, Idx, Code) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::Get_Variablei")?;
         Ok(result)
     }
 
@@ -1016,44 +1298,44 @@ impl<'a> ICktElement<'a> {
     }
 
     /// Value as return and error code in Code parameter. For PCElement, get the value of a variable by name. If Code>0 then no variable by this name or not a PCelement.
-    pub fn Get_VariableByName(&self, Name: String, Code: *mut i32) -> Result<f64, DSSError> {
+    pub fn Get_VariableByName(&self, Name: impl AsRef<str>, Code: *mut i32) -> Result<f64, DSSError> {
         self.Get_Variable(Name, Code)
     }
 
     /// Set the Value of a variable by index if a PCElement. If Code>0 then no variable by this index or not a PCelement.
     pub fn Set_VariableByIndex(&self, Idx: i32, Code: *mut i32, Value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CktElement_Set_Variablei(self.ctx_ptr, Idx, Code, Value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICktElement::Set_VariableByIndex")
     }
 
     /// Set the Value of a variable by name if a PCElement. If Code>0 then no variable by this name or not a PCelement.
-    pub fn Set_VariableByName(&self, varName: String, Code: *mut i32, Value: f64) -> Result<(), DSSError> {
-        let varName_c = CString::new(varName).unwrap();
+    pub fn Set_VariableByName(&self, varName: impl AsRef<str>, Code: *mut i32, Value: f64) -> Result<(), DSSError> {
+        let varName_c = self.ctx.PrepareString(varName).origin("ICktElement::Set_VariableByName")?;
         unsafe { dss_capi::ctx_CktElement_Set_Variable(self.ctx_ptr, varName_c.as_ptr(), Code, Value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICktElement::Set_VariableByName")
     }
 
     pub fn Close(&self, Term: i32, Phs: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CktElement_Close(self.ctx_ptr, Term, Phs) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICktElement::Close")
     }
 
     /// Full name of the i-th controller attached to this element. Ex: str = Controller(2).  See NumControls to determine valid index range
     pub fn Controller(&self, idx: i32) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_CktElement_Get_Controller(self.ctx_ptr, idx)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::Controller")?;
         Ok(result)
     }
 
     pub fn IsOpen(&self, Term: i32, Phs: i32) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_CktElement_IsOpen(self.ctx_ptr, Term, Phs) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::IsOpen")?;
         Ok(result)
     }
 
     pub fn Open(&self, Term: i32, Phs: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CktElement_Open(self.ctx_ptr, Term, Phs) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICktElement::Open")
     }
 
     /// Array containing all property names of the active device.
@@ -1061,7 +1343,7 @@ impl<'a> ICktElement<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_CktElement_Get_AllPropertyNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICktElement::AllPropertyNames")
     }
 
     /// Array of strings listing all the published state variable names.
@@ -1070,14 +1352,27 @@ impl<'a> ICktElement<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_CktElement_Get_AllVariableNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICktElement::AllVariableNames")
     }
 
     /// Array of doubles. Values of state variables of active element if PC element.
     /// Valid only for PCElements.
     pub fn AllVariableValues(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::AllVariableValues")?;
+        unsafe { dss_capi::ctx_CktElement_Get_AllVariableValues_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ICktElement::AllVariableValues")
+    }
+
+    pub fn AllVariableValues_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::AllVariableValues_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_AllVariableValues_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICktElement::AllVariableValues_view")
+    }
+
+    pub fn AllVariableValues_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::AllVariableValues_into")?;
         unsafe { dss_capi::ctx_CktElement_Get_AllVariableValues_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICktElement::AllVariableValues_into")
     }
 
     /// Array of strings. Get  Bus definitions to which each terminal is connected.
@@ -1085,153 +1380,219 @@ impl<'a> ICktElement<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_CktElement_Get_BusNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICktElement::Get_BusNames")
     }
 
-    pub fn Set_BusNames(&self, value: &[String]) -> Result<(), DSSError> {
-        let (_value_cstrs, value_c) = self.ctx.PrepareStringArray(value);
+    pub fn Set_BusNames(&self, value: &[impl AsRef<str>]) -> Result<(), DSSError> {
+        let (_value_cstrs, value_c) = self.ctx.PrepareStringArray(value).origin("ICktElement::Set_BusNames")?;
         unsafe { dss_capi::ctx_CktElement_Set_BusNames(self.ctx_ptr, value_c.as_ptr() as *mut *const c_char, value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICktElement::Set_BusNames")
     }
 
     /// Complex double array of Sequence Currents for all conductors of all terminals of active circuit element.
     pub fn CplxSeqCurrents(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::CplxSeqCurrents")?;
         unsafe { dss_capi::ctx_CktElement_Get_CplxSeqCurrents_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGR().origin("ICktElement::CplxSeqCurrents")
+    }
+
+    pub fn CplxSeqCurrents_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::CplxSeqCurrents_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_CplxSeqCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ICktElement::CplxSeqCurrents_view")
+    }
+
+    pub fn CplxSeqCurrents_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::CplxSeqCurrents_into")?;
+        unsafe { dss_capi::ctx_CktElement_Get_CplxSeqCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ICktElement::CplxSeqCurrents_into")
     }
 
     /// Complex double array of Sequence Voltage for all terminals of active circuit element.
     pub fn CplxSeqVoltages(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::CplxSeqVoltages")?;
         unsafe { dss_capi::ctx_CktElement_Get_CplxSeqVoltages_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGR().origin("ICktElement::CplxSeqVoltages")
+    }
+
+    pub fn CplxSeqVoltages_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::CplxSeqVoltages_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_CplxSeqVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ICktElement::CplxSeqVoltages_view")
+    }
+
+    pub fn CplxSeqVoltages_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::CplxSeqVoltages_into")?;
+        unsafe { dss_capi::ctx_CktElement_Get_CplxSeqVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ICktElement::CplxSeqVoltages_into")
     }
 
     /// Complex array of currents into each conductor of each terminal
     pub fn Currents(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Currents")?;
+        unsafe { dss_capi::ctx_CktElement_Get_Currents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ICktElement::Currents")
+    }
+
+    pub fn Currents_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Currents_view")?;
         unsafe { dss_capi::ctx_CktElement_Get_Currents_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRView().origin("ICktElement::Currents_view")
+    }
+
+    pub fn Currents_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Currents_into")?;
+        unsafe { dss_capi::ctx_CktElement_Get_Currents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ICktElement::Currents_into")
     }
 
     /// Currents in magnitude, angle (degrees) format as a array of doubles.
     pub fn CurrentsMagAng(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::CurrentsMagAng")?;
+        unsafe { dss_capi::ctx_CktElement_Get_CurrentsMagAng_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ICktElement::CurrentsMagAng")
+    }
+
+    pub fn CurrentsMagAng_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::CurrentsMagAng_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_CurrentsMagAng_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICktElement::CurrentsMagAng_view")
+    }
+
+    pub fn CurrentsMagAng_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::CurrentsMagAng_into")?;
         unsafe { dss_capi::ctx_CktElement_Get_CurrentsMagAng_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICktElement::CurrentsMagAng_into")
     }
 
     /// Display name of the object (not necessarily unique)
     pub fn Get_DisplayName(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_CktElement_Get_DisplayName(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::Get_DisplayName")?;
         Ok(result)
     }
 
-    pub fn Set_DisplayName(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_DisplayName(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ICktElement::Set_DisplayName")?;
         unsafe { dss_capi::ctx_CktElement_Set_DisplayName(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICktElement::Set_DisplayName")
     }
 
     /// Emergency Ampere Rating for PD elements
     pub fn Get_EmergAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CktElement_Get_EmergAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::Get_EmergAmps")?;
         Ok(result)
     }
 
     pub fn Set_EmergAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CktElement_Set_EmergAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICktElement::Set_EmergAmps")
     }
 
     /// Boolean indicating that element is currently in the circuit.
     pub fn Get_Enabled(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_CktElement_Get_Enabled(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::Get_Enabled")?;
         Ok(result)
     }
 
     pub fn Set_Enabled(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CktElement_Set_Enabled(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICktElement::Set_Enabled")
     }
 
     /// Name of the Energy Meter this element is assigned to.
     pub fn EnergyMeter(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_CktElement_Get_EnergyMeter(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::EnergyMeter")?;
         Ok(result)
     }
 
     /// globally unique identifier for this object
     pub fn GUID(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_CktElement_Get_GUID(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::GUID")?;
         Ok(result)
     }
 
     /// Pointer to this object
     pub fn Handle(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CktElement_Get_Handle(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::Handle")?;
         Ok(result)
     }
 
     /// True if a recloser, relay, or fuse controlling this ckt element. OCP = Overcurrent Protection
     pub fn HasOCPDevice(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_CktElement_Get_HasOCPDevice(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::HasOCPDevice")?;
         Ok(result)
     }
 
     /// This element has a SwtControl attached.
     pub fn HasSwitchControl(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_CktElement_Get_HasSwitchControl(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::HasSwitchControl")?;
         Ok(result)
     }
 
     /// This element has a CapControl or RegControl attached.
     pub fn HasVoltControl(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_CktElement_Get_HasVoltControl(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::HasVoltControl")?;
         Ok(result)
     }
 
     /// Total losses in the element: two-element double array (complex), in VA (watts, vars)
     pub fn Losses(&self) -> Result<Complex<f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Losses")?;
         unsafe { dss_capi::ctx_CktElement_Get_Losses_GR(self.ctx_ptr) };
-        self.ctx.GetComplexSimpleGR()
+        self.ctx.GetComplexSimpleGR().origin("ICktElement::Losses")
     }
 
     /// Full Name of Active Circuit Element
     pub fn Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_CktElement_Get_Name(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::Name")?;
         Ok(result)
     }
 
     /// Array of integer containing the node numbers (representing phases, for example) for each conductor of each terminal.
     pub fn NodeOrder(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::NodeOrder")?;
+        unsafe { dss_capi::ctx_CktElement_Get_NodeOrder_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGR().origin("ICktElement::NodeOrder")
+    }
+
+    pub fn NodeOrder_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::NodeOrder_view")?;
         unsafe { dss_capi::ctx_CktElement_Get_NodeOrder_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGRView().origin("ICktElement::NodeOrder_view")
+    }
+
+    pub fn NodeOrder_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::NodeOrder_into")?;
+        unsafe { dss_capi::ctx_CktElement_Get_NodeOrder_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRInto(out).origin("ICktElement::NodeOrder_into")
     }
 
     /// Normal ampere rating for PD Elements
     pub fn Get_NormalAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CktElement_Get_NormalAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::Get_NormalAmps")?;
         Ok(result)
     }
 
     pub fn Set_NormalAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CktElement_Set_NormalAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICktElement::Set_NormalAmps")
     }
 
     /// Number of Conductors per Terminal
     pub fn NumConductors(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CktElement_Get_NumConductors(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::NumConductors")?;
         Ok(result)
     }
 
@@ -1239,97 +1600,214 @@ impl<'a> ICktElement<'a> {
     /// Use to determine valid range for index into Controller array.
     pub fn NumControls(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CktElement_Get_NumControls(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::NumControls")?;
         Ok(result)
     }
 
     /// Number of Phases
     pub fn NumPhases(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CktElement_Get_NumPhases(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::NumPhases")?;
         Ok(result)
     }
 
     /// Number of Properties this Circuit Element.
     pub fn NumProperties(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CktElement_Get_NumProperties(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::NumProperties")?;
         Ok(result)
     }
 
     /// Number of Terminals this Circuit Element
     pub fn NumTerminals(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CktElement_Get_NumTerminals(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::NumTerminals")?;
         Ok(result)
     }
 
     /// Index into Controller list of OCP Device controlling this CktElement
     pub fn OCPDevIndex(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CktElement_Get_OCPDevIndex(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::OCPDevIndex")?;
         Ok(result)
     }
 
     /// 0=None; 1=Fuse; 2=Recloser; 3=Relay;  Type of OCP controller device
     pub fn OCPDevType(&self) -> Result<OCPDevType, DSSError> {
         let result = unsafe { dss_capi::ctx_CktElement_Get_OCPDevType(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ICktElement::OCPDevType")?;
+        result.try_into().origin("ICktElement::OCPDevType")
     }
 
     /// Complex array of losses (kVA) by phase
     pub fn PhaseLosses(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::PhaseLosses")?;
         unsafe { dss_capi::ctx_CktElement_Get_PhaseLosses_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGR().origin("ICktElement::PhaseLosses")
+    }
+
+    pub fn PhaseLosses_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::PhaseLosses_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_PhaseLosses_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ICktElement::PhaseLosses_view")
+    }
+
+    pub fn PhaseLosses_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::PhaseLosses_into")?;
+        unsafe { dss_capi::ctx_CktElement_Get_PhaseLosses_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ICktElement::PhaseLosses_into")
     }
 
     /// Complex array of powers (kVA) into each conductor of each terminal
     pub fn Powers(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Powers")?;
+        unsafe { dss_capi::ctx_CktElement_Get_Powers_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ICktElement::Powers")
+    }
+
+    pub fn Powers_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Powers_view")?;
         unsafe { dss_capi::ctx_CktElement_Get_Powers_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRView().origin("ICktElement::Powers_view")
+    }
+
+    pub fn Powers_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Powers_into")?;
+        unsafe { dss_capi::ctx_CktElement_Get_Powers_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ICktElement::Powers_into")
     }
 
     /// Residual currents for each terminal: (magnitude, angle in degrees)
     pub fn Residuals(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Residuals")?;
         unsafe { dss_capi::ctx_CktElement_Get_Residuals_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ICktElement::Residuals")
+    }
+
+    pub fn Residuals_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Residuals_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_Residuals_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICktElement::Residuals_view")
+    }
+
+    pub fn Residuals_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Residuals_into")?;
+        unsafe { dss_capi::ctx_CktElement_Get_Residuals_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICktElement::Residuals_into")
     }
 
     /// Double array of symmetrical component currents (magnitudes only) into each 3-phase terminal
     pub fn SeqCurrents(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::SeqCurrents")?;
+        unsafe { dss_capi::ctx_CktElement_Get_SeqCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ICktElement::SeqCurrents")
+    }
+
+    pub fn SeqCurrents_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::SeqCurrents_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_SeqCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICktElement::SeqCurrents_view")
+    }
+
+    pub fn SeqCurrents_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::SeqCurrents_into")?;
         unsafe { dss_capi::ctx_CktElement_Get_SeqCurrents_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICktElement::SeqCurrents_into")
     }
 
     /// Complex array of sequence powers (kW, kvar) into each 3-phase teminal
     pub fn SeqPowers(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::SeqPowers")?;
         unsafe { dss_capi::ctx_CktElement_Get_SeqPowers_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGR().origin("ICktElement::SeqPowers")
+    }
+
+    pub fn SeqPowers_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::SeqPowers_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_SeqPowers_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ICktElement::SeqPowers_view")
+    }
+
+    pub fn SeqPowers_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::SeqPowers_into")?;
+        unsafe { dss_capi::ctx_CktElement_Get_SeqPowers_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ICktElement::SeqPowers_into")
     }
 
     /// Double array of symmetrical component voltages (magnitudes only) at each 3-phase terminal
     pub fn SeqVoltages(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::SeqVoltages")?;
+        unsafe { dss_capi::ctx_CktElement_Get_SeqVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ICktElement::SeqVoltages")
+    }
+
+    pub fn SeqVoltages_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::SeqVoltages_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_SeqVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICktElement::SeqVoltages_view")
+    }
+
+    pub fn SeqVoltages_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::SeqVoltages_into")?;
         unsafe { dss_capi::ctx_CktElement_Get_SeqVoltages_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICktElement::SeqVoltages_into")
     }
 
     /// Complex array of voltages at terminals
     pub fn Voltages(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Voltages")?;
+        unsafe { dss_capi::ctx_CktElement_Get_Voltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ICktElement::Voltages")
+    }
+
+    pub fn Voltages_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Voltages_view")?;
         unsafe { dss_capi::ctx_CktElement_Get_Voltages_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRView().origin("ICktElement::Voltages_view")
+    }
+
+    pub fn Voltages_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Voltages_into")?;
+        unsafe { dss_capi::ctx_CktElement_Get_Voltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ICktElement::Voltages_into")
     }
 
     /// Voltages at each conductor in magnitude, angle form as array of doubles.
     pub fn VoltagesMagAng(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::VoltagesMagAng")?;
+        unsafe { dss_capi::ctx_CktElement_Get_VoltagesMagAng_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ICktElement::VoltagesMagAng")
+    }
+
+    pub fn VoltagesMagAng_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::VoltagesMagAng_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_VoltagesMagAng_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICktElement::VoltagesMagAng_view")
+    }
+
+    pub fn VoltagesMagAng_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::VoltagesMagAng_into")?;
         unsafe { dss_capi::ctx_CktElement_Get_VoltagesMagAng_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICktElement::VoltagesMagAng_into")
     }
 
     /// YPrim matrix, column order, complex numbers
     pub fn Yprim(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Yprim")?;
+        unsafe { dss_capi::ctx_CktElement_Get_Yprim_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ICktElement::Yprim")
+    }
+
+    pub fn Yprim_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Yprim_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_Yprim_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ICktElement::Yprim_view")
+    }
+
+    pub fn Yprim_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::Yprim_into")?;
         unsafe { dss_capi::ctx_CktElement_Get_Yprim_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("ICktElement::Yprim_into")
     }
 
     /// Returns true if the current active element is isolated.
@@ -1338,20 +1816,46 @@ impl<'a> ICktElement<'a> {
     /// (API Extension)
     pub fn IsIsolated(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_CktElement_Get_IsIsolated(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICktElement::IsIsolated")?;
         Ok(result)
     }
 
     /// Returns an array with the total powers (complex, kVA) at ALL terminals of the active circuit element.
     pub fn TotalPowers(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::TotalPowers")?;
         unsafe { dss_capi::ctx_CktElement_Get_TotalPowers_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGR().origin("ICktElement::TotalPowers")
+    }
+
+    pub fn TotalPowers_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::TotalPowers_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_TotalPowers_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ICktElement::TotalPowers_view")
+    }
+
+    pub fn TotalPowers_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::TotalPowers_into")?;
+        unsafe { dss_capi::ctx_CktElement_Get_TotalPowers_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ICktElement::TotalPowers_into")
     }
 
     /// Array of integers, a copy of the internal NodeRef of the CktElement.
     pub fn NodeRef(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::NodeRef")?;
         unsafe { dss_capi::ctx_CktElement_Get_NodeRef_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGR().origin("ICktElement::NodeRef")
+    }
+
+    pub fn NodeRef_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::NodeRef_view")?;
+        unsafe { dss_capi::ctx_CktElement_Get_NodeRef_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRView().origin("ICktElement::NodeRef_view")
+    }
+
+    pub fn NodeRef_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICktElement::NodeRef_into")?;
+        unsafe { dss_capi::ctx_CktElement_Get_NodeRef_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRInto(out).origin("ICktElement::NodeRef_into")
     }
 }
 
@@ -1375,103 +1879,103 @@ impl<'a> IGenerators<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Generators_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IGenerators::AllNames")
     }
 
     /// Number of Generator objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Count")?;
         Ok(result)
     }
 
     /// Sets the first Generator active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::First")?;
         Ok(result)
     }
 
     /// Sets the active Generator by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Generators_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Generator.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IGenerators::Set_Name")?;
         unsafe { dss_capi::ctx_Generators_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_Name")
     }
 
     /// Sets the next Generator active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Generator; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Generator by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_idx")
     }
 
     /// Indicates whether the generator is forced ON regardles of other dispatch criteria.
     pub fn Get_ForcedON(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Generators_Get_ForcedON(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_ForcedON")?;
         Ok(result)
     }
 
     pub fn Set_ForcedON(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_ForcedON(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_ForcedON")
     }
 
     /// Generator Model
     pub fn Get_Model(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_Model(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_Model")?;
         Ok(result)
     }
 
     pub fn Set_Model(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_Model(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_Model")
     }
 
     /// Power factor (pos. = producing vars). Updates kvar based on present kW value.
     pub fn Get_PF(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_PF(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_PF")?;
         Ok(result)
     }
 
     pub fn Set_PF(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_PF(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_PF")
     }
 
     /// Number of phases
     pub fn Get_Phases(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_Phases(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_Phases")?;
         Ok(result)
     }
 
     pub fn Set_Phases(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_Phases(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_Phases")
     }
 
     /// Array of Names of all generator energy meter registers
@@ -1479,85 +1983,98 @@ impl<'a> IGenerators<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Generators_Get_RegisterNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IGenerators::RegisterNames")
     }
 
     /// Array of valus in generator energy meter registers.
     pub fn RegisterValues(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IGenerators::RegisterValues")?;
         unsafe { dss_capi::ctx_Generators_Get_RegisterValues_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("IGenerators::RegisterValues")
+    }
+
+    pub fn RegisterValues_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IGenerators::RegisterValues_view")?;
+        unsafe { dss_capi::ctx_Generators_Get_RegisterValues_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IGenerators::RegisterValues_view")
+    }
+
+    pub fn RegisterValues_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IGenerators::RegisterValues_into")?;
+        unsafe { dss_capi::ctx_Generators_Get_RegisterValues_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IGenerators::RegisterValues_into")
     }
 
     /// Vmaxpu for generator model
     pub fn Get_Vmaxpu(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_Vmaxpu(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_Vmaxpu")?;
         Ok(result)
     }
 
     pub fn Set_Vmaxpu(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_Vmaxpu(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_Vmaxpu")
     }
 
     /// Vminpu for Generator model
     pub fn Get_Vminpu(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_Vminpu(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_Vminpu")?;
         Ok(result)
     }
 
     pub fn Set_Vminpu(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_Vminpu(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_Vminpu")
     }
 
     /// Voltage base for the active generator, kV
     pub fn Get_kV(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_kV(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_kV")?;
         Ok(result)
     }
 
     pub fn Set_kV(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_kV(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_kV")
     }
 
     /// kVA rating of the generator
     pub fn Get_kVArated(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_kVArated(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_kVArated")?;
         Ok(result)
     }
 
     pub fn Set_kVArated(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_kVArated(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_kVArated")
     }
 
     /// kW output for the active generator. kvar is updated for current power factor.
     pub fn Get_kW(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_kW(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_kW")?;
         Ok(result)
     }
 
     pub fn Set_kW(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_kW(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_kW")
     }
 
     /// kvar output for the active generator. Updates power factor based on present kW value.
     pub fn Get_kvar(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_kvar(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_kvar")?;
         Ok(result)
     }
 
     pub fn Set_kvar(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_kvar(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_kvar")
     }
 
     /// Name of the loadshape for a daily generation profile.
@@ -1565,14 +2082,14 @@ impl<'a> IGenerators<'a> {
     /// (API Extension)
     pub fn Get_daily(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Generators_Get_daily(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_daily")?;
         Ok(result)
     }
 
-    pub fn Set_daily(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_daily(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IGenerators::Set_daily")?;
         unsafe { dss_capi::ctx_Generators_Set_daily(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_daily")
     }
 
     /// Name of the loadshape for a duty cycle simulation.
@@ -1580,14 +2097,14 @@ impl<'a> IGenerators<'a> {
     /// (API Extension)
     pub fn Get_duty(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Generators_Get_duty(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_duty")?;
         Ok(result)
     }
 
-    pub fn Set_duty(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_duty(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IGenerators::Set_duty")?;
         unsafe { dss_capi::ctx_Generators_Set_duty(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_duty")
     }
 
     /// Name of yearly loadshape
@@ -1595,14 +2112,14 @@ impl<'a> IGenerators<'a> {
     /// (API Extension)
     pub fn Get_Yearly(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Generators_Get_Yearly(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_Yearly")?;
         Ok(result)
     }
 
-    pub fn Set_Yearly(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Yearly(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IGenerators::Set_Yearly")?;
         unsafe { dss_capi::ctx_Generators_Set_Yearly(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_Yearly")
     }
 
     /// Response to dispatch multipliers: Fixed=1 (dispatch multipliers do not apply), Variable=0 (follows curves).
@@ -1612,13 +2129,13 @@ impl<'a> IGenerators<'a> {
     /// (API Extension)
     pub fn Get_Status(&self) -> Result<GeneratorStatus, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_Status(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("IGenerators::Get_Status")?;
+        result.try_into().origin("IGenerators::Get_Status")
     }
 
     pub fn Set_Status(&self, value: GeneratorStatus) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_Status(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_Status")
     }
 
     /// Generator connection. True/1 if delta connection, False/0 if wye.
@@ -1626,13 +2143,13 @@ impl<'a> IGenerators<'a> {
     /// (API Extension)
     pub fn Get_IsDelta(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Generators_Get_IsDelta(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_IsDelta")?;
         Ok(result)
     }
 
     pub fn Set_IsDelta(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_IsDelta(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_IsDelta")
     }
 
     /// kVA rating of electrical machine. Applied to machine or inverter definition for Dynamics mode solutions.
@@ -1640,13 +2157,13 @@ impl<'a> IGenerators<'a> {
     /// (API Extension)
     pub fn Get_kva(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_kva(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_kva")?;
         Ok(result)
     }
 
     pub fn Set_kva(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_kva(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_kva")
     }
 
     /// An arbitrary integer number representing the class of Generator so that Generator values may be segregated by class.
@@ -1654,13 +2171,13 @@ impl<'a> IGenerators<'a> {
     /// (API Extension)
     pub fn Get_Class(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Generators_Get_Class_(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_Class")?;
         Ok(result)
     }
 
     pub fn Set_Class(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Generators_Set_Class_(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_Class")
     }
 
     /// Bus to which the Generator is connected. May include specific node specification.
@@ -1668,14 +2185,14 @@ impl<'a> IGenerators<'a> {
     /// (API Extension)
     pub fn Get_Bus1(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Generators_Get_Bus1(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGenerators::Get_Bus1")?;
         Ok(result)
     }
 
-    pub fn Set_Bus1(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Bus1(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IGenerators::Set_Bus1")?;
         unsafe { dss_capi::ctx_Generators_Set_Bus1(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGenerators::Set_Bus1")
     }
 }
 
@@ -1699,364 +2216,416 @@ impl<'a> ILines<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Lines_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ILines::AllNames")
     }
 
     /// Number of Line objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Count")?;
         Ok(result)
     }
 
     /// Sets the first Line active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::First")?;
         Ok(result)
     }
 
     /// Sets the active Line by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Lines_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Line.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILines::Set_Name")?;
         unsafe { dss_capi::ctx_Lines_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Name")
     }
 
     /// Sets the next Line active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Line; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Line by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_idx")
     }
 
-    pub fn New(&self, Name: String) -> Result<i32, DSSError> {
-        let Name_c = CString::new(Name).unwrap();
+    pub fn New(&self, Name: impl AsRef<str>) -> Result<i32, DSSError> {
+        let Name_c = self.ctx.PrepareString(Name).origin("ILines::New")?;
         let result = unsafe { dss_capi::ctx_Lines_New(self.ctx_ptr, Name_c.as_ptr()) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::New")?;
         Ok(result)
     }
 
     /// Name of bus for terminal 1.
     pub fn Get_Bus1(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Lines_Get_Bus1(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_Bus1")?;
         Ok(result)
     }
 
-    pub fn Set_Bus1(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Bus1(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILines::Set_Bus1")?;
         unsafe { dss_capi::ctx_Lines_Set_Bus1(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Bus1")
     }
 
     /// Name of bus for terminal 2.
     pub fn Get_Bus2(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Lines_Get_Bus2(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_Bus2")?;
         Ok(result)
     }
 
-    pub fn Set_Bus2(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Bus2(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILines::Set_Bus2")?;
         unsafe { dss_capi::ctx_Lines_Set_Bus2(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Bus2")
     }
 
     /// Zero Sequence capacitance, nanofarads per unit length.
     pub fn Get_C0(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_C0(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_C0")?;
         Ok(result)
     }
 
     pub fn Set_C0(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_C0(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_C0")
     }
 
     /// Positive Sequence capacitance, nanofarads per unit length.
     pub fn Get_C1(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_C1(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_C1")?;
         Ok(result)
     }
 
     pub fn Set_C1(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_C1(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_C1")
     }
 
     pub fn Get_Cmatrix(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Cmatrix")?;
         unsafe { dss_capi::ctx_Lines_Get_Cmatrix_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ILines::Get_Cmatrix")
+    }
+
+    pub fn Get_Cmatrix_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Cmatrix_view")?;
+        unsafe { dss_capi::ctx_Lines_Get_Cmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILines::Get_Cmatrix_view")
+    }
+
+    pub fn Get_Cmatrix_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Cmatrix_into")?;
+        unsafe { dss_capi::ctx_Lines_Get_Cmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILines::Get_Cmatrix_into")
     }
 
     pub fn Set_Cmatrix(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_Cmatrix(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Cmatrix")
     }
 
     /// Emergency (maximum) ampere rating of Line.
     pub fn Get_EmergAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_EmergAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_EmergAmps")?;
         Ok(result)
     }
 
     pub fn Set_EmergAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_EmergAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_EmergAmps")
     }
 
     /// Line geometry code
     pub fn Get_Geometry(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Lines_Get_Geometry(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_Geometry")?;
         Ok(result)
     }
 
-    pub fn Set_Geometry(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Geometry(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILines::Set_Geometry")?;
         unsafe { dss_capi::ctx_Lines_Set_Geometry(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Geometry")
     }
 
     /// Length of line section in units compatible with the LineCode definition.
     pub fn Get_Length(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_Length(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_Length")?;
         Ok(result)
     }
 
     pub fn Set_Length(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_Length(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Length")
     }
 
     /// Name of LineCode object that defines the impedances.
     pub fn Get_LineCode(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Lines_Get_LineCode(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_LineCode")?;
         Ok(result)
     }
 
-    pub fn Set_LineCode(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_LineCode(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILines::Set_LineCode")?;
         unsafe { dss_capi::ctx_Lines_Set_LineCode(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_LineCode")
     }
 
     /// Normal ampere rating of Line.
     pub fn Get_NormAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_NormAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_NormAmps")?;
         Ok(result)
     }
 
     pub fn Set_NormAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_NormAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_NormAmps")
     }
 
     /// Number of customers on this line section.
     pub fn NumCust(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_NumCust(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::NumCust")?;
         Ok(result)
     }
 
     /// Sets Parent of the active Line to be the active line. Returns 0 if no parent or action fails.
     pub fn Parent(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_Parent(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Parent")?;
         Ok(result)
     }
 
     /// Number of Phases, this Line element.
     pub fn Get_Phases(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_Phases(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_Phases")?;
         Ok(result)
     }
 
     pub fn Set_Phases(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_Phases(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Phases")
     }
 
     /// Zero Sequence resistance, ohms per unit length.
     pub fn Get_R0(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_R0(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_R0")?;
         Ok(result)
     }
 
     pub fn Set_R0(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_R0(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_R0")
     }
 
     /// Positive Sequence resistance, ohms per unit length.
     pub fn Get_R1(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_R1(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_R1")?;
         Ok(result)
     }
 
     pub fn Set_R1(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_R1(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_R1")
     }
 
     /// Earth return resistance value used to compute line impedances at power frequency
     pub fn Get_Rg(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_Rg(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_Rg")?;
         Ok(result)
     }
 
     pub fn Set_Rg(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_Rg(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Rg")
     }
 
     /// Earth Resistivity, m-ohms
     pub fn Get_Rho(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_Rho(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_Rho")?;
         Ok(result)
     }
 
     pub fn Set_Rho(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_Rho(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Rho")
     }
 
     /// Resistance matrix (full), ohms per unit length. Array of doubles.
     pub fn Get_Rmatrix(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Rmatrix")?;
+        unsafe { dss_capi::ctx_Lines_Get_Rmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ILines::Get_Rmatrix")
+    }
+
+    pub fn Get_Rmatrix_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Rmatrix_view")?;
         unsafe { dss_capi::ctx_Lines_Get_Rmatrix_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("ILines::Get_Rmatrix_view")
+    }
+
+    pub fn Get_Rmatrix_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Rmatrix_into")?;
+        unsafe { dss_capi::ctx_Lines_Get_Rmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILines::Get_Rmatrix_into")
     }
 
     pub fn Set_Rmatrix(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_Rmatrix(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Rmatrix")
     }
 
     /// Line spacing code
     pub fn Get_Spacing(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Lines_Get_Spacing(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_Spacing")?;
         Ok(result)
     }
 
-    pub fn Set_Spacing(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Spacing(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILines::Set_Spacing")?;
         unsafe { dss_capi::ctx_Lines_Set_Spacing(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Spacing")
     }
 
     /// Total Number of customers served from this line section.
     pub fn TotalCust(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_TotalCust(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::TotalCust")?;
         Ok(result)
     }
 
     pub fn Get_Units(&self) -> Result<LineUnits, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_Units(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ILines::Get_Units")?;
+        result.try_into().origin("ILines::Get_Units")
     }
 
     pub fn Set_Units(&self, value: LineUnits) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_Units(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Units")
     }
 
     /// Zero Sequence reactance ohms per unit length.
     pub fn Get_X0(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_X0(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_X0")?;
         Ok(result)
     }
 
     pub fn Set_X0(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_X0(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_X0")
     }
 
     /// Positive Sequence reactance, ohms per unit length.
     pub fn Get_X1(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_X1(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_X1")?;
         Ok(result)
     }
 
     pub fn Set_X1(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_X1(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_X1")
     }
 
     /// Earth return reactance value used to compute line impedances at power frequency
     pub fn Get_Xg(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_Xg(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_Xg")?;
         Ok(result)
     }
 
     pub fn Set_Xg(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_Xg(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Xg")
     }
 
     /// Reactance matrix (full), ohms per unit length. Array of doubles.
     pub fn Get_Xmatrix(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Xmatrix")?;
         unsafe { dss_capi::ctx_Lines_Get_Xmatrix_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ILines::Get_Xmatrix")
+    }
+
+    pub fn Get_Xmatrix_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Xmatrix_view")?;
+        unsafe { dss_capi::ctx_Lines_Get_Xmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILines::Get_Xmatrix_view")
+    }
+
+    pub fn Get_Xmatrix_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Xmatrix_into")?;
+        unsafe { dss_capi::ctx_Lines_Get_Xmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILines::Get_Xmatrix_into")
     }
 
     pub fn Set_Xmatrix(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_Xmatrix(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Xmatrix")
     }
 
     /// Yprimitive for the active line object (complex array).
     pub fn Get_Yprim(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Yprim")?;
+        unsafe { dss_capi::ctx_Lines_Get_Yprim_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ILines::Get_Yprim")
+    }
+
+    pub fn Get_Yprim_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Yprim_view")?;
+        unsafe { dss_capi::ctx_Lines_Get_Yprim_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ILines::Get_Yprim_view")
+    }
+
+    pub fn Get_Yprim_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILines::Get_Yprim_into")?;
         unsafe { dss_capi::ctx_Lines_Get_Yprim_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("ILines::Get_Yprim_into")
     }
 
     pub fn Set_Yprim(&self, value: &[Complex<f64>]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_Yprim(self.ctx_ptr, &value[0].re, 2 * value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_Yprim")
     }
 
     /// Delivers the rating for the current season (in Amps)  if the "SeasonalRatings" option is active
     pub fn SeasonRating(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Lines_Get_SeasonRating(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::SeasonRating")?;
         Ok(result)
     }
 
@@ -2065,13 +2634,13 @@ impl<'a> ILines<'a> {
     /// (API Extension)
     pub fn Get_IsSwitch(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Lines_Get_IsSwitch(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILines::Get_IsSwitch")?;
         Ok(result)
     }
 
     pub fn Set_IsSwitch(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Lines_Set_IsSwitch(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILines::Set_IsSwitch")
     }
 }
 
@@ -2096,209 +2665,248 @@ impl<'a> ISettings<'a> {
     /// release since it has performance impacts even when not used.
     pub fn Get_AllowDuplicates(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Settings_Get_AllowDuplicates(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_AllowDuplicates")?;
         Ok(result)
     }
 
     pub fn Set_AllowDuplicates(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_AllowDuplicates(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_AllowDuplicates")
     }
 
     /// List of Buses or (File=xxxx) syntax for the AutoAdd solution mode.
     pub fn Get_AutoBusList(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Settings_Get_AutoBusList(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_AutoBusList")?;
         Ok(result)
     }
 
-    pub fn Set_AutoBusList(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_AutoBusList(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ISettings::Set_AutoBusList")?;
         unsafe { dss_capi::ctx_Settings_Set_AutoBusList(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_AutoBusList")
     }
 
     /// {dssMultiphase (0) * | dssPositiveSeq (1) } Indicate if the circuit model is positive sequence.
     pub fn Get_CktModel(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Settings_Get_CktModel(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_CktModel")?;
         Ok(result)
     }
 
     pub fn Set_CktModel(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_CktModel(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_CktModel")
     }
 
     /// {True | False*} Denotes whether to trace the control actions to a file.
     pub fn Get_ControlTrace(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Settings_Get_ControlTrace(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_ControlTrace")?;
         Ok(result)
     }
 
     pub fn Set_ControlTrace(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_ControlTrace(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_ControlTrace")
     }
 
     /// Per Unit maximum voltage for Emergency conditions.
     pub fn Get_EmergVmaxpu(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Settings_Get_EmergVmaxpu(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_EmergVmaxpu")?;
         Ok(result)
     }
 
     pub fn Set_EmergVmaxpu(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_EmergVmaxpu(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_EmergVmaxpu")
     }
 
     /// Per Unit minimum voltage for Emergency conditions.
     pub fn Get_EmergVminpu(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Settings_Get_EmergVminpu(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_EmergVminpu")?;
         Ok(result)
     }
 
     pub fn Set_EmergVminpu(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_EmergVminpu(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_EmergVminpu")
     }
 
     /// Integer array defining which energy meter registers to use for computing losses
     pub fn Get_LossRegs(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISettings::Get_LossRegs")?;
         unsafe { dss_capi::ctx_Settings_Get_LossRegs_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGR().origin("ISettings::Get_LossRegs")
+    }
+
+    pub fn Get_LossRegs_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISettings::Get_LossRegs_view")?;
+        unsafe { dss_capi::ctx_Settings_Get_LossRegs_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRView().origin("ISettings::Get_LossRegs_view")
+    }
+
+    pub fn Get_LossRegs_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISettings::Get_LossRegs_into")?;
+        unsafe { dss_capi::ctx_Settings_Get_LossRegs_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRInto(out).origin("ISettings::Get_LossRegs_into")
     }
 
     pub fn Set_LossRegs(&self, value: &[i32]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_LossRegs(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_LossRegs")
     }
 
     /// Weighting factor applied to Loss register values.
     pub fn Get_LossWeight(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Settings_Get_LossWeight(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_LossWeight")?;
         Ok(result)
     }
 
     pub fn Set_LossWeight(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_LossWeight(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_LossWeight")
     }
 
     /// Per Unit maximum voltage for Normal conditions.
     pub fn Get_NormVmaxpu(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Settings_Get_NormVmaxpu(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_NormVmaxpu")?;
         Ok(result)
     }
 
     pub fn Set_NormVmaxpu(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_NormVmaxpu(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_NormVmaxpu")
     }
 
     /// Per Unit minimum voltage for Normal conditions.
     pub fn Get_NormVminpu(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Settings_Get_NormVminpu(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_NormVminpu")?;
         Ok(result)
     }
 
     pub fn Set_NormVminpu(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_NormVminpu(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_NormVminpu")
     }
 
     /// Name of LoadShape object that serves as the source of price signal data for yearly simulations, etc.
     pub fn Get_PriceCurve(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Settings_Get_PriceCurve(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_PriceCurve")?;
         Ok(result)
     }
 
-    pub fn Set_PriceCurve(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_PriceCurve(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ISettings::Set_PriceCurve")?;
         unsafe { dss_capi::ctx_Settings_Set_PriceCurve(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_PriceCurve")
     }
 
     /// Price Signal for the Circuit
     pub fn Get_PriceSignal(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Settings_Get_PriceSignal(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_PriceSignal")?;
         Ok(result)
     }
 
     pub fn Set_PriceSignal(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_PriceSignal(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_PriceSignal")
     }
 
     /// Gets value of trapezoidal integration flag in energy meters. Defaults to `False`.
     pub fn Get_Trapezoidal(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Settings_Get_Trapezoidal(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_Trapezoidal")?;
         Ok(result)
     }
 
     pub fn Set_Trapezoidal(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_Trapezoidal(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_Trapezoidal")
     }
 
     /// Array of Integers defining energy meter registers to use for computing UE
     pub fn Get_UEregs(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISettings::Get_UEregs")?;
         unsafe { dss_capi::ctx_Settings_Get_UEregs_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGR().origin("ISettings::Get_UEregs")
+    }
+
+    pub fn Get_UEregs_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISettings::Get_UEregs_view")?;
+        unsafe { dss_capi::ctx_Settings_Get_UEregs_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRView().origin("ISettings::Get_UEregs_view")
+    }
+
+    pub fn Get_UEregs_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISettings::Get_UEregs_into")?;
+        unsafe { dss_capi::ctx_Settings_Get_UEregs_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRInto(out).origin("ISettings::Get_UEregs_into")
     }
 
     pub fn Set_UEregs(&self, value: &[i32]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_UEregs(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_UEregs")
     }
 
     /// Weighting factor applied to UE register values.
     pub fn Get_UEweight(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Settings_Get_UEweight(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_UEweight")?;
         Ok(result)
     }
 
     pub fn Set_UEweight(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_UEweight(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_UEweight")
     }
 
     /// Array of doubles defining the legal voltage bases in kV L-L
     pub fn Get_VoltageBases(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISettings::Get_VoltageBases")?;
+        unsafe { dss_capi::ctx_Settings_Get_VoltageBases_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ISettings::Get_VoltageBases")
+    }
+
+    pub fn Get_VoltageBases_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISettings::Get_VoltageBases_view")?;
         unsafe { dss_capi::ctx_Settings_Get_VoltageBases_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("ISettings::Get_VoltageBases_view")
+    }
+
+    pub fn Get_VoltageBases_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISettings::Get_VoltageBases_into")?;
+        unsafe { dss_capi::ctx_Settings_Get_VoltageBases_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ISettings::Get_VoltageBases_into")
     }
 
     pub fn Set_VoltageBases(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_VoltageBases(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_VoltageBases")
     }
 
     /// {True | False*}  Locks Zones on energy meters to prevent rebuilding if a circuit change occurs.
     pub fn Get_ZoneLock(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Settings_Get_ZoneLock(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_ZoneLock")?;
         Ok(result)
     }
 
     pub fn Set_ZoneLock(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_ZoneLock(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_ZoneLock")
     }
 
     pub fn Set_AllocationFactors(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_AllocationFactors(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_AllocationFactors")
     }
 
     /// Controls whether the terminals are checked when updating the currents in Load component. Defaults to True.
@@ -2307,13 +2915,13 @@ impl<'a> ISettings<'a> {
     /// (API Extension)
     pub fn Get_LoadsTerminalCheck(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Settings_Get_LoadsTerminalCheck(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_LoadsTerminalCheck")?;
         Ok(result)
     }
 
     pub fn Set_LoadsTerminalCheck(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_LoadsTerminalCheck(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_LoadsTerminalCheck")
     }
 
     /// Controls whether `First`/`Next` iteration includes or skips disabled circuit elements.
@@ -2326,19 +2934,19 @@ impl<'a> ISettings<'a> {
     /// (API Extension)
     pub fn Get_IterateDisabled(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Settings_Get_IterateDisabled(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISettings::Get_IterateDisabled")?;
         Ok(result)
     }
 
     pub fn Set_IterateDisabled(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Settings_Set_IterateDisabled(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISettings::Set_IterateDisabled")
     }
 }
 
 pub struct IActiveClass<'a> {
     ctx_ptr: *const c_void,
-    ctx: &'a DSSContext,
+    pub(crate) ctx: &'a DSSContext,
 }
 
 unsafe impl<'a> Send for IActiveClass <'a> {
@@ -2354,7 +2962,7 @@ impl<'a> IActiveClass<'a> {
     /// Returns name of active class.
     pub fn ActiveClassName(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_ActiveClass_Get_ActiveClassName(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IActiveClass::ActiveClassName")?;
         Ok(result)
     }
 
@@ -2363,54 +2971,54 @@ impl<'a> IActiveClass<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_ActiveClass_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IActiveClass::AllNames")
     }
 
     /// Number of elements in Active Class. Same as NumElements Property.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_ActiveClass_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IActiveClass::Count")?;
         Ok(result)
     }
 
     /// Sets first element in the active class to be the active DSS object. If object is a CktElement, ActiveCktELment also points to this element. Returns 0 if none.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_ActiveClass_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IActiveClass::First")?;
         Ok(result)
     }
 
     /// Name of the Active Element of the Active Class
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_ActiveClass_Get_Name(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IActiveClass::Get_Name")?;
         Ok(result)
     }
 
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IActiveClass::Set_Name")?;
         unsafe { dss_capi::ctx_ActiveClass_Set_Name(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IActiveClass::Set_Name")
     }
 
     /// Sets next element in active class to be the active DSS object. If object is a CktElement, ActiveCktElement also points to this element.  Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_ActiveClass_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IActiveClass::Next")?;
         Ok(result)
     }
 
     /// Number of elements in this class. Same as Count property.
     pub fn NumElements(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_ActiveClass_Get_NumElements(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IActiveClass::NumElements")?;
         Ok(result)
     }
 
     /// Get the name of the parent class of the active class
     pub fn ActiveClassParent(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_ActiveClass_Get_ActiveClassParent(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IActiveClass::ActiveClassParent")?;
         Ok(result)
     }
     /// Returns the data (as a list) of all elements from the active class as a JSON-encoded string.
@@ -2423,7 +3031,7 @@ impl<'a> IActiveClass<'a> {
     /// (API Extension)
     pub fn ToJSON(&self, options: i32) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_ActiveClass_ToJSON(self.ctx_ptr, options)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IActiveClass::ToJSON")?;
         Ok(result)
     }
 }
@@ -2448,229 +3056,229 @@ impl<'a> ICapControls<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_CapControls_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICapControls::AllNames")
     }
 
     /// Number of CapControl objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Count")?;
         Ok(result)
     }
 
     /// Sets the first CapControl active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::First")?;
         Ok(result)
     }
 
     /// Sets the active CapControl by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_CapControls_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active CapControl.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ICapControls::Set_Name")?;
         unsafe { dss_capi::ctx_CapControls_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_Name")
     }
 
     /// Sets the next CapControl active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active CapControl; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active CapControl by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_idx")
     }
 
     pub fn Reset(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Reset(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Reset")
     }
 
     /// Transducer ratio from pirmary current to control current.
     pub fn Get_CTratio(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_CTratio(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_CTratio")?;
         Ok(result)
     }
 
     pub fn Set_CTratio(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_CTratio(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_CTratio")
     }
 
     /// Name of the Capacitor that is controlled.
     pub fn Get_Capacitor(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_CapControls_Get_Capacitor(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_Capacitor")?;
         Ok(result)
     }
 
-    pub fn Set_Capacitor(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Capacitor(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ICapControls::Set_Capacitor")?;
         unsafe { dss_capi::ctx_CapControls_Set_Capacitor(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_Capacitor")
     }
 
     pub fn Get_DeadTime(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_DeadTime(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_DeadTime")?;
         Ok(result)
     }
 
     pub fn Set_DeadTime(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_DeadTime(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_DeadTime")
     }
 
     /// Time delay [s] to switch on after arming.  Control may reset before actually switching.
     pub fn Get_Delay(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_Delay(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_Delay")?;
         Ok(result)
     }
 
     pub fn Set_Delay(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_Delay(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_Delay")
     }
 
     /// Time delay [s] before switching off a step. Control may reset before actually switching.
     pub fn Get_DelayOff(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_DelayOff(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_DelayOff")?;
         Ok(result)
     }
 
     pub fn Set_DelayOff(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_DelayOff(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_DelayOff")
     }
 
     /// Type of automatic controller.
     pub fn Get_Mode(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_Mode(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_Mode")?;
         Ok(result)
     }
 
     pub fn Set_Mode(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_Mode(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_Mode")
     }
 
     /// Full name of the element that PT and CT are connected to.
     pub fn Get_MonitoredObj(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_CapControls_Get_MonitoredObj(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_MonitoredObj")?;
         Ok(result)
     }
 
-    pub fn Set_MonitoredObj(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_MonitoredObj(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ICapControls::Set_MonitoredObj")?;
         unsafe { dss_capi::ctx_CapControls_Set_MonitoredObj(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_MonitoredObj")
     }
 
     /// Terminal number on the element that PT and CT are connected to.
     pub fn Get_MonitoredTerm(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_MonitoredTerm(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_MonitoredTerm")?;
         Ok(result)
     }
 
     pub fn Set_MonitoredTerm(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_MonitoredTerm(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_MonitoredTerm")
     }
 
     /// Threshold to switch off a step. See Mode for units.
     pub fn Get_OFFSetting(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_OFFSetting(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_OFFSetting")?;
         Ok(result)
     }
 
     pub fn Set_OFFSetting(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_OFFSetting(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_OFFSetting")
     }
 
     /// Threshold to arm or switch on a step.  See Mode for units.
     pub fn Get_ONSetting(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_ONSetting(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_ONSetting")?;
         Ok(result)
     }
 
     pub fn Set_ONSetting(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_ONSetting(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_ONSetting")
     }
 
     /// Transducer ratio from primary feeder to control voltage.
     pub fn Get_PTratio(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_PTratio(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_PTratio")?;
         Ok(result)
     }
 
     pub fn Set_PTratio(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_PTratio(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_PTratio")
     }
 
     /// Enables Vmin and Vmax to override the control Mode
     pub fn Get_UseVoltOverride(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_CapControls_Get_UseVoltOverride(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_UseVoltOverride")?;
         Ok(result)
     }
 
     pub fn Set_UseVoltOverride(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_UseVoltOverride(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_UseVoltOverride")
     }
 
     /// With VoltOverride, swtich off whenever PT voltage exceeds this level.
     pub fn Get_Vmax(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_Vmax(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_Vmax")?;
         Ok(result)
     }
 
     pub fn Set_Vmax(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_Vmax(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_Vmax")
     }
 
     /// With VoltOverride, switch ON whenever PT voltage drops below this level.
     pub fn Get_Vmin(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_CapControls_Get_Vmin(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICapControls::Get_Vmin")?;
         Ok(result)
     }
 
     pub fn Set_Vmin(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CapControls_Set_Vmin(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICapControls::Set_Vmin")
     }
 }
 
@@ -2778,96 +3386,109 @@ impl<'a> ICircuit<'a> {
     /// Returns a reference to the existing ActiveBus.
     pub fn Get_Buses(&self, idx: i32) -> Result<&IBus, DSSError> {
         if (unsafe { dss_capi::ctx_Circuit_SetActiveBusi(self.ctx_ptr, idx) < 0 }) {
-            let res = self.ctx.DSSError();
+            let res = self.ctx.DSSError().origin("ICircuit::Get_Buses");
             match res {
                 Err(e) => return Err(e),
-                Ok(()) => return Err(DSSError {
-                    number: 0,
-                    message: "Could not activate bus".to_string()
-                })
+                Ok(()) => return Err(DSSError::wrapper(
+                    DSSErrorKind::NotFound,
+                    "Could not activate bus".to_string()
+                )).origin("ICircuit::Get_Buses")
             }
         }
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::Get_Buses")?;
         Ok(&self.ActiveBus)
     }
 
     /// Activates and returns a bus by its name.
-    pub fn get_Buses(&self, name: String) -> Result<&IBus, DSSError> {
-        let name_c = CString::new(name).unwrap();
+    pub fn get_Buses(&self, name: impl AsRef<str>) -> Result<&IBus, DSSError> {
+        let name_c = self.ctx.PrepareString(name).origin("ICircuit::get_Buses")?;
         if (unsafe { dss_capi::ctx_Circuit_SetActiveBus(self.ctx_ptr, name_c.as_ptr()) < 0 }) {
-            let res = self.ctx.DSSError();
+            let res = self.ctx.DSSError().origin("ICircuit::get_Buses");
             match res {
                 Err(e) => return Err(e),
-                Ok(()) => return Err(DSSError {
-                    number: 0,
-                    message: "Could not activate bus".to_string()
-                })
+                Ok(()) => return Err(DSSError::wrapper(
+                    DSSErrorKind::NotFound,
+                    "Could not activate bus".to_string()
+                )).origin("ICircuit::get_Buses")
             }
         }
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::get_Buses")?;
         Ok(&self.ActiveBus)
     }
 
     /// Activates and returns a CktElement by its global (zero-based) index.
     pub fn get_CktElementsi(&self, idx: i32) -> Result<&ICktElement, DSSError> {
         unsafe { dss_capi::ctx_Circuit_SetCktElementIndex(self.ctx_ptr, idx); }
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::get_CktElementsi")?;
         Ok(&self.ActiveCktElement)
     }
 
     /// Activates and returns a CktElement by its full name (e.g. "load.abc").
-    pub fn get_CktElements(&self, fullName: String) -> Result<&ICktElement, DSSError> {
-        let fullName_c = CString::new(fullName).unwrap();
+    pub fn get_CktElements(&self, fullName: impl AsRef<str>) -> Result<&ICktElement, DSSError> {
+        let fullName_c = self.ctx.PrepareString(fullName).origin("ICircuit::get_CktElements")?;
         unsafe { dss_capi::ctx_Circuit_SetCktElementName(self.ctx_ptr, fullName_c.as_ptr()); }
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::get_CktElements")?;
         Ok(&self.ActiveCktElement)
     }
 
     pub fn Capacity(&self, Start: f64, Increment: f64) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_Capacity(self.ctx_ptr, Start, Increment) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::Capacity")?;
         Ok(result)
     }
 
-    pub fn Disable(&self, Name: String) -> Result<(), DSSError> {
-        let Name_c = CString::new(Name).unwrap();
+    pub fn Disable(&self, Name: impl AsRef<str>) -> Result<(), DSSError> {
+        let Name_c = self.ctx.PrepareString(Name).origin("ICircuit::Disable")?;
         unsafe { dss_capi::ctx_Circuit_Disable(self.ctx_ptr, Name_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICircuit::Disable")
     }
 
-    pub fn Enable(&self, Name: String) -> Result<(), DSSError> {
-        let Name_c = CString::new(Name).unwrap();
+    pub fn Enable(&self, Name: impl AsRef<str>) -> Result<(), DSSError> {
+        let Name_c = self.ctx.PrepareString(Name).origin("ICircuit::Enable")?;
         unsafe { dss_capi::ctx_Circuit_Enable(self.ctx_ptr, Name_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICircuit::Enable")
     }
 
     pub fn EndOfTimeStepUpdate(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Circuit_EndOfTimeStepUpdate(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICircuit::EndOfTimeStepUpdate")
     }
 
     pub fn FirstElement(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_FirstElement(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::FirstElement")?;
         Ok(result)
     }
 
     pub fn FirstPCElement(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_FirstPCElement(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::FirstPCElement")?;
         Ok(result)
     }
 
     pub fn FirstPDElement(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_FirstPDElement(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::FirstPDElement")?;
         Ok(result)
     }
 
     /// Returns an array of doubles representing the distances to parent EnergyMeter. Sequence of array corresponds to other node ByPhase properties.
     pub fn AllNodeDistancesByPhase(&self, Phase: i32) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeDistancesByPhase")?;
         unsafe { dss_capi::ctx_Circuit_Get_AllNodeDistancesByPhase_GR(self.ctx_ptr, Phase) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ICircuit::AllNodeDistancesByPhase")
+    }
+
+    pub fn AllNodeDistancesByPhase_view(&self, Phase: i32) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeDistancesByPhase_view")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllNodeDistancesByPhase_GR(self.ctx_ptr, Phase) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICircuit::AllNodeDistancesByPhase_view")
+    }
+
+    pub fn AllNodeDistancesByPhase_into(&self, Phase: i32, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeDistancesByPhase_into")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllNodeDistancesByPhase_GR(self.ctx_ptr, Phase) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICircuit::AllNodeDistancesByPhase_into")
     }
 
     /// Return array of strings of the node names for the By Phase criteria. Sequence corresponds to other ByPhase properties.
@@ -2875,85 +3496,124 @@ impl<'a> ICircuit<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Circuit_Get_AllNodeNamesByPhase(self.ctx_ptr, &mut data, &mut cnt[0], Phase) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICircuit::AllNodeNamesByPhase")
     }
 
     /// Returns Array of doubles represent voltage magnitudes for nodes on the specified phase.
     pub fn AllNodeVmagByPhase(&self, Phase: i32) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeVmagByPhase")?;
         unsafe { dss_capi::ctx_Circuit_Get_AllNodeVmagByPhase_GR(self.ctx_ptr, Phase) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ICircuit::AllNodeVmagByPhase")
+    }
+
+    pub fn AllNodeVmagByPhase_view(&self, Phase: i32) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeVmagByPhase_view")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllNodeVmagByPhase_GR(self.ctx_ptr, Phase) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICircuit::AllNodeVmagByPhase_view")
+    }
+
+    pub fn AllNodeVmagByPhase_into(&self, Phase: i32, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeVmagByPhase_into")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllNodeVmagByPhase_GR(self.ctx_ptr, Phase) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICircuit::AllNodeVmagByPhase_into")
     }
 
     /// Returns array of per unit voltage magnitudes for each node by phase
     pub fn AllNodeVmagPUByPhase(&self, Phase: i32) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeVmagPUByPhase")?;
         unsafe { dss_capi::ctx_Circuit_Get_AllNodeVmagPUByPhase_GR(self.ctx_ptr, Phase) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ICircuit::AllNodeVmagPUByPhase")
+    }
+
+    pub fn AllNodeVmagPUByPhase_view(&self, Phase: i32) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeVmagPUByPhase_view")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllNodeVmagPUByPhase_GR(self.ctx_ptr, Phase) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICircuit::AllNodeVmagPUByPhase_view")
+    }
+
+    pub fn AllNodeVmagPUByPhase_into(&self, Phase: i32, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeVmagPUByPhase_into")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllNodeVmagPUByPhase_GR(self.ctx_ptr, Phase) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICircuit::AllNodeVmagPUByPhase_into")
     }
 
     pub fn NextElement(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_NextElement(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::NextElement")?;
         Ok(result)
     }
 
     pub fn NextPCElement(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_NextPCElement(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::NextPCElement")?;
         Ok(result)
     }
 
     pub fn NextPDElement(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_NextPDElement(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::NextPDElement")?;
         Ok(result)
     }
 
     pub fn Sample(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Circuit_Sample(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICircuit::Sample")
     }
 
     pub fn SaveSample(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Circuit_SaveSample(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICircuit::SaveSample")
     }
 
-    pub fn SetActiveBus(&self, BusName: String) -> Result<i32, DSSError> {
-        let BusName_c = CString::new(BusName).unwrap();
+    pub fn SetActiveBus(&self, BusName: impl AsRef<str>) -> Result<i32, DSSError> {
+        let BusName_c = self.ctx.PrepareString(BusName).origin("ICircuit::SetActiveBus")?;
         let result = unsafe { dss_capi::ctx_Circuit_SetActiveBus(self.ctx_ptr, BusName_c.as_ptr()) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::SetActiveBus")?;
         Ok(result)
     }
 
     pub fn SetActiveBusi(&self, BusIndex: i32) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_SetActiveBusi(self.ctx_ptr, BusIndex) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::SetActiveBusi")?;
         Ok(result)
     }
 
-    pub fn SetActiveClass(&self, ClassName: String) -> Result<i32, DSSError> {
-        let ClassName_c = CString::new(ClassName).unwrap();
+    pub fn SetActiveClass(&self, ClassName: impl AsRef<str>) -> Result<i32, DSSError> {
+        let ClassName_c = self.ctx.PrepareString(ClassName).origin("ICircuit::SetActiveClass")?;
         let result = unsafe { dss_capi::ctx_Circuit_SetActiveClass(self.ctx_ptr, ClassName_c.as_ptr()) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::SetActiveClass")?;
         Ok(result)
     }
 
-    pub fn SetActiveElement(&self, FullName: String) -> Result<i32, DSSError> {
-        let FullName_c = CString::new(FullName).unwrap();
+    pub fn SetActiveElement(&self, FullName: impl AsRef<str>) -> Result<i32, DSSError> {
+        let FullName_c = self.ctx.PrepareString(FullName).origin("ICircuit::SetActiveElement")?;
         let result = unsafe { dss_capi::ctx_Circuit_SetActiveElement(self.ctx_ptr, FullName_c.as_ptr()) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::SetActiveElement")?;
         Ok(result)
     }
 
     pub fn UpdateStorage(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Circuit_UpdateStorage(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICircuit::UpdateStorage")
     }
 
     /// Returns distance from each bus to parent EnergyMeter. Corresponds to sequence in AllBusNames.
     pub fn AllBusDistances(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusDistances")?;
         unsafe { dss_capi::ctx_Circuit_Get_AllBusDistances_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ICircuit::AllBusDistances")
+    }
+
+    pub fn AllBusDistances_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusDistances_view")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllBusDistances_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICircuit::AllBusDistances_view")
+    }
+
+    pub fn AllBusDistances_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusDistances_into")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllBusDistances_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICircuit::AllBusDistances_into")
     }
 
     /// Array of strings containing names of all buses in circuit (see AllNodeNames).
@@ -2961,31 +3621,83 @@ impl<'a> ICircuit<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Circuit_Get_AllBusNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICircuit::AllBusNames")
     }
 
     /// Array of magnitudes (doubles) of voltages at all buses
     pub fn AllBusVmag(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusVmag")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllBusVmag_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ICircuit::AllBusVmag")
+    }
+
+    pub fn AllBusVmag_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusVmag_view")?;
         unsafe { dss_capi::ctx_Circuit_Get_AllBusVmag_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("ICircuit::AllBusVmag_view")
+    }
+
+    pub fn AllBusVmag_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusVmag_into")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllBusVmag_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICircuit::AllBusVmag_into")
     }
 
     /// Double Array of all bus voltages (each node) magnitudes in Per unit
     pub fn AllBusVmagPu(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusVmagPu")?;
         unsafe { dss_capi::ctx_Circuit_Get_AllBusVmagPu_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ICircuit::AllBusVmagPu")
+    }
+
+    pub fn AllBusVmagPu_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusVmagPu_view")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllBusVmagPu_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICircuit::AllBusVmagPu_view")
+    }
+
+    pub fn AllBusVmagPu_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusVmagPu_into")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllBusVmagPu_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICircuit::AllBusVmagPu_into")
     }
 
     /// Complex array of all bus, node voltages from most recent solution
     pub fn AllBusVolts(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusVolts")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllBusVolts_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ICircuit::AllBusVolts")
+    }
+
+    pub fn AllBusVolts_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusVolts_view")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllBusVolts_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ICircuit::AllBusVolts_view")
+    }
+
+    pub fn AllBusVolts_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllBusVolts_into")?;
         unsafe { dss_capi::ctx_Circuit_Get_AllBusVolts_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("ICircuit::AllBusVolts_into")
     }
 
     /// Array of total losses (complex) in each circuit element
     pub fn AllElementLosses(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllElementLosses")?;
         unsafe { dss_capi::ctx_Circuit_Get_AllElementLosses_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGR().origin("ICircuit::AllElementLosses")
+    }
+
+    pub fn AllElementLosses_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllElementLosses_view")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllElementLosses_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ICircuit::AllElementLosses_view")
+    }
+
+    pub fn AllElementLosses_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllElementLosses_into")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllElementLosses_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ICircuit::AllElementLosses_into")
     }
 
     /// Array of strings containing Full Name of all elements.
@@ -2993,13 +3705,26 @@ impl<'a> ICircuit<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Circuit_Get_AllElementNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICircuit::AllElementNames")
     }
 
     /// Returns an array of distances from parent EnergyMeter for each Node. Corresponds to AllBusVMag sequence.
     pub fn AllNodeDistances(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeDistances")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllNodeDistances_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ICircuit::AllNodeDistances")
+    }
+
+    pub fn AllNodeDistances_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeDistances_view")?;
+        unsafe { dss_capi::ctx_Circuit_Get_AllNodeDistances_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ICircuit::AllNodeDistances_view")
+    }
+
+    pub fn AllNodeDistances_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::AllNodeDistances_into")?;
         unsafe { dss_capi::ctx_Circuit_Get_AllNodeDistances_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ICircuit::AllNodeDistances_into")
     }
 
     /// Array of strings containing full name of each node in system in same order as returned by AllBusVolts, etc.
@@ -3007,80 +3732,110 @@ impl<'a> ICircuit<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Circuit_Get_AllNodeNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICircuit::AllNodeNames")
     }
 
     /// Complex total line losses in the circuit
     pub fn LineLosses(&self) -> Result<Complex<f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::LineLosses")?;
         unsafe { dss_capi::ctx_Circuit_Get_LineLosses_GR(self.ctx_ptr) };
-        self.ctx.GetComplexSimpleGR()
+        self.ctx.GetComplexSimpleGR().origin("ICircuit::LineLosses")
     }
 
     /// Total losses in active circuit, complex number (two-element array of double).
     pub fn Losses(&self) -> Result<Complex<f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::Losses")?;
         unsafe { dss_capi::ctx_Circuit_Get_Losses_GR(self.ctx_ptr) };
-        self.ctx.GetComplexSimpleGR()
+        self.ctx.GetComplexSimpleGR().origin("ICircuit::Losses")
     }
 
     /// Name of the active circuit.
     pub fn Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Circuit_Get_Name(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::Name")?;
         Ok(result)
     }
 
     /// Total number of Buses in the circuit.
     pub fn NumBuses(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_Get_NumBuses(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::NumBuses")?;
         Ok(result)
     }
 
     /// Number of CktElements in the circuit.
     pub fn NumCktElements(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_Get_NumCktElements(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::NumCktElements")?;
         Ok(result)
     }
 
     /// Total number of nodes in the circuit.
     pub fn NumNodes(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_Get_NumNodes(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::NumNodes")?;
         Ok(result)
     }
 
     /// Sets Parent PD element, if any, to be the active circuit element and returns index>0; Returns 0 if it fails or not applicable.
     pub fn ParentPDElement(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Circuit_Get_ParentPDElement(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::ParentPDElement")?;
         Ok(result)
     }
 
     /// Complex losses in all transformers designated to substations.
     pub fn SubstationLosses(&self) -> Result<Complex<f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::SubstationLosses")?;
         unsafe { dss_capi::ctx_Circuit_Get_SubstationLosses_GR(self.ctx_ptr) };
-        self.ctx.GetComplexSimpleGR()
+        self.ctx.GetComplexSimpleGR().origin("ICircuit::SubstationLosses")
     }
 
     /// System Y matrix (after a solution has been performed).
     /// This is deprecated as it returns a dense matrix. Only use it for small systems.
     /// For large-scale systems, prefer YMatrix.GetCompressedYMatrix.
     pub fn SystemY(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::SystemY")?;
+        unsafe { dss_capi::ctx_Circuit_Get_SystemY_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ICircuit::SystemY")
+    }
+
+    pub fn SystemY_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::SystemY_view")?;
         unsafe { dss_capi::ctx_Circuit_Get_SystemY_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRView().origin("ICircuit::SystemY_view")
+    }
+
+    pub fn SystemY_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::SystemY_into")?;
+        unsafe { dss_capi::ctx_Circuit_Get_SystemY_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ICircuit::SystemY_into")
     }
 
     /// Total power (complex), kVA delivered to the circuit
     pub fn TotalPower(&self) -> Result<Complex<f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::TotalPower")?;
         unsafe { dss_capi::ctx_Circuit_Get_TotalPower_GR(self.ctx_ptr) };
-        self.ctx.GetComplexSimpleGR()
+        self.ctx.GetComplexSimpleGR().origin("ICircuit::TotalPower")
     }
 
     /// Array of doubles containing complex injection currents for the present solution. It is the "I" vector of I=YV
     pub fn YCurrents(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::YCurrents")?;
+        unsafe { dss_capi::ctx_Circuit_Get_YCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ICircuit::YCurrents")
+    }
+
+    pub fn YCurrents_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::YCurrents_view")?;
+        unsafe { dss_capi::ctx_Circuit_Get_YCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ICircuit::YCurrents_view")
+    }
+
+    pub fn YCurrents_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::YCurrents_into")?;
         unsafe { dss_capi::ctx_Circuit_Get_YCurrents_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("ICircuit::YCurrents_into")
     }
 
     /// Array of strings containing the names of the nodes in the same order as the Y matrix
@@ -3088,13 +3843,26 @@ impl<'a> ICircuit<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Circuit_Get_YNodeOrder(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICircuit::YNodeOrder")
     }
 
     /// Complex array of actual node voltages in same order as SystemY matrix.
     pub fn YNodeVarray(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::YNodeVarray")?;
+        unsafe { dss_capi::ctx_Circuit_Get_YNodeVarray_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ICircuit::YNodeVarray")
+    }
+
+    pub fn YNodeVarray_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::YNodeVarray_view")?;
         unsafe { dss_capi::ctx_Circuit_Get_YNodeVarray_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRView().origin("ICircuit::YNodeVarray_view")
+    }
+
+    pub fn YNodeVarray_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ICircuit::YNodeVarray_into")?;
+        unsafe { dss_capi::ctx_Circuit_Get_YNodeVarray_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ICircuit::YNodeVarray_into")
     }
     /// Returns data for all objects and basic circuit properties as a JSON-encoded string.
     ///
@@ -3107,9 +3875,26 @@ impl<'a> ICircuit<'a> {
     /// (API Extension)
     pub fn ToJSON(&self, options: i32) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Circuit_ToJSON(self.ctx_ptr, options)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICircuit::ToJSON")?;
         Ok(result)
     }
+
+    /// Equivalent to the "save circuit" DSS command, with explicit flags and output
+    /// path. With the `ToString` flag, nothing is written to disk and the whole
+    /// circuit is returned as a script instead; otherwise, the result is empty.
+    ///
+    /// The `saveFlags` parameter contains bit-flags from the enum `DSSSaveFlags`.
+    ///
+    /// (API Extension)
+    pub fn Save(&self, dirOrFilePath: impl AsRef<str>, saveFlags: u32) -> Result<String, DSSError> {
+        let dirOrFilePath_c = self.ctx.PrepareString(dirOrFilePath).origin("ICircuit::Save")?;
+        let result_ptr = unsafe { dss_capi::ctx_Circuit_Save(self.ctx_ptr, dirOrFilePath_c.as_ptr(), saveFlags) };
+        self.ctx.DSSError().origin("ICircuit::Save")?;
+        if result_ptr.is_null() {
+            return Ok(String::new());
+        }
+        Ok(unsafe { CStr::from_ptr(result_ptr) }.to_string_lossy().into_owned())
+    }
 }
 
 pub struct ICtrlQueue<'a> {
@@ -3129,60 +3914,60 @@ impl<'a> ICtrlQueue<'a> {
 
     pub fn ClearActions(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CtrlQueue_ClearActions(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICtrlQueue::ClearActions")
     }
 
     pub fn ClearQueue(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CtrlQueue_ClearQueue(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICtrlQueue::ClearQueue")
     }
 
     pub fn Delete(&self, ActionHandle: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CtrlQueue_Delete(self.ctx_ptr, ActionHandle) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICtrlQueue::Delete")
     }
 
     pub fn DoAllQueue(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CtrlQueue_DoAllQueue(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICtrlQueue::DoAllQueue")
     }
 
     pub fn Show(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CtrlQueue_Show(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICtrlQueue::Show")
     }
 
     /// Code for the active action. Long integer code to tell the control device what to do
     pub fn ActionCode(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CtrlQueue_Get_ActionCode(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICtrlQueue::ActionCode")?;
         Ok(result)
     }
 
     /// Handle (User defined) to device that must act on the pending action.
     pub fn DeviceHandle(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CtrlQueue_Get_DeviceHandle(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICtrlQueue::DeviceHandle")?;
         Ok(result)
     }
 
     /// Number of Actions on the current actionlist (that have been popped off the control queue by CheckControlActions)
     pub fn NumActions(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CtrlQueue_Get_NumActions(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICtrlQueue::NumActions")?;
         Ok(result)
     }
     /// Push a control action onto the DSS control queue by time, action code, and device handle (user defined). Returns Control Queue handle.
     pub fn Push(&self, Hour: i32, Seconds: f64, ActionCode: i32, DeviceHandle: i32) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CtrlQueue_Push(self.ctx_ptr, Hour, Seconds, ActionCode, DeviceHandle) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICtrlQueue::Push")?;
         Ok(result)
     }
 
     /// Pops next action off the action list and makes it the active action. Returns zero if none.
     pub fn PopAction(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CtrlQueue_Get_PopAction(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICtrlQueue::PopAction")?;
         Ok(result)
     }
 
@@ -3191,19 +3976,19 @@ impl<'a> ICtrlQueue<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_CtrlQueue_Get_Queue(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ICtrlQueue::Queue")
     }
 
     /// Number of items on the OpenDSS control Queue
     pub fn QueueSize(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_CtrlQueue_Get_QueueSize(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ICtrlQueue::QueueSize")?;
         Ok(result)
     }
 
     pub fn Set_Action(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_CtrlQueue_Set_Action(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ICtrlQueue::Set_Action")
     }
 }
 
@@ -3230,20 +4015,20 @@ impl<'a> IDSSElement<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_DSSElement_Get_AllPropertyNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IDSSElement::AllPropertyNames")
     }
 
     /// Full Name of Active DSS Object (general element or circuit element).
     pub fn Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSSElement_Get_Name(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSSElement::Name")?;
         Ok(result)
     }
 
     /// Number of Properties for the active DSS object.
     pub fn NumProperties(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_DSSElement_Get_NumProperties(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSSElement::NumProperties")?;
         Ok(result)
     }
     /// Returns the properties of the active DSS object as a JSON-encoded string.
@@ -3254,11 +4039,67 @@ impl<'a> IDSSElement<'a> {
     /// (API Extension)
     pub fn ToJSON(&self, options: i32) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSSElement_ToJSON(self.ctx_ptr, options)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSSElement::ToJSON")?;
         Ok(result)
     }
 }
 
+pub struct IDSSEvents<'a> {
+    ctx: &'a DSSContext,
+}
+
+unsafe impl<'a> Send for IDSSEvents <'a> {
+}
+impl<'a> IDSSEvents<'a> {
+    pub fn new(ctx: &'a DSSContext) -> Self {
+        Self {
+            ctx: ctx,
+        }
+    }
+
+    /// Subscribes a closure to an event of this DSS context. The closure receives the event
+    /// code and, for the control events, the current control iteration step.
+    ///
+    /// The closure is called from the thread running the engine, in the middle of the
+    /// respective engine process (e.g. the control loop for `Legacy_CheckControls`).
+    ///
+    /// The subscription is removed when the returned value is dropped.
+    ///
+    /// (API Extension)
+    pub fn Subscribe<F: Fn(AltDSSEvent, i32) + Send + Sync + 'static>(&self, event: AltDSSEvent, callback: F) -> Result<DSSEventSubscription<'a>, DSSError> {
+        let id = callbacks::subscribe_event(self.ctx, event, Arc::new(callback)).origin("IDSSEvents::Subscribe")?;
+        Ok(DSSEventSubscription {
+            ctx: self.ctx,
+            event: event,
+            id: id,
+        })
+    }
+}
+
+/// Subscription to an event of a DSS context, created by `IDSSEvents::Subscribe`.
+/// The closure is unregistered when this is dropped.
+pub struct DSSEventSubscription<'a> {
+    ctx: &'a DSSContext,
+    event: AltDSSEvent,
+    id: u64,
+}
+
+impl<'a> DSSEventSubscription<'a> {
+    pub fn Event(&self) -> AltDSSEvent {
+        self.event
+    }
+
+    /// Removes the subscription. Same as dropping it.
+    pub fn Unsubscribe(self) {
+    }
+}
+
+impl<'a> Drop for DSSEventSubscription<'a> {
+    fn drop(&mut self) {
+        callbacks::unsubscribe_event(self.ctx, self.event, self.id);
+    }
+}
+
 pub struct IDSSProgress<'a> {
     ctx_ptr: *const c_void,
     ctx: &'a DSSContext,
@@ -3276,23 +4117,23 @@ impl<'a> IDSSProgress<'a> {
 
     pub fn Close(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSSProgress_Close(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSSProgress::Close")
     }
 
     pub fn Show(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSSProgress_Show(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSSProgress::Show")
     }
 
-    pub fn Set_Caption(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Caption(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IDSSProgress::Set_Caption")?;
         unsafe { dss_capi::ctx_DSSProgress_Set_Caption(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSSProgress::Set_Caption")
     }
 
     pub fn Set_PctProgress(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSSProgress_Set_PctProgress(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSSProgress::Set_PctProgress")
     }
 }
 
@@ -3313,39 +4154,39 @@ impl<'a> IDSSProperty<'a> {
     
     pub fn Set_idx(&self, key: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSSProperty_Set_Index(self.ctx_ptr, key); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSSProperty::Set_idx")
     }
 
-    pub fn Set_Name(&self, key: String) -> Result<(), DSSError> {
-        let key_c = CString::new(key).unwrap();
+    pub fn Set_Name(&self, key: impl AsRef<str>) -> Result<(), DSSError> {
+        let key_c = self.ctx.PrepareString(key).origin("IDSSProperty::Set_Name")?;
         unsafe { dss_capi::ctx_DSSProperty_Set_Name(self.ctx_ptr, key_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSSProperty::Set_Name")
     }
 
     /// Description of the property.
     pub fn Description(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSSProperty_Get_Description(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSSProperty::Description")?;
         Ok(result)
     }
 
     /// Name of Property
     pub fn Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSSProperty_Get_Name(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSSProperty::Name")?;
         Ok(result)
     }
 
     pub fn Get_Val(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSSProperty_Get_Val(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSSProperty::Get_Val")?;
         Ok(result)
     }
 
-    pub fn Set_Val(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Val(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IDSSProperty::Set_Val")?;
         unsafe { dss_capi::ctx_DSSProperty_Set_Val(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSSProperty::Set_Val")
     }
 }
 
@@ -3367,49 +4208,49 @@ impl<'a> IDSS_Executive<'a> {
     /// Get i-th command
     pub fn Command(&self, i: i32) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSS_Executive_Get_Command(self.ctx_ptr, i)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS_Executive::Command")?;
         Ok(result)
     }
 
     /// Get help string for i-th command
     pub fn CommandHelp(&self, i: i32) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSS_Executive_Get_CommandHelp(self.ctx_ptr, i)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS_Executive::CommandHelp")?;
         Ok(result)
     }
 
     /// Get i-th option
     pub fn Option(&self, i: i32) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSS_Executive_Get_Option(self.ctx_ptr, i)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS_Executive::Option")?;
         Ok(result)
     }
 
     /// Get help string for i-th option
     pub fn OptionHelp(&self, i: i32) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSS_Executive_Get_OptionHelp(self.ctx_ptr, i)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS_Executive::OptionHelp")?;
         Ok(result)
     }
 
     /// Get present value of i-th option
     pub fn OptionValue(&self, i: i32) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSS_Executive_Get_OptionValue(self.ctx_ptr, i)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS_Executive::OptionValue")?;
         Ok(result)
     }
 
     /// Number of DSS Executive Commands
     pub fn NumCommands(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_DSS_Executive_Get_NumCommands(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS_Executive::NumCommands")?;
         Ok(result)
     }
 
     /// Number of DSS Executive Options
     pub fn NumOptions(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_DSS_Executive_Get_NumOptions(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS_Executive::NumOptions")?;
         Ok(result)
     }
 }
@@ -3432,14 +4273,14 @@ impl<'a> IError<'a> {
     /// Description of error for last operation
     pub fn Description(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Error_Get_Description(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IError::Description")?;
         Ok(result)
     }
 
     /// Error Number (returns current value and then resets to zero)
     pub fn Number(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Error_Get_Number(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IError::Number")?;
         Ok(result)
     }
 
@@ -3448,13 +4289,13 @@ impl<'a> IError<'a> {
     /// (API Extension)
     pub fn Get_EarlyAbort(&self) -> Result<bool, DSSError> {
         let result = (unsafe { dss_capi::ctx_Error_Get_EarlyAbort(self.ctx_ptr) } != 0);
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IError::Get_EarlyAbort")?;
         Ok(result)
     }
 
     pub fn Set_EarlyAbort(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Error_Set_EarlyAbort(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IError::Set_EarlyAbort")
     }
 
     /// Controls whether the extended error mechanism is used. Defaults to True.
@@ -3478,13 +4319,13 @@ impl<'a> IError<'a> {
     /// (API Extension)
     pub fn Get_ExtendedErrors(&self) -> Result<bool, DSSError> {
         let result = (unsafe { dss_capi::ctx_Error_Get_ExtendedErrors(self.ctx_ptr) } != 0);
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IError::Get_ExtendedErrors")?;
         Ok(result)
     }
 
     pub fn Set_ExtendedErrors(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Error_Set_ExtendedErrors(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IError::Set_ExtendedErrors")
     }
 }
 
@@ -3508,124 +4349,124 @@ impl<'a> IFuses<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Fuses_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IFuses::AllNames")
     }
 
     /// Number of Fuse objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Fuses_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::Count")?;
         Ok(result)
     }
 
     /// Sets the first Fuse active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Fuses_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::First")?;
         Ok(result)
     }
 
     /// Sets the active Fuse by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Fuses_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Fuse.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IFuses::Set_Name")?;
         unsafe { dss_capi::ctx_Fuses_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Set_Name")
     }
 
     /// Sets the next Fuse active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Fuses_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Fuse; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Fuses_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Fuse by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Fuses_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Set_idx")
     }
 
     /// Close all phases of the fuse.
     pub fn Close(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Fuses_Close(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Close")
     }
 
     /// Current state of the fuses. TRUE if any fuse on any phase is blown. Else FALSE.
     pub fn IsBlown(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Fuses_IsBlown(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::IsBlown")?;
         Ok(result)
     }
 
     /// Manual opening of all phases of the fuse.
     pub fn Open(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Fuses_Open(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Open")
     }
 
     /// Reset fuse to normal state.
     pub fn Reset(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Fuses_Reset(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Reset")
     }
 
     /// A fixed delay time in seconds added to the fuse blowing time determined by the TCC curve. Default is 0.
     /// This represents a fuse clear or other delay.
     pub fn Get_Delay(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Fuses_Get_Delay(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::Get_Delay")?;
         Ok(result)
     }
 
     pub fn Set_Delay(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Fuses_Set_Delay(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Set_Delay")
     }
 
     /// Full name of the circuit element to which the fuse is connected.
     pub fn Get_MonitoredObj(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Fuses_Get_MonitoredObj(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::Get_MonitoredObj")?;
         Ok(result)
     }
 
-    pub fn Set_MonitoredObj(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_MonitoredObj(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IFuses::Set_MonitoredObj")?;
         unsafe { dss_capi::ctx_Fuses_Set_MonitoredObj(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Set_MonitoredObj")
     }
 
     /// Terminal number to which the fuse is connected.
     pub fn Get_MonitoredTerm(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Fuses_Get_MonitoredTerm(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::Get_MonitoredTerm")?;
         Ok(result)
     }
 
     pub fn Set_MonitoredTerm(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Fuses_Set_MonitoredTerm(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Set_MonitoredTerm")
     }
 
     /// Number of phases, this fuse.
     pub fn NumPhases(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Fuses_Get_NumPhases(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::NumPhases")?;
         Ok(result)
     }
 
@@ -3633,52 +4474,52 @@ impl<'a> IFuses<'a> {
     /// Multiply current values of TCC curve by this to get actual amps.
     pub fn Get_RatedCurrent(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Fuses_Get_RatedCurrent(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::Get_RatedCurrent")?;
         Ok(result)
     }
 
     pub fn Set_RatedCurrent(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Fuses_Set_RatedCurrent(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Set_RatedCurrent")
     }
 
     /// Full name of the circuit element switch that the fuse controls.
     /// Defaults to the MonitoredObj.
     pub fn Get_SwitchedObj(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Fuses_Get_SwitchedObj(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::Get_SwitchedObj")?;
         Ok(result)
     }
 
-    pub fn Set_SwitchedObj(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_SwitchedObj(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IFuses::Set_SwitchedObj")?;
         unsafe { dss_capi::ctx_Fuses_Set_SwitchedObj(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Set_SwitchedObj")
     }
 
     /// Number of the terminal of the controlled element containing the switch controlled by the fuse.
     pub fn Get_SwitchedTerm(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Fuses_Get_SwitchedTerm(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::Get_SwitchedTerm")?;
         Ok(result)
     }
 
     pub fn Set_SwitchedTerm(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Fuses_Set_SwitchedTerm(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Set_SwitchedTerm")
     }
 
     /// Name of the TCCcurve object that determines fuse blowing.
     pub fn Get_TCCcurve(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Fuses_Get_TCCcurve(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IFuses::Get_TCCcurve")?;
         Ok(result)
     }
 
-    pub fn Set_TCCcurve(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_TCCcurve(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IFuses::Set_TCCcurve")?;
         unsafe { dss_capi::ctx_Fuses_Set_TCCcurve(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Set_TCCcurve")
     }
 
     /// Array of strings indicating the state of each phase of the fuse.
@@ -3686,13 +4527,13 @@ impl<'a> IFuses<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Fuses_Get_State(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IFuses::Get_State")
     }
 
-    pub fn Set_State(&self, value: &[String]) -> Result<(), DSSError> {
-        let (_value_cstrs, value_c) = self.ctx.PrepareStringArray(value);
+    pub fn Set_State(&self, value: &[impl AsRef<str>]) -> Result<(), DSSError> {
+        let (_value_cstrs, value_c) = self.ctx.PrepareStringArray(value).origin("IFuses::Set_State")?;
         unsafe { dss_capi::ctx_Fuses_Set_State(self.ctx_ptr, value_c.as_ptr() as *mut *const c_char, value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Set_State")
     }
 
     /// Array of strings indicating the normal state of each phase of the fuse.
@@ -3700,13 +4541,13 @@ impl<'a> IFuses<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Fuses_Get_NormalState(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IFuses::Get_NormalState")
     }
 
-    pub fn Set_NormalState(&self, value: &[String]) -> Result<(), DSSError> {
-        let (_value_cstrs, value_c) = self.ctx.PrepareStringArray(value);
+    pub fn Set_NormalState(&self, value: &[impl AsRef<str>]) -> Result<(), DSSError> {
+        let (_value_cstrs, value_c) = self.ctx.PrepareStringArray(value).origin("IFuses::Set_NormalState")?;
         unsafe { dss_capi::ctx_Fuses_Set_NormalState(self.ctx_ptr, value_c.as_ptr() as *mut *const c_char, value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IFuses::Set_NormalState")
     }
 }
 
@@ -3730,91 +4571,91 @@ impl<'a> IISources<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_ISources_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IISources::AllNames")
     }
 
     /// Number of ISource objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_ISources_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IISources::Count")?;
         Ok(result)
     }
 
     /// Sets the first ISource active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_ISources_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IISources::First")?;
         Ok(result)
     }
 
     /// Sets the active ISource by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_ISources_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IISources::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active ISource.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IISources::Set_Name")?;
         unsafe { dss_capi::ctx_ISources_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IISources::Set_Name")
     }
 
     /// Sets the next ISource active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_ISources_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IISources::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active ISource; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_ISources_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IISources::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active ISource by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ISources_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IISources::Set_idx")
     }
 
     /// Magnitude of the ISource in amps
     pub fn Get_Amps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_ISources_Get_Amps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IISources::Get_Amps")?;
         Ok(result)
     }
 
     pub fn Set_Amps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ISources_Set_Amps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IISources::Set_Amps")
     }
 
     /// Phase angle for ISource, degrees
     pub fn Get_AngleDeg(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_ISources_Get_AngleDeg(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IISources::Get_AngleDeg")?;
         Ok(result)
     }
 
     pub fn Set_AngleDeg(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ISources_Set_AngleDeg(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IISources::Set_AngleDeg")
     }
 
     /// The present frequency of the ISource, Hz
     pub fn Get_Frequency(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_ISources_Get_Frequency(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IISources::Get_Frequency")?;
         Ok(result)
     }
 
     pub fn Set_Frequency(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ISources_Set_Frequency(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IISources::Set_Frequency")
     }
 }
 
@@ -3838,214 +4679,253 @@ impl<'a> ILineCodes<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_LineCodes_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ILineCodes::AllNames")
     }
 
     /// Number of LineCode objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Count")?;
         Ok(result)
     }
 
     /// Sets the first LineCode active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::First")?;
         Ok(result)
     }
 
     /// Sets the active LineCode by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_LineCodes_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active LineCode.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILineCodes::Set_Name")?;
         unsafe { dss_capi::ctx_LineCodes_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_Name")
     }
 
     /// Sets the next LineCode active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active LineCode; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active LineCode by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_idx")
     }
 
     /// Zero-sequence capacitance, nF per unit length
     pub fn Get_C0(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_C0(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Get_C0")?;
         Ok(result)
     }
 
     pub fn Set_C0(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_C0(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_C0")
     }
 
     /// Positive-sequence capacitance, nF per unit length
     pub fn Get_C1(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_C1(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Get_C1")?;
         Ok(result)
     }
 
     pub fn Set_C1(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_C1(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_C1")
     }
 
     /// Capacitance matrix, nF per unit length
     pub fn Get_Cmatrix(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineCodes::Get_Cmatrix")?;
         unsafe { dss_capi::ctx_LineCodes_Get_Cmatrix_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ILineCodes::Get_Cmatrix")
+    }
+
+    pub fn Get_Cmatrix_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineCodes::Get_Cmatrix_view")?;
+        unsafe { dss_capi::ctx_LineCodes_Get_Cmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILineCodes::Get_Cmatrix_view")
+    }
+
+    pub fn Get_Cmatrix_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineCodes::Get_Cmatrix_into")?;
+        unsafe { dss_capi::ctx_LineCodes_Get_Cmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILineCodes::Get_Cmatrix_into")
     }
 
     pub fn Set_Cmatrix(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_Cmatrix(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_Cmatrix")
     }
 
     /// Emergency ampere rating
     pub fn Get_EmergAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_EmergAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Get_EmergAmps")?;
         Ok(result)
     }
 
     pub fn Set_EmergAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_EmergAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_EmergAmps")
     }
 
     /// Flag denoting whether impedance data were entered in symmetrical components
     pub fn IsZ1Z0(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_LineCodes_Get_IsZ1Z0(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::IsZ1Z0")?;
         Ok(result)
     }
 
     /// Normal Ampere rating
     pub fn Get_NormAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_NormAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Get_NormAmps")?;
         Ok(result)
     }
 
     pub fn Set_NormAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_NormAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_NormAmps")
     }
 
     /// Number of Phases
     pub fn Get_Phases(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_Phases(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Get_Phases")?;
         Ok(result)
     }
 
     pub fn Set_Phases(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_Phases(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_Phases")
     }
 
     /// Zero-Sequence Resistance, ohms per unit length
     pub fn Get_R0(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_R0(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Get_R0")?;
         Ok(result)
     }
 
     pub fn Set_R0(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_R0(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_R0")
     }
 
     /// Positive-sequence resistance ohms per unit length
     pub fn Get_R1(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_R1(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Get_R1")?;
         Ok(result)
     }
 
     pub fn Set_R1(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_R1(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_R1")
     }
 
     /// Resistance matrix, ohms per unit length
     pub fn Get_Rmatrix(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineCodes::Get_Rmatrix")?;
         unsafe { dss_capi::ctx_LineCodes_Get_Rmatrix_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ILineCodes::Get_Rmatrix")
+    }
+
+    pub fn Get_Rmatrix_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineCodes::Get_Rmatrix_view")?;
+        unsafe { dss_capi::ctx_LineCodes_Get_Rmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILineCodes::Get_Rmatrix_view")
+    }
+
+    pub fn Get_Rmatrix_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineCodes::Get_Rmatrix_into")?;
+        unsafe { dss_capi::ctx_LineCodes_Get_Rmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILineCodes::Get_Rmatrix_into")
     }
 
     pub fn Set_Rmatrix(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_Rmatrix(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_Rmatrix")
     }
 
     pub fn Get_Units(&self) -> Result<LineUnits, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_Units(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ILineCodes::Get_Units")?;
+        result.try_into().origin("ILineCodes::Get_Units")
     }
 
     pub fn Set_Units(&self, value: LineUnits) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_Units(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_Units")
     }
 
     /// Zero Sequence Reactance, Ohms per unit length
     pub fn Get_X0(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_X0(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Get_X0")?;
         Ok(result)
     }
 
     pub fn Set_X0(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_X0(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_X0")
     }
 
     /// Posiive-sequence reactance, ohms per unit length
     pub fn Get_X1(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LineCodes_Get_X1(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineCodes::Get_X1")?;
         Ok(result)
     }
 
     pub fn Set_X1(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_X1(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_X1")
     }
 
     /// Reactance matrix, ohms per unit length
     pub fn Get_Xmatrix(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineCodes::Get_Xmatrix")?;
+        unsafe { dss_capi::ctx_LineCodes_Get_Xmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ILineCodes::Get_Xmatrix")
+    }
+
+    pub fn Get_Xmatrix_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineCodes::Get_Xmatrix_view")?;
+        unsafe { dss_capi::ctx_LineCodes_Get_Xmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILineCodes::Get_Xmatrix_view")
+    }
+
+    pub fn Get_Xmatrix_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineCodes::Get_Xmatrix_into")?;
         unsafe { dss_capi::ctx_LineCodes_Get_Xmatrix_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILineCodes::Get_Xmatrix_into")
     }
 
     pub fn Set_Xmatrix(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineCodes_Set_Xmatrix(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineCodes::Set_Xmatrix")
     }
 }
 
@@ -4071,8 +4951,23 @@ impl<'a> IMonitors<'a> {
     /// Channels start at index 1.
     pub fn Channel(&self, index: i32) -> Result<Box::<[f64]>, DSSError> {
         //TODO: use the better implementation
+        self.ctx.CheckGRBuffers().origin("IMonitors::Channel")?;
+        unsafe { dss_capi::ctx_Monitors_Get_Channel_GR(self.ctx_ptr, index); }
+        self.ctx.GetFloat64ArrayGR().origin("IMonitors::Channel")
+    }
+
+    pub fn Channel_view(&self, index: i32) -> Result<GRView<'a, f64>, DSSError> {
+        //TODO: use the better implementation
+        self.ctx.CheckGRBuffers().origin("IMonitors::Channel_view")?;
         unsafe { dss_capi::ctx_Monitors_Get_Channel_GR(self.ctx_ptr, index); }
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("IMonitors::Channel_view")
+    }
+
+    pub fn Channel_into(&self, index: i32, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        //TODO: use the better implementation
+        self.ctx.CheckGRBuffers().origin("IMonitors::Channel_into")?;
+        unsafe { dss_capi::ctx_Monitors_Get_Channel_GR(self.ctx_ptr, index); }
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IMonitors::Channel_into")
     }
 
     /// Array of strings with all Monitor names in the circuit.
@@ -4080,132 +4975,145 @@ impl<'a> IMonitors<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Monitors_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IMonitors::AllNames")
     }
 
     /// Number of Monitor objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Monitors_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::Count")?;
         Ok(result)
     }
 
     /// Sets the first Monitor active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Monitors_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::First")?;
         Ok(result)
     }
 
     /// Sets the active Monitor by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Monitors_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Monitor.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IMonitors::Set_Name")?;
         unsafe { dss_capi::ctx_Monitors_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::Set_Name")
     }
 
     /// Sets the next Monitor active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Monitors_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Monitor; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Monitors_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Monitor by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::Set_idx")
     }
 
     pub fn Process(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_Process(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::Process")
     }
 
     pub fn ProcessAll(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_ProcessAll(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::ProcessAll")
     }
 
     pub fn Reset(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_Reset(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::Reset")
     }
 
     pub fn ResetAll(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_ResetAll(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::ResetAll")
     }
 
     pub fn Sample(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_Sample(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::Sample")
     }
 
     pub fn SampleAll(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_SampleAll(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::SampleAll")
     }
 
     pub fn Save(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_Save(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::Save")
     }
 
     pub fn SaveAll(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_SaveAll(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::SaveAll")
     }
 
     pub fn Show(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_Show(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::Show")
     }
 
     /// Byte Array containing monitor stream values. Make sure a "save" is done first (standard solution modes do this automatically)
     pub fn ByteStream(&self) -> Result<Box::<[i8]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMonitors::ByteStream")?;
+        unsafe { dss_capi::ctx_Monitors_Get_ByteStream_GR(self.ctx_ptr) };
+        self.ctx.GetInt8ArrayGR().origin("IMonitors::ByteStream")
+    }
+
+    pub fn ByteStream_view(&self) -> Result<GRView<'a, i8>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMonitors::ByteStream_view")?;
+        unsafe { dss_capi::ctx_Monitors_Get_ByteStream_GR(self.ctx_ptr) };
+        self.ctx.GetInt8ArrayGRView().origin("IMonitors::ByteStream_view")
+    }
+
+    pub fn ByteStream_into(&self, out: &mut Vec<i8>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMonitors::ByteStream_into")?;
         unsafe { dss_capi::ctx_Monitors_Get_ByteStream_GR(self.ctx_ptr) };
-        self.ctx.GetInt8ArrayGR()
+        self.ctx.GetInt8ArrayGRInto(out).origin("IMonitors::ByteStream_into")
     }
 
     /// Full object name of element being monitored.
     pub fn Get_Element(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Monitors_Get_Element(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::Get_Element")?;
         Ok(result)
     }
 
-    pub fn Set_Element(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Element(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IMonitors::Set_Element")?;
         unsafe { dss_capi::ctx_Monitors_Set_Element(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::Set_Element")
     }
 
     /// Name of CSV file associated with active Monitor.
     pub fn FileName(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Monitors_Get_FileName(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::FileName")?;
         Ok(result)
     }
 
     /// Monitor File Version (integer)
     pub fn FileVersion(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Monitors_Get_FileVersion(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::FileVersion")?;
         Ok(result)
     }
 
@@ -4214,64 +5122,90 @@ impl<'a> IMonitors<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Monitors_Get_Header(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IMonitors::Header")
     }
 
     /// Set Monitor mode (bitmask integer - see DSS Help)
     pub fn Get_Mode(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Monitors_Get_Mode(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::Get_Mode")?;
         Ok(result)
     }
 
     pub fn Set_Mode(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_Set_Mode(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::Set_Mode")
     }
 
     /// Number of Channels in the active Monitor
     pub fn NumChannels(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Monitors_Get_NumChannels(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::NumChannels")?;
         Ok(result)
     }
 
     /// Size of each record in ByteStream (Integer). Same as NumChannels.
     pub fn RecordSize(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Monitors_Get_RecordSize(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::RecordSize")?;
         Ok(result)
     }
 
     /// Number of Samples in Monitor at Present
     pub fn SampleCount(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Monitors_Get_SampleCount(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::SampleCount")?;
         Ok(result)
     }
 
     /// Terminal number of element being monitored.
     pub fn Get_Terminal(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Monitors_Get_Terminal(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMonitors::Get_Terminal")?;
         Ok(result)
     }
 
     pub fn Set_Terminal(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Monitors_Set_Terminal(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMonitors::Set_Terminal")
     }
 
     /// Array of doubles containing frequency values for harmonics mode solutions; Empty for time mode solutions (use dblHour)
     pub fn dblFreq(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMonitors::dblFreq")?;
         unsafe { dss_capi::ctx_Monitors_Get_dblFreq_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("IMonitors::dblFreq")
+    }
+
+    pub fn dblFreq_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMonitors::dblFreq_view")?;
+        unsafe { dss_capi::ctx_Monitors_Get_dblFreq_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IMonitors::dblFreq_view")
+    }
+
+    pub fn dblFreq_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMonitors::dblFreq_into")?;
+        unsafe { dss_capi::ctx_Monitors_Get_dblFreq_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IMonitors::dblFreq_into")
     }
 
     /// Array of doubles containing time value in hours for time-sampled monitor values; Empty if frequency-sampled values for harmonics solution (see dblFreq)
     pub fn dblHour(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMonitors::dblHour")?;
         unsafe { dss_capi::ctx_Monitors_Get_dblHour_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("IMonitors::dblHour")
+    }
+
+    pub fn dblHour_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMonitors::dblHour_view")?;
+        unsafe { dss_capi::ctx_Monitors_Get_dblHour_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IMonitors::dblHour_view")
+    }
+
+    pub fn dblHour_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMonitors::dblHour_into")?;
+        unsafe { dss_capi::ctx_Monitors_Get_dblHour_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IMonitors::dblHour_into")
     }
 }
 
@@ -4292,130 +5226,169 @@ impl<'a> IParser<'a> {
 
     /// Use this property to parse a Matrix token in OpenDSS format.  Returns square matrix of order specified. Order same as default Fortran order: column by column.
     pub fn Matrix(&self, ExpectedOrder: i32) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParser::Matrix")?;
+        unsafe { dss_capi::ctx_Parser_Get_Matrix_GR(self.ctx_ptr, ExpectedOrder) };
+        self.ctx.GetFloat64ArrayGR().origin("IParser::Matrix")
+    }
+
+    pub fn Matrix_view(&self, ExpectedOrder: i32) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParser::Matrix_view")?;
         unsafe { dss_capi::ctx_Parser_Get_Matrix_GR(self.ctx_ptr, ExpectedOrder) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("IParser::Matrix_view")
+    }
+
+    pub fn Matrix_into(&self, ExpectedOrder: i32, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParser::Matrix_into")?;
+        unsafe { dss_capi::ctx_Parser_Get_Matrix_GR(self.ctx_ptr, ExpectedOrder) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IParser::Matrix_into")
     }
 
     /// Use this property to parse a matrix token specified in lower triangle form. Symmetry is forced.
     pub fn SymMatrix(&self, ExpectedOrder: i32) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParser::SymMatrix")?;
+        unsafe { dss_capi::ctx_Parser_Get_SymMatrix_GR(self.ctx_ptr, ExpectedOrder) };
+        self.ctx.GetFloat64ArrayGR().origin("IParser::SymMatrix")
+    }
+
+    pub fn SymMatrix_view(&self, ExpectedOrder: i32) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParser::SymMatrix_view")?;
+        unsafe { dss_capi::ctx_Parser_Get_SymMatrix_GR(self.ctx_ptr, ExpectedOrder) };
+        self.ctx.GetFloat64ArrayGRView().origin("IParser::SymMatrix_view")
+    }
+
+    pub fn SymMatrix_into(&self, ExpectedOrder: i32, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParser::SymMatrix_into")?;
         unsafe { dss_capi::ctx_Parser_Get_SymMatrix_GR(self.ctx_ptr, ExpectedOrder) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IParser::SymMatrix_into")
     }
 
     /// Returns token as array of doubles. For parsing quoted array syntax.
     pub fn Vector(&self, ExpectedSize: i32) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParser::Vector")?;
+        unsafe { dss_capi::ctx_Parser_Get_Vector_GR(self.ctx_ptr, ExpectedSize) };
+        self.ctx.GetFloat64ArrayGR().origin("IParser::Vector")
+    }
+
+    pub fn Vector_view(&self, ExpectedSize: i32) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParser::Vector_view")?;
+        unsafe { dss_capi::ctx_Parser_Get_Vector_GR(self.ctx_ptr, ExpectedSize) };
+        self.ctx.GetFloat64ArrayGRView().origin("IParser::Vector_view")
+    }
+
+    pub fn Vector_into(&self, ExpectedSize: i32, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParser::Vector_into")?;
         unsafe { dss_capi::ctx_Parser_Get_Vector_GR(self.ctx_ptr, ExpectedSize) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IParser::Vector_into")
     }
 
     pub fn ResetDelimiters(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Parser_ResetDelimiters(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParser::ResetDelimiters")
     }
 
     /// Default is FALSE. If TRUE parser automatically advances to next token after DblValue, IntValue, or StrValue. Simpler when you don't need to check for parameter names.
     pub fn Get_AutoIncrement(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Parser_Get_AutoIncrement(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParser::Get_AutoIncrement")?;
         Ok(result)
     }
 
     pub fn Set_AutoIncrement(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Parser_Set_AutoIncrement(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParser::Set_AutoIncrement")
     }
 
     /// Get/Set String containing the the characters for Quoting in OpenDSS scripts. Matching pairs defined in EndQuote. Default is "'([{.
     pub fn Get_BeginQuote(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Parser_Get_BeginQuote(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParser::Get_BeginQuote")?;
         Ok(result)
     }
 
-    pub fn Set_BeginQuote(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_BeginQuote(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IParser::Set_BeginQuote")?;
         unsafe { dss_capi::ctx_Parser_Set_BeginQuote(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParser::Set_BeginQuote")
     }
 
     /// String to be parsed. Loading this string resets the Parser to the beginning of the line. Then parse off the tokens in sequence.
     pub fn Get_CmdString(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Parser_Get_CmdString(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParser::Get_CmdString")?;
         Ok(result)
     }
 
-    pub fn Set_CmdString(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_CmdString(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IParser::Set_CmdString")?;
         unsafe { dss_capi::ctx_Parser_Set_CmdString(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParser::Set_CmdString")
     }
 
     /// Return next parameter as a double.
     pub fn DblValue(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Parser_Get_DblValue(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParser::DblValue")?;
         Ok(result)
     }
 
     /// String defining hard delimiters used to separate token on the command string. Default is , and =. The = separates token name from token value. These override whitesspace to separate tokens.
     pub fn Get_Delimiters(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Parser_Get_Delimiters(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParser::Get_Delimiters")?;
         Ok(result)
     }
 
-    pub fn Set_Delimiters(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Delimiters(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IParser::Set_Delimiters")?;
         unsafe { dss_capi::ctx_Parser_Set_Delimiters(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParser::Set_Delimiters")
     }
 
     /// String containing characters, in order, that match the beginning quote characters in BeginQuote. Default is "')]}
     pub fn Get_EndQuote(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Parser_Get_EndQuote(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParser::Get_EndQuote")?;
         Ok(result)
     }
 
-    pub fn Set_EndQuote(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_EndQuote(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IParser::Set_EndQuote")?;
         unsafe { dss_capi::ctx_Parser_Set_EndQuote(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParser::Set_EndQuote")
     }
 
     /// Return next parameter as a long integer.
     pub fn IntValue(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Parser_Get_IntValue(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParser::IntValue")?;
         Ok(result)
     }
 
     /// Get next token and return tag name (before = sign) if any. See AutoIncrement.
     pub fn NextParam(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Parser_Get_NextParam(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParser::NextParam")?;
         Ok(result)
     }
 
     /// Return next parameter as a string
     pub fn StrValue(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Parser_Get_StrValue(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParser::StrValue")?;
         Ok(result)
     }
 
     /// Get/set the characters used for White space in the command string.  Default is blank and Tab.
     pub fn Get_WhiteSpace(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Parser_Get_WhiteSpace(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParser::Get_WhiteSpace")?;
         Ok(result)
     }
 
-    pub fn Set_WhiteSpace(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_WhiteSpace(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IParser::Set_WhiteSpace")?;
         unsafe { dss_capi::ctx_Parser_Set_WhiteSpace(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParser::Set_WhiteSpace")
     }
 }
 
@@ -4437,115 +5410,115 @@ impl<'a> IReduceCkt<'a> {
     /// Zmag (ohms) for Reduce Option for Z of short lines
     pub fn Get_Zmag(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_ReduceCkt_Get_Zmag(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReduceCkt::Get_Zmag")?;
         Ok(result)
     }
 
     pub fn Set_Zmag(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ReduceCkt_Set_Zmag(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::Set_Zmag")
     }
 
     /// Keep load flag for Reduction options that remove branches
     pub fn Get_KeepLoad(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_ReduceCkt_Get_KeepLoad(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReduceCkt::Get_KeepLoad")?;
         Ok(result)
     }
 
     pub fn Set_KeepLoad(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ReduceCkt_Set_KeepLoad(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::Set_KeepLoad")
     }
 
     /// Edit String for RemoveBranches functions
     pub fn Get_EditString(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_ReduceCkt_Get_EditString(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReduceCkt::Get_EditString")?;
         Ok(result)
     }
 
-    pub fn Set_EditString(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_EditString(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IReduceCkt::Set_EditString")?;
         unsafe { dss_capi::ctx_ReduceCkt_Set_EditString(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::Set_EditString")
     }
 
     /// Start element for Remove Branch function
     pub fn Get_StartPDElement(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_ReduceCkt_Get_StartPDElement(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReduceCkt::Get_StartPDElement")?;
         Ok(result)
     }
 
-    pub fn Set_StartPDElement(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_StartPDElement(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IReduceCkt::Set_StartPDElement")?;
         unsafe { dss_capi::ctx_ReduceCkt_Set_StartPDElement(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::Set_StartPDElement")
     }
 
     /// Name of Energymeter to use for reduction
     pub fn Get_EnergyMeter(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_ReduceCkt_Get_EnergyMeter(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReduceCkt::Get_EnergyMeter")?;
         Ok(result)
     }
 
-    pub fn Set_EnergyMeter(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_EnergyMeter(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IReduceCkt::Set_EnergyMeter")?;
         unsafe { dss_capi::ctx_ReduceCkt_Set_EnergyMeter(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::Set_EnergyMeter")
     }
 
     /// Save present (reduced) circuit
     /// Filename is listed in the Text Result interface
-    pub fn SaveCircuit(&self, CktName: String) -> Result<(), DSSError> {
-        let CktName_c = CString::new(CktName).unwrap();
+    pub fn SaveCircuit(&self, CktName: impl AsRef<str>) -> Result<(), DSSError> {
+        let CktName_c = self.ctx.PrepareString(CktName).origin("IReduceCkt::SaveCircuit")?;
         unsafe { dss_capi::ctx_ReduceCkt_SaveCircuit(self.ctx_ptr, CktName_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::SaveCircuit")
     }
 
     /// Do Default Reduction algorithm
     pub fn DoDefault(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ReduceCkt_DoDefault(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::DoDefault")
     }
 
     /// Do ShortLines algorithm: Set Zmag first if you don't want the default
     pub fn DoShortLines(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ReduceCkt_DoShortLines(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::DoShortLines")
     }
 
     /// Reduce Dangling Algorithm; branches with nothing connected
     pub fn DoDangling(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ReduceCkt_DoDangling(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::DoDangling")
     }
 
     pub fn DoLoopBreak(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ReduceCkt_DoLoopBreak(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::DoLoopBreak")
     }
 
     pub fn DoParallelLines(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ReduceCkt_DoParallelLines(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::DoParallelLines")
     }
 
     pub fn DoSwitches(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ReduceCkt_DoSwitches(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::DoSwitches")
     }
 
     pub fn Do1phLaterals(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ReduceCkt_Do1phLaterals(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::Do1phLaterals")
     }
 
     pub fn DoBranchRemove(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ReduceCkt_DoBranchRemove(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReduceCkt::DoBranchRemove")
     }
 }
 
@@ -4566,187 +5539,187 @@ impl<'a> ISolution<'a> {
 
     pub fn BuildYMatrix(&self, BuildOption: i32, AllocateVI: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_BuildYMatrix(self.ctx_ptr, BuildOption, AllocateVI) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::BuildYMatrix")
     }
 
     pub fn CheckControls(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_CheckControls(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::CheckControls")
     }
 
     pub fn CheckFaultStatus(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_CheckFaultStatus(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::CheckFaultStatus")
     }
 
     pub fn Cleanup(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Cleanup(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Cleanup")
     }
 
     pub fn DoControlActions(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_DoControlActions(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::DoControlActions")
     }
 
     pub fn FinishTimeStep(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_FinishTimeStep(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::FinishTimeStep")
     }
 
     pub fn InitSnap(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_InitSnap(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::InitSnap")
     }
 
     pub fn SampleControlDevices(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_SampleControlDevices(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::SampleControlDevices")
     }
 
     pub fn Sample_DoControlActions(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Sample_DoControlActions(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Sample_DoControlActions")
     }
 
     pub fn Solve(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Solve(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Solve")
     }
 
     pub fn SolveDirect(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_SolveDirect(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::SolveDirect")
     }
 
     pub fn SolveNoControl(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_SolveNoControl(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::SolveNoControl")
     }
 
     pub fn SolvePflow(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_SolvePflow(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::SolvePflow")
     }
 
     pub fn SolvePlusControl(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_SolvePlusControl(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::SolvePlusControl")
     }
 
     pub fn SolveSnap(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_SolveSnap(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::SolveSnap")
     }
 
     /// Type of device to add in AutoAdd Mode: {dssGen (Default) | dssCap}
     pub fn Get_AddType(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_AddType(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_AddType")?;
         Ok(result)
     }
 
     pub fn Set_AddType(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_AddType(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_AddType")
     }
 
     /// Base Solution algorithm: {dssNormalSolve | dssNewtonSolve}
     pub fn Get_Algorithm(&self) -> Result<SolutionAlgorithms, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Algorithm(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ISolution::Get_Algorithm")?;
+        result.try_into().origin("ISolution::Get_Algorithm")
     }
 
     pub fn Set_Algorithm(&self, value: SolutionAlgorithms) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Algorithm(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Algorithm")
     }
 
     /// Capacitor kvar for adding capacitors in AutoAdd mode
     pub fn Get_Capkvar(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Capkvar(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_Capkvar")?;
         Ok(result)
     }
 
     pub fn Set_Capkvar(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Capkvar(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Capkvar")
     }
 
     /// Flag indicating the control actions are done.
     pub fn Get_ControlActionsDone(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Solution_Get_ControlActionsDone(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_ControlActionsDone")?;
         Ok(result)
     }
 
     pub fn Set_ControlActionsDone(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_ControlActionsDone(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_ControlActionsDone")
     }
 
     /// Value of the control iteration counter
     pub fn Get_ControlIterations(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_ControlIterations(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_ControlIterations")?;
         Ok(result)
     }
 
     pub fn Set_ControlIterations(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_ControlIterations(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_ControlIterations")
     }
 
     /// {dssStatic* | dssEvent | dssTime}  Modes for control devices
     pub fn Get_ControlMode(&self) -> Result<ControlModes, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_ControlMode(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ISolution::Get_ControlMode")?;
+        result.try_into().origin("ISolution::Get_ControlMode")
     }
 
     pub fn Set_ControlMode(&self, value: ControlModes) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_ControlMode(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_ControlMode")
     }
 
     /// Flag to indicate whether the circuit solution converged
     pub fn Get_Converged(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Solution_Get_Converged(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_Converged")?;
         Ok(result)
     }
 
     pub fn Set_Converged(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Converged(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Converged")
     }
 
     /// Default daily load shape (defaults to "Default")
     pub fn Get_DefaultDaily(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Solution_Get_DefaultDaily(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_DefaultDaily")?;
         Ok(result)
     }
 
-    pub fn Set_DefaultDaily(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_DefaultDaily(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ISolution::Set_DefaultDaily")?;
         unsafe { dss_capi::ctx_Solution_Set_DefaultDaily(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_DefaultDaily")
     }
 
     /// Default Yearly load shape (defaults to "Default")
     pub fn Get_DefaultYearly(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Solution_Get_DefaultYearly(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_DefaultYearly")?;
         Ok(result)
     }
 
-    pub fn Set_DefaultYearly(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_DefaultYearly(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ISolution::Set_DefaultYearly")?;
         unsafe { dss_capi::ctx_Solution_Set_DefaultYearly(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_DefaultYearly")
     }
 
     /// Array of strings containing the Event Log
@@ -4754,364 +5727,403 @@ impl<'a> ISolution<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Solution_Get_EventLog(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ISolution::EventLog")
     }
 
     /// Set the Frequency for next solution
     pub fn Get_Frequency(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Frequency(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_Frequency")?;
         Ok(result)
     }
 
     pub fn Set_Frequency(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Frequency(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Frequency")
     }
 
     /// Default Multiplier applied to generators (like LoadMult)
     pub fn Get_GenMult(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_GenMult(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_GenMult")?;
         Ok(result)
     }
 
     pub fn Set_GenMult(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_GenMult(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_GenMult")
     }
 
     /// PF for generators in AutoAdd mode
     pub fn Get_GenPF(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_GenPF(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_GenPF")?;
         Ok(result)
     }
 
     pub fn Set_GenPF(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_GenPF(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_GenPF")
     }
 
     /// Generator kW for AutoAdd mode
     pub fn Get_GenkW(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_GenkW(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_GenkW")?;
         Ok(result)
     }
 
     pub fn Set_GenkW(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_GenkW(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_GenkW")
     }
 
     /// Set Hour for time series solutions.
     pub fn Get_Hour(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Hour(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_Hour")?;
         Ok(result)
     }
 
     pub fn Set_Hour(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Hour(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Hour")
     }
 
     /// Get/Set the Solution.IntervalHrs variable used for devices that integrate / custom solution algorithms
     pub fn Get_IntervalHrs(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_IntervalHrs(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_IntervalHrs")?;
         Ok(result)
     }
 
     pub fn Set_IntervalHrs(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_IntervalHrs(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_IntervalHrs")
     }
 
     /// Number of iterations taken for last solution. (Same as Totaliterations)
     pub fn Iterations(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Iterations(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Iterations")?;
         Ok(result)
     }
 
     /// Load-Duration Curve name for LD modes
     pub fn Get_LDCurve(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Solution_Get_LDCurve(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_LDCurve")?;
         Ok(result)
     }
 
-    pub fn Set_LDCurve(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_LDCurve(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ISolution::Set_LDCurve")?;
         unsafe { dss_capi::ctx_Solution_Set_LDCurve(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_LDCurve")
     }
 
     /// Load Model: {dssPowerFlow (default) | dssAdmittance}
     pub fn Get_LoadModel(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_LoadModel(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_LoadModel")?;
         Ok(result)
     }
 
     pub fn Set_LoadModel(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_LoadModel(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_LoadModel")
     }
 
     /// Default load multiplier applied to all non-fixed loads
     pub fn Get_LoadMult(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_LoadMult(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_LoadMult")?;
         Ok(result)
     }
 
     pub fn Set_LoadMult(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_LoadMult(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_LoadMult")
     }
 
     /// Maximum allowable control iterations
     pub fn Get_MaxControlIterations(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_MaxControlIterations(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_MaxControlIterations")?;
         Ok(result)
     }
 
     pub fn Set_MaxControlIterations(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_MaxControlIterations(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_MaxControlIterations")
     }
 
     /// Max allowable iterations.
     pub fn Get_MaxIterations(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_MaxIterations(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_MaxIterations")?;
         Ok(result)
     }
 
     pub fn Set_MaxIterations(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_MaxIterations(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_MaxIterations")
     }
 
     /// Minimum number of iterations required for a power flow solution.
     pub fn Get_MinIterations(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_MinIterations(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_MinIterations")?;
         Ok(result)
     }
 
     pub fn Set_MinIterations(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_MinIterations(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_MinIterations")
     }
 
     /// Set present solution mode
     pub fn Get_Mode(&self) -> Result<SolveModes, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Mode(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ISolution::Get_Mode")?;
+        result.try_into().origin("ISolution::Get_Mode")
     }
 
     pub fn Set_Mode(&self, value: SolveModes) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Mode(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Mode")
     }
 
     /// ID (text) of the present solution mode
     pub fn ModeID(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Solution_Get_ModeID(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::ModeID")?;
         Ok(result)
     }
 
     /// Max number of iterations required to converge at any control iteration of the most recent solution.
     pub fn MostIterationsDone(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_MostIterationsDone(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::MostIterationsDone")?;
         Ok(result)
     }
 
     /// Number of solutions to perform for Monte Carlo and time series simulations
     pub fn Get_Number(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Number(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_Number")?;
         Ok(result)
     }
 
     pub fn Set_Number(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Number(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Number")
     }
 
     /// Gets the time required to perform the latest solution (Read only)
     pub fn Process_Time(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Process_Time(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Process_Time")?;
         Ok(result)
     }
 
     /// Randomization mode for random variables "Gaussian" or "Uniform"
     pub fn Get_Random(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Random(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_Random")?;
         Ok(result)
     }
 
     pub fn Set_Random(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Random(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Random")
     }
 
     /// Seconds from top of the hour.
     pub fn Get_Seconds(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Seconds(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_Seconds")?;
         Ok(result)
     }
 
     pub fn Set_Seconds(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Seconds(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Seconds")
     }
 
     /// Time step size in sec
     pub fn Get_StepSize(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_StepSize(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_StepSize")?;
         Ok(result)
     }
 
     pub fn Set_StepSize(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_StepSize(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_StepSize")
     }
 
     /// Flag that indicates if elements of the System Y have been changed by recent activity.
     pub fn SystemYChanged(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Solution_Get_SystemYChanged(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::SystemYChanged")?;
         Ok(result)
     }
 
     /// Get the solution process time + sample time for time step
     pub fn Time_of_Step(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Time_of_Step(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Time_of_Step")?;
         Ok(result)
     }
 
     /// Solution convergence tolerance.
     pub fn Get_Tolerance(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Tolerance(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_Tolerance")?;
         Ok(result)
     }
 
     pub fn Set_Tolerance(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Tolerance(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Tolerance")
     }
 
     /// Gets/sets the accumulated time of the simulation
     pub fn Get_Total_Time(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Total_Time(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_Total_Time")?;
         Ok(result)
     }
 
     pub fn Set_Total_Time(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Total_Time(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Total_Time")
     }
 
     /// Total iterations including control iterations for most recent solution.
     pub fn Totaliterations(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Totaliterations(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Totaliterations")?;
         Ok(result)
     }
 
     /// Set year for planning studies
     pub fn Get_Year(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_Year(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_Year")?;
         Ok(result)
     }
 
     pub fn Set_Year(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_Year(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_Year")
     }
 
     /// Hour as a double, including fractional part
     pub fn Get_dblHour(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_dblHour(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_dblHour")?;
         Ok(result)
     }
 
     pub fn Set_dblHour(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_dblHour(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_dblHour")
     }
 
     /// Percent default  annual load growth rate
     pub fn Get_pctGrowth(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Solution_Get_pctGrowth(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISolution::Get_pctGrowth")?;
         Ok(result)
     }
 
     pub fn Set_pctGrowth(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_pctGrowth(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_pctGrowth")
     }
 
     pub fn Set_StepsizeHr(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_StepsizeHr(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_StepsizeHr")
     }
 
     pub fn Set_StepsizeMin(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_Set_StepsizeMin(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::Set_StepsizeMin")
     }
 
     pub fn BusLevels(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISolution::BusLevels")?;
         unsafe { dss_capi::ctx_Solution_Get_BusLevels_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGR().origin("ISolution::BusLevels")
+    }
+
+    pub fn BusLevels_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISolution::BusLevels_view")?;
+        unsafe { dss_capi::ctx_Solution_Get_BusLevels_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRView().origin("ISolution::BusLevels_view")
+    }
+
+    pub fn BusLevels_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISolution::BusLevels_into")?;
+        unsafe { dss_capi::ctx_Solution_Get_BusLevels_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRInto(out).origin("ISolution::BusLevels_into")
     }
 
     pub fn IncMatrix(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISolution::IncMatrix")?;
         unsafe { dss_capi::ctx_Solution_Get_IncMatrix_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGR().origin("ISolution::IncMatrix")
+    }
+
+    pub fn IncMatrix_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISolution::IncMatrix_view")?;
+        unsafe { dss_capi::ctx_Solution_Get_IncMatrix_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRView().origin("ISolution::IncMatrix_view")
+    }
+
+    pub fn IncMatrix_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISolution::IncMatrix_into")?;
+        unsafe { dss_capi::ctx_Solution_Get_IncMatrix_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRInto(out).origin("ISolution::IncMatrix_into")
     }
 
     pub fn IncMatrixCols(&self) -> Result<Box::<[String]>, DSSError> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Solution_Get_IncMatrixCols(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ISolution::IncMatrixCols")
     }
 
     pub fn IncMatrixRows(&self) -> Result<Box::<[String]>, DSSError> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Solution_Get_IncMatrixRows(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ISolution::IncMatrixRows")
     }
 
     pub fn Laplacian(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISolution::Laplacian")?;
+        unsafe { dss_capi::ctx_Solution_Get_Laplacian_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGR().origin("ISolution::Laplacian")
+    }
+
+    pub fn Laplacian_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISolution::Laplacian_view")?;
         unsafe { dss_capi::ctx_Solution_Get_Laplacian_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGRView().origin("ISolution::Laplacian_view")
+    }
+
+    pub fn Laplacian_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISolution::Laplacian_into")?;
+        unsafe { dss_capi::ctx_Solution_Get_Laplacian_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRInto(out).origin("ISolution::Laplacian_into")
     }
     pub fn SolveAll(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Solution_SolveAll(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISolution::SolveAll")
     }
 }
 
@@ -5131,14 +6143,26 @@ impl<'a> ILineGeometries<'a> {
     }
     
     pub fn Get_Units(&self) -> Result<Box::<[LineUnits]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Get_Units")?;
         unsafe { dss_capi::ctx_LineGeometries_Get_Units_GR(self.ctx_ptr); }
-        let int_result = self.ctx.GetInt32ArrayGR()?;
-        Ok(unsafe { transmute(int_result) })
+        let int_result = self.ctx.GetInt32ArrayGR().origin("ILineGeometries::Get_Units")?;
+        int_result.iter().map(|v| LineUnits::try_from(*v)).collect()
+    }
+
+    pub fn Get_Units_into(&self, out: &mut Vec<LineUnits>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Get_Units_into")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Units_GR(self.ctx_ptr); }
+        let int_result = self.ctx.GetInt32ArrayGRView().origin("ILineGeometries::Get_Units_into")?;
+        out.clear();
+        for v in int_result.iter() {
+            out.push(LineUnits::try_from(*v)?);
+        }
+        Ok(())
     }
 
     pub fn Set_Units(&self, value: &[LineUnits]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineGeometries_Set_Units(self.ctx_ptr, value.as_ptr() as *const i32, value.len() as i32); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineGeometries::Set_Units")
     }
 
     /// Array of strings with all LineGeometrie names in the circuit.
@@ -5146,55 +6170,55 @@ impl<'a> ILineGeometries<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_LineGeometries_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ILineGeometries::AllNames")
     }
 
     /// Number of LineGeometrie objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineGeometries_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineGeometries::Count")?;
         Ok(result)
     }
 
     /// Sets the first LineGeometrie active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineGeometries_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineGeometries::First")?;
         Ok(result)
     }
 
     /// Sets the active LineGeometrie by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_LineGeometries_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineGeometries::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active LineGeometrie.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILineGeometries::Set_Name")?;
         unsafe { dss_capi::ctx_LineGeometries_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineGeometries::Set_Name")
     }
 
     /// Sets the next LineGeometrie active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineGeometries_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineGeometries::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active LineGeometrie; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineGeometries_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineGeometries::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active LineGeometrie by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineGeometries_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineGeometries::Set_idx")
     }
 
     /// Array of strings with names of all conductors in the active LineGeometry object
@@ -5202,123 +6226,201 @@ impl<'a> ILineGeometries<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_LineGeometries_Get_Conductors(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ILineGeometries::Conductors")
     }
 
     /// Emergency ampere rating
     pub fn Get_EmergAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LineGeometries_Get_EmergAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineGeometries::Get_EmergAmps")?;
         Ok(result)
     }
 
     pub fn Set_EmergAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineGeometries_Set_EmergAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineGeometries::Set_EmergAmps")
     }
 
     /// Normal ampere rating
     pub fn Get_NormAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LineGeometries_Get_NormAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineGeometries::Get_NormAmps")?;
         Ok(result)
     }
 
     pub fn Set_NormAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineGeometries_Set_NormAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineGeometries::Set_NormAmps")
     }
 
     pub fn Get_RhoEarth(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LineGeometries_Get_RhoEarth(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineGeometries::Get_RhoEarth")?;
         Ok(result)
     }
 
     pub fn Set_RhoEarth(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineGeometries_Set_RhoEarth(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineGeometries::Set_RhoEarth")
     }
 
     pub fn Get_Reduce(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_LineGeometries_Get_Reduce(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineGeometries::Get_Reduce")?;
         Ok(result)
     }
 
     pub fn Set_Reduce(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineGeometries_Set_Reduce(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineGeometries::Set_Reduce")
     }
 
     /// Number of Phases
     pub fn Get_Phases(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineGeometries_Get_Phases(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineGeometries::Get_Phases")?;
         Ok(result)
     }
 
     pub fn Set_Phases(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineGeometries_Set_Phases(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineGeometries::Set_Phases")
     }
 
     /// Resistance matrix, ohms
     pub fn Rmatrix(&self, Frequency: f64, Length: f64, Units: i32) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Rmatrix")?;
         unsafe { dss_capi::ctx_LineGeometries_Get_Rmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ILineGeometries::Rmatrix")
+    }
+
+    pub fn Rmatrix_view(&self, Frequency: f64, Length: f64, Units: i32) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Rmatrix_view")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Rmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILineGeometries::Rmatrix_view")
+    }
+
+    pub fn Rmatrix_into(&self, Frequency: f64, Length: f64, Units: i32, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Rmatrix_into")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Rmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILineGeometries::Rmatrix_into")
     }
 
     /// Reactance matrix, ohms
     pub fn Xmatrix(&self, Frequency: f64, Length: f64, Units: i32) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Xmatrix")?;
         unsafe { dss_capi::ctx_LineGeometries_Get_Xmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ILineGeometries::Xmatrix")
+    }
+
+    pub fn Xmatrix_view(&self, Frequency: f64, Length: f64, Units: i32) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Xmatrix_view")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Xmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILineGeometries::Xmatrix_view")
+    }
+
+    pub fn Xmatrix_into(&self, Frequency: f64, Length: f64, Units: i32, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Xmatrix_into")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Xmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILineGeometries::Xmatrix_into")
     }
 
     /// Complex impedance matrix, ohms
     pub fn Zmatrix(&self, Frequency: f64, Length: f64, Units: i32) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Zmatrix")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Zmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
+        self.ctx.GetComplexArrayGR().origin("ILineGeometries::Zmatrix")
+    }
+
+    pub fn Zmatrix_view(&self, Frequency: f64, Length: f64, Units: i32) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Zmatrix_view")?;
         unsafe { dss_capi::ctx_LineGeometries_Get_Zmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRView().origin("ILineGeometries::Zmatrix_view")
+    }
+
+    pub fn Zmatrix_into(&self, Frequency: f64, Length: f64, Units: i32, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Zmatrix_into")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Zmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ILineGeometries::Zmatrix_into")
     }
 
     /// Capacitance matrix, nF
     pub fn Cmatrix(&self, Frequency: f64, Length: f64, Units: i32) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Cmatrix")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Cmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
+        self.ctx.GetFloat64ArrayGR().origin("ILineGeometries::Cmatrix")
+    }
+
+    pub fn Cmatrix_view(&self, Frequency: f64, Length: f64, Units: i32) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Cmatrix_view")?;
         unsafe { dss_capi::ctx_LineGeometries_Get_Cmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("ILineGeometries::Cmatrix_view")
+    }
+
+    pub fn Cmatrix_into(&self, Frequency: f64, Length: f64, Units: i32, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Cmatrix_into")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Cmatrix_GR(self.ctx_ptr, Frequency, Length, Units) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILineGeometries::Cmatrix_into")
     }
 
     /// Get/Set the X (horizontal) coordinates of the conductors
     pub fn Get_Xcoords(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Get_Xcoords")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Xcoords_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ILineGeometries::Get_Xcoords")
+    }
+
+    pub fn Get_Xcoords_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Get_Xcoords_view")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Xcoords_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILineGeometries::Get_Xcoords_view")
+    }
+
+    pub fn Get_Xcoords_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Get_Xcoords_into")?;
         unsafe { dss_capi::ctx_LineGeometries_Get_Xcoords_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILineGeometries::Get_Xcoords_into")
     }
 
     pub fn Set_Xcoords(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineGeometries_Set_Xcoords(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineGeometries::Set_Xcoords")
     }
 
     /// Get/Set the Y (vertical/height) coordinates of the conductors
     pub fn Get_Ycoords(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Get_Ycoords")?;
         unsafe { dss_capi::ctx_LineGeometries_Get_Ycoords_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGR().origin("ILineGeometries::Get_Ycoords")
+    }
+
+    pub fn Get_Ycoords_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Get_Ycoords_view")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Ycoords_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILineGeometries::Get_Ycoords_view")
+    }
+
+    pub fn Get_Ycoords_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineGeometries::Get_Ycoords_into")?;
+        unsafe { dss_capi::ctx_LineGeometries_Get_Ycoords_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILineGeometries::Get_Ycoords_into")
     }
 
     pub fn Set_Ycoords(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineGeometries_Set_Ycoords(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineGeometries::Set_Ycoords")
     }
 
     /// Number of conductors in this geometry. Default is 3. Triggers memory allocations. Define first!
     pub fn Get_Nconds(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineGeometries_Get_Nconds(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineGeometries::Get_Nconds")?;
         Ok(result)
     }
 
     pub fn Set_Nconds(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineGeometries_Set_Nconds(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineGeometries::Set_Nconds")
     }
 }
 
@@ -5342,111 +6444,137 @@ impl<'a> ILineSpacings<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_LineSpacings_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ILineSpacings::AllNames")
     }
 
     /// Number of LineSpacing objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineSpacings_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineSpacings::Count")?;
         Ok(result)
     }
 
     /// Sets the first LineSpacing active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineSpacings_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineSpacings::First")?;
         Ok(result)
     }
 
     /// Sets the active LineSpacing by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_LineSpacings_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineSpacings::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active LineSpacing.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILineSpacings::Set_Name")?;
         unsafe { dss_capi::ctx_LineSpacings_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineSpacings::Set_Name")
     }
 
     /// Sets the next LineSpacing active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineSpacings_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineSpacings::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active LineSpacing; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineSpacings_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineSpacings::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active LineSpacing by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineSpacings_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineSpacings::Set_idx")
     }
 
     /// Number of Phases
     pub fn Get_Phases(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineSpacings_Get_Phases(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineSpacings::Get_Phases")?;
         Ok(result)
     }
 
     pub fn Set_Phases(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineSpacings_Set_Phases(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineSpacings::Set_Phases")
     }
 
     pub fn Get_Nconds(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LineSpacings_Get_Nconds(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILineSpacings::Get_Nconds")?;
         Ok(result)
     }
 
     pub fn Set_Nconds(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineSpacings_Set_Nconds(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineSpacings::Set_Nconds")
     }
 
     pub fn Get_Units(&self) -> Result<LineUnits, DSSError> {
         let result = unsafe { dss_capi::ctx_LineSpacings_Get_Units(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ILineSpacings::Get_Units")?;
+        result.try_into().origin("ILineSpacings::Get_Units")
+    }
+
+    pub fn Set_Units(&self, value: LineUnits) -> Result<(), DSSError> {
+        unsafe { dss_capi::ctx_LineSpacings_Set_Units(self.ctx_ptr, (value) as i32) };
+        self.ctx.DSSError().origin("ILineSpacings::Set_Units")
+    }
+
+    /// Get/Set the X (horizontal) coordinates of the conductors
+    pub fn Get_Xcoords(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineSpacings::Get_Xcoords")?;
+        unsafe { dss_capi::ctx_LineSpacings_Get_Xcoords_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ILineSpacings::Get_Xcoords")
     }
 
-    pub fn Set_Units(&self, value: LineUnits) -> Result<(), DSSError> {
-        unsafe { dss_capi::ctx_LineSpacings_Set_Units(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+    pub fn Get_Xcoords_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineSpacings::Get_Xcoords_view")?;
+        unsafe { dss_capi::ctx_LineSpacings_Get_Xcoords_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILineSpacings::Get_Xcoords_view")
     }
 
-    /// Get/Set the X (horizontal) coordinates of the conductors
-    pub fn Get_Xcoords(&self) -> Result<Box::<[f64]>, DSSError> {
+    pub fn Get_Xcoords_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineSpacings::Get_Xcoords_into")?;
         unsafe { dss_capi::ctx_LineSpacings_Get_Xcoords_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILineSpacings::Get_Xcoords_into")
     }
 
     pub fn Set_Xcoords(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineSpacings_Set_Xcoords(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineSpacings::Set_Xcoords")
     }
 
     /// Get/Set the Y (vertical/height) coordinates of the conductors
     pub fn Get_Ycoords(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineSpacings::Get_Ycoords")?;
+        unsafe { dss_capi::ctx_LineSpacings_Get_Ycoords_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ILineSpacings::Get_Ycoords")
+    }
+
+    pub fn Get_Ycoords_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineSpacings::Get_Ycoords_view")?;
         unsafe { dss_capi::ctx_LineSpacings_Get_Ycoords_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("ILineSpacings::Get_Ycoords_view")
+    }
+
+    pub fn Get_Ycoords_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILineSpacings::Get_Ycoords_into")?;
+        unsafe { dss_capi::ctx_LineSpacings_Get_Ycoords_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILineSpacings::Get_Ycoords_into")
     }
 
     pub fn Set_Ycoords(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LineSpacings_Set_Ycoords(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILineSpacings::Set_Ycoords")
     }
 }
 
@@ -5470,182 +6598,221 @@ impl<'a> ILoadShapes<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_LoadShapes_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ILoadShapes::AllNames")
     }
 
     /// Number of LoadShape objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LoadShapes_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::Count")?;
         Ok(result)
     }
 
     /// Sets the first LoadShape active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LoadShapes_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::First")?;
         Ok(result)
     }
 
     /// Sets the active LoadShape by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_LoadShapes_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active LoadShape.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILoadShapes::Set_Name")?;
         unsafe { dss_capi::ctx_LoadShapes_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_Name")
     }
 
     /// Sets the next LoadShape active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LoadShapes_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active LoadShape; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LoadShapes_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active LoadShape by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_idx")
     }
 
-    pub fn New(&self, Name: String) -> Result<i32, DSSError> {
-        let Name_c = CString::new(Name).unwrap();
+    pub fn New(&self, Name: impl AsRef<str>) -> Result<i32, DSSError> {
+        let Name_c = self.ctx.PrepareString(Name).origin("ILoadShapes::New")?;
         let result = unsafe { dss_capi::ctx_LoadShapes_New(self.ctx_ptr, Name_c.as_ptr()) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::New")?;
         Ok(result)
     }
 
     pub fn Normalize(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Normalize(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Normalize")
     }
 
     /// Fixed interval time value, hours.
     pub fn Get_HrInterval(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LoadShapes_Get_HrInterval(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::Get_HrInterval")?;
         Ok(result)
     }
 
     pub fn Set_HrInterval(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Set_HrInterval(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_HrInterval")
     }
 
     /// Fixed Interval time value, in minutes
     pub fn Get_MinInterval(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LoadShapes_Get_MinInterval(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::Get_MinInterval")?;
         Ok(result)
     }
 
     pub fn Set_MinInterval(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Set_MinInterval(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_MinInterval")
     }
 
     /// Get/set Number of points in active Loadshape.
     pub fn Get_Npts(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_LoadShapes_Get_Npts(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::Get_Npts")?;
         Ok(result)
     }
 
     pub fn Set_Npts(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Set_Npts(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_Npts")
     }
 
     pub fn Get_PBase(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LoadShapes_Get_PBase(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::Get_PBase")?;
         Ok(result)
     }
 
     pub fn Set_PBase(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Set_PBase(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_PBase")
     }
 
     /// Array of doubles for the P multiplier in the Loadshape.
     pub fn Get_Pmult(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoadShapes::Get_Pmult")?;
+        unsafe { dss_capi::ctx_LoadShapes_Get_Pmult_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ILoadShapes::Get_Pmult")
+    }
+
+    pub fn Get_Pmult_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoadShapes::Get_Pmult_view")?;
+        unsafe { dss_capi::ctx_LoadShapes_Get_Pmult_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILoadShapes::Get_Pmult_view")
+    }
+
+    pub fn Get_Pmult_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoadShapes::Get_Pmult_into")?;
         unsafe { dss_capi::ctx_LoadShapes_Get_Pmult_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILoadShapes::Get_Pmult_into")
     }
 
     pub fn Set_Pmult(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Set_Pmult(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_Pmult")
     }
 
     /// Base for normalizing Q curve. If left at zero, the peak value is used.
     pub fn Get_QBase(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LoadShapes_Get_Qbase(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::Get_QBase")?;
         Ok(result)
     }
 
     pub fn Set_QBase(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Set_Qbase(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_QBase")
     }
 
     /// Array of doubles containing the Q multipliers.
     pub fn Get_Qmult(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoadShapes::Get_Qmult")?;
+        unsafe { dss_capi::ctx_LoadShapes_Get_Qmult_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ILoadShapes::Get_Qmult")
+    }
+
+    pub fn Get_Qmult_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoadShapes::Get_Qmult_view")?;
         unsafe { dss_capi::ctx_LoadShapes_Get_Qmult_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("ILoadShapes::Get_Qmult_view")
+    }
+
+    pub fn Get_Qmult_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoadShapes::Get_Qmult_into")?;
+        unsafe { dss_capi::ctx_LoadShapes_Get_Qmult_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILoadShapes::Get_Qmult_into")
     }
 
     pub fn Set_Qmult(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Set_Qmult(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_Qmult")
     }
 
     /// Time array in hours correscponding to P and Q multipliers when the Interval=0.
     pub fn Get_TimeArray(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoadShapes::Get_TimeArray")?;
+        unsafe { dss_capi::ctx_LoadShapes_Get_TimeArray_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ILoadShapes::Get_TimeArray")
+    }
+
+    pub fn Get_TimeArray_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoadShapes::Get_TimeArray_view")?;
+        unsafe { dss_capi::ctx_LoadShapes_Get_TimeArray_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ILoadShapes::Get_TimeArray_view")
+    }
+
+    pub fn Get_TimeArray_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoadShapes::Get_TimeArray_into")?;
         unsafe { dss_capi::ctx_LoadShapes_Get_TimeArray_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILoadShapes::Get_TimeArray_into")
     }
 
     pub fn Set_TimeArray(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Set_TimeArray(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_TimeArray")
     }
 
     /// Boolean flag to let Loads know to use the actual value in the curve rather than use the value as a multiplier.
     pub fn Get_UseActual(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_LoadShapes_Get_UseActual(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::Get_UseActual")?;
         Ok(result)
     }
 
     pub fn Set_UseActual(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Set_UseActual(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_UseActual")
     }
 
     pub fn Get_sInterval(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_LoadShapes_Get_SInterval(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoadShapes::Get_sInterval")?;
         Ok(result)
     }
 
     pub fn Set_sInterval(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_Set_SInterval(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::Set_sInterval")
     }
 
     /// Converts the current LoadShape data to float32/single precision.
@@ -5654,7 +6821,7 @@ impl<'a> ILoadShapes<'a> {
     /// (API Extension)
     pub fn UseFloat32(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_UseFloat32(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::UseFloat32")
     }
 
     /// Converts the current LoadShape data to float64/double precision.
@@ -5663,7 +6830,7 @@ impl<'a> ILoadShapes<'a> {
     /// (API Extension)
     pub fn UseFloat64(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_LoadShapes_UseFloat64(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoadShapes::UseFloat64")
     }
 }
 
@@ -5687,473 +6854,486 @@ impl<'a> ILoads<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Loads_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ILoads::AllNames")
     }
 
     /// Number of Load objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Count")?;
         Ok(result)
     }
 
     /// Sets the first Load active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::First")?;
         Ok(result)
     }
 
     /// Sets the active Load by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Loads_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Load.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILoads::Set_Name")?;
         unsafe { dss_capi::ctx_Loads_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Name")
     }
 
     /// Sets the next Load active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Load; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Load by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_idx")
     }
 
     /// Factor for allocating loads by connected xfkva
     pub fn Get_AllocationFactor(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_AllocationFactor(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_AllocationFactor")?;
         Ok(result)
     }
 
     pub fn Set_AllocationFactor(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_AllocationFactor(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_AllocationFactor")
     }
 
     /// Name of a loadshape with both Mult and Qmult, for CVR factors as a function of time.
     pub fn Get_CVRcurve(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Loads_Get_CVRcurve(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_CVRcurve")?;
         Ok(result)
     }
 
-    pub fn Set_CVRcurve(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_CVRcurve(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILoads::Set_CVRcurve")?;
         unsafe { dss_capi::ctx_Loads_Set_CVRcurve(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_CVRcurve")
     }
 
     /// Percent reduction in Q for percent reduction in V. Must be used with dssLoadModelCVR.
     pub fn Get_CVRvars(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_CVRvars(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_CVRvars")?;
         Ok(result)
     }
 
     pub fn Set_CVRvars(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_CVRvars(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_CVRvars")
     }
 
     /// Percent reduction in P for percent reduction in V. Must be used with dssLoadModelCVR.
     pub fn Get_CVRwatts(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_CVRwatts(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_CVRwatts")?;
         Ok(result)
     }
 
     pub fn Set_CVRwatts(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_CVRwatts(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_CVRwatts")
     }
 
     /// Factor relates average to peak kw.  Used for allocation with kwh and kwhdays
     pub fn Get_Cfactor(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Cfactor(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Cfactor")?;
         Ok(result)
     }
 
     pub fn Set_Cfactor(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_Cfactor(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Cfactor")
     }
 
     pub fn Get_Class(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Class_(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Class")?;
         Ok(result)
     }
 
     pub fn Set_Class(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_Class_(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Class")
     }
 
     /// Name of the growthshape curve for yearly load growth factors.
     pub fn Get_Growth(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Loads_Get_Growth(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Growth")?;
         Ok(result)
     }
 
-    pub fn Set_Growth(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Growth(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILoads::Set_Growth")?;
         unsafe { dss_capi::ctx_Loads_Set_Growth(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Growth")
     }
 
     /// Delta loads are connected line-to-line.
     pub fn Get_IsDelta(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Loads_Get_IsDelta(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_IsDelta")?;
         Ok(result)
     }
 
     pub fn Set_IsDelta(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_IsDelta(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_IsDelta")
     }
 
     /// The Load Model defines variation of P and Q with voltage.
     pub fn Get_Model(&self) -> Result<LoadModels, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Model(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ILoads::Get_Model")?;
+        result.try_into().origin("ILoads::Get_Model")
     }
 
     pub fn Set_Model(&self, value: LoadModels) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_Model(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Model")
     }
 
     /// Number of customers in this load, defaults to one.
     pub fn Get_NumCust(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_NumCust(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_NumCust")?;
         Ok(result)
     }
 
     pub fn Set_NumCust(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_NumCust(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_NumCust")
     }
 
     /// Get or set Power Factor for Active Load. Specify leading PF as negative. Updates kvar based on present value of kW
     pub fn Get_PF(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_PF(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_PF")?;
         Ok(result)
     }
 
     pub fn Set_PF(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_PF(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_PF")
     }
 
     /// Average percent of nominal load in Monte Carlo studies; only if no loadshape defined for this load.
     pub fn Get_PctMean(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_PctMean(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_PctMean")?;
         Ok(result)
     }
 
     pub fn Set_PctMean(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_PctMean(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_PctMean")
     }
 
     /// Percent standard deviation for Monte Carlo load studies; if there is no loadshape assigned to this load.
     pub fn Get_PctStdDev(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_PctStdDev(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_PctStdDev")?;
         Ok(result)
     }
 
     pub fn Set_PctStdDev(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_PctStdDev(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_PctStdDev")
     }
 
     /// Relative Weighting factor for the active LOAD
     pub fn Get_RelWeight(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_RelWeight(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_RelWeight")?;
         Ok(result)
     }
 
     pub fn Set_RelWeight(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_RelWeight(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_RelWeight")
     }
 
     /// Neutral resistance for wye-connected loads.
     pub fn Get_Rneut(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Rneut(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Rneut")?;
         Ok(result)
     }
 
     pub fn Set_Rneut(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_Rneut(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Rneut")
     }
 
     /// Name of harmonic current spectrrum shape.
     pub fn Get_Spectrum(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Loads_Get_Spectrum(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Spectrum")?;
         Ok(result)
     }
 
-    pub fn Set_Spectrum(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Spectrum(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILoads::Set_Spectrum")?;
         unsafe { dss_capi::ctx_Loads_Set_Spectrum(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Spectrum")
     }
 
     /// Response to load multipliers: Fixed (growth only), Exempt (no LD curve), Variable (all).
     pub fn Get_Status(&self) -> Result<LoadStatus, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Status(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ILoads::Get_Status")?;
+        result.try_into().origin("ILoads::Get_Status")
     }
 
     pub fn Set_Status(&self, value: LoadStatus) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_Status(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Status")
     }
 
     /// Maximum per-unit voltage to use the load model. Above this, constant Z applies.
     pub fn Get_Vmaxpu(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Vmaxpu(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Vmaxpu")?;
         Ok(result)
     }
 
     pub fn Set_Vmaxpu(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_Vmaxpu(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Vmaxpu")
     }
 
     /// Minimum voltage for unserved energy (UE) evaluation.
     pub fn Get_Vminemerg(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Vminemerg(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Vminemerg")?;
         Ok(result)
     }
 
     pub fn Set_Vminemerg(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_Vminemerg(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Vminemerg")
     }
 
     /// Minimum voltage for energy exceeding normal (EEN) evaluations.
     pub fn Get_Vminnorm(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Vminnorm(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Vminnorm")?;
         Ok(result)
     }
 
     pub fn Set_Vminnorm(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_Vminnorm(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Vminnorm")
     }
 
     /// Minimum voltage to apply the load model. Below this, constant Z is used.
     pub fn Get_Vminpu(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Vminpu(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Vminpu")?;
         Ok(result)
     }
 
     pub fn Set_Vminpu(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_Vminpu(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Vminpu")
     }
 
     /// Neutral reactance for wye-connected loads.
     pub fn Get_Xneut(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Xneut(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Xneut")?;
         Ok(result)
     }
 
     pub fn Set_Xneut(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_Xneut(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Xneut")
     }
 
     /// Name of yearly duration loadshape
     pub fn Get_Yearly(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Loads_Get_Yearly(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Yearly")?;
         Ok(result)
     }
 
-    pub fn Set_Yearly(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Yearly(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILoads::Set_Yearly")?;
         unsafe { dss_capi::ctx_Loads_Set_Yearly(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Yearly")
     }
 
     /// Array of 7 doubles with values for ZIPV property of the load object
     pub fn Get_ZIPV(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoads::Get_ZIPV")?;
+        unsafe { dss_capi::ctx_Loads_Get_ZIPV_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ILoads::Get_ZIPV")
+    }
+
+    pub fn Get_ZIPV_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoads::Get_ZIPV_view")?;
         unsafe { dss_capi::ctx_Loads_Get_ZIPV_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("ILoads::Get_ZIPV_view")
+    }
+
+    pub fn Get_ZIPV_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ILoads::Get_ZIPV_into")?;
+        unsafe { dss_capi::ctx_Loads_Get_ZIPV_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ILoads::Get_ZIPV_into")
     }
 
     pub fn Set_ZIPV(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_ZIPV(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_ZIPV")
     }
 
     /// Name of the loadshape for a daily load profile.
     pub fn Get_daily(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Loads_Get_daily(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_daily")?;
         Ok(result)
     }
 
-    pub fn Set_daily(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_daily(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILoads::Set_daily")?;
         unsafe { dss_capi::ctx_Loads_Set_daily(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_daily")
     }
 
     /// Name of the loadshape for a duty cycle simulation.
     pub fn Get_duty(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Loads_Get_duty(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_duty")?;
         Ok(result)
     }
 
-    pub fn Set_duty(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_duty(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ILoads::Set_duty")?;
         unsafe { dss_capi::ctx_Loads_Set_duty(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_duty")
     }
 
     /// Set kV rating for active Load. For 2 or more phases set Line-Line kV. Else actual kV across terminals.
     pub fn Get_kV(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_kV(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_kV")?;
         Ok(result)
     }
 
     pub fn Set_kV(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_kV(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_kV")
     }
 
     /// Set kW for active Load. Updates kvar based on present PF.
     pub fn Get_kW(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_kW(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_kW")?;
         Ok(result)
     }
 
     pub fn Set_kW(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_kW(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_kW")
     }
 
     /// Base load kva. Also defined kw and kvar or pf input, or load allocation by kwh or xfkva.
     pub fn Get_kva(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_kva(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_kva")?;
         Ok(result)
     }
 
     pub fn Set_kva(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_kva(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_kva")
     }
 
     /// Get/set kvar for active Load. If set, updates PF based on present kW.
     pub fn Get_kvar(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_kvar(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_kvar")?;
         Ok(result)
     }
 
     pub fn Set_kvar(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_kvar(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_kvar")
     }
 
     /// kwh billed for this period. Can be used with Cfactor for load allocation.
     pub fn Get_kwh(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_kwh(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_kwh")?;
         Ok(result)
     }
 
     pub fn Set_kwh(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_kwh(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_kwh")
     }
 
     /// Length of kwh billing period for average demand calculation. Default 30.
     pub fn Get_kwhdays(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_kwhdays(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_kwhdays")?;
         Ok(result)
     }
 
     pub fn Set_kwhdays(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_kwhdays(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_kwhdays")
     }
 
     /// Percent of Load that is modeled as series R-L for harmonics studies
     pub fn Get_pctSeriesRL(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_pctSeriesRL(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_pctSeriesRL")?;
         Ok(result)
     }
 
     pub fn Set_pctSeriesRL(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_pctSeriesRL(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_pctSeriesRL")
     }
 
     /// Rated service transformer kVA for load allocation, using AllocationFactor. Affects kW, kvar, and pf.
     pub fn Get_xfkVA(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_xfkVA(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_xfkVA")?;
         Ok(result)
     }
 
     pub fn Set_xfkVA(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_xfkVA(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_xfkVA")
     }
 
     /// Name of the sensor monitoring this load.
     pub fn Sensor(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Loads_Get_Sensor(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Sensor")?;
         Ok(result)
     }
 
@@ -6162,13 +7342,13 @@ impl<'a> ILoads<'a> {
     /// (API Extension)
     pub fn Get_Phases(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Loads_Get_Phases(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ILoads::Get_Phases")?;
         Ok(result)
     }
 
     pub fn Set_Phases(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Loads_Set_Phases(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ILoads::Set_Phases")
     }
 }
 
@@ -6193,7 +7373,7 @@ impl<'a> IMeters<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Meters_Get_ZonePCE(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IMeters::ZonePCE")
     }
 
     /// Array of strings with all Meter names in the circuit.
@@ -6201,114 +7381,114 @@ impl<'a> IMeters<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Meters_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IMeters::AllNames")
     }
 
     /// Number of Meter objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::Count")?;
         Ok(result)
     }
 
     /// Sets the first Meter active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::First")?;
         Ok(result)
     }
 
     /// Sets the active Meter by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Meters_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Meter.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IMeters::Set_Name")?;
         unsafe { dss_capi::ctx_Meters_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::Set_Name")
     }
 
     /// Sets the next Meter active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Meter; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Meter by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::Set_idx")
     }
 
     /// Close All Demand Interval Files. Users are required to close the DI files at the end of a run.
     pub fn CloseAllDIFiles(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_CloseAllDIFiles(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::CloseAllDIFiles")
     }
 
     /// Calculate reliability indices
     pub fn DoReliabilityCalc(&self, AssumeRestoration: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_DoReliabilityCalc(self.ctx_ptr, bool_to_u16(AssumeRestoration)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::DoReliabilityCalc")
     }
 
     /// Open Demand Interval (DI) files
     pub fn OpenAllDIFiles(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_OpenAllDIFiles(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::OpenAllDIFiles")
     }
 
     /// Resets registers of active meter.
     pub fn Reset(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_Reset(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::Reset")
     }
 
     /// Resets registers of all meter objects.
     pub fn ResetAll(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_ResetAll(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::ResetAll")
     }
 
     /// Forces active Meter to take a sample.
     pub fn Sample(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_Sample(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::Sample")
     }
 
     /// Causes all EnergyMeter objects to take a sample at the present time.
     pub fn SampleAll(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_SampleAll(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::SampleAll")
     }
 
     /// Saves meter register values.
     pub fn Save(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_Save(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::Save")
     }
 
     /// Save All EnergyMeter objects
     pub fn SaveAll(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_SaveAll(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::SaveAll")
     }
 
     pub fn SetActiveSection(&self, SectIdx: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_SetActiveSection(self.ctx_ptr, SectIdx) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::SetActiveSection")
     }
 
     /// Wide string list of all branches in zone of the active EnergyMeter object.
@@ -6316,7 +7496,7 @@ impl<'a> IMeters<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Meters_Get_AllBranchesInZone(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IMeters::AllBranchesInZone")
     }
 
     /// Array of names of all zone end elements.
@@ -6324,135 +7504,174 @@ impl<'a> IMeters<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Meters_Get_AllEndElements(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IMeters::AllEndElements")
     }
 
     /// Array of doubles: set the phase allocation factors for the active meter.
     pub fn Get_AllocFactors(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Get_AllocFactors")?;
+        unsafe { dss_capi::ctx_Meters_Get_AllocFactors_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IMeters::Get_AllocFactors")
+    }
+
+    pub fn Get_AllocFactors_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Get_AllocFactors_view")?;
+        unsafe { dss_capi::ctx_Meters_Get_AllocFactors_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IMeters::Get_AllocFactors_view")
+    }
+
+    pub fn Get_AllocFactors_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Get_AllocFactors_into")?;
         unsafe { dss_capi::ctx_Meters_Get_AllocFactors_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IMeters::Get_AllocFactors_into")
     }
 
     pub fn Set_AllocFactors(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_Set_AllocFactors(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::Set_AllocFactors")
     }
 
     /// Average Repair time in this section of the meter zone
     pub fn AvgRepairTime(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_AvgRepairTime(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::AvgRepairTime")?;
         Ok(result)
     }
 
     /// Set the magnitude of the real part of the Calculated Current (normally determined by solution) for the Meter to force some behavior on Load Allocation
     pub fn Get_CalcCurrent(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Get_CalcCurrent")?;
+        unsafe { dss_capi::ctx_Meters_Get_CalcCurrent_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IMeters::Get_CalcCurrent")
+    }
+
+    pub fn Get_CalcCurrent_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Get_CalcCurrent_view")?;
         unsafe { dss_capi::ctx_Meters_Get_CalcCurrent_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("IMeters::Get_CalcCurrent_view")
+    }
+
+    pub fn Get_CalcCurrent_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Get_CalcCurrent_into")?;
+        unsafe { dss_capi::ctx_Meters_Get_CalcCurrent_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IMeters::Get_CalcCurrent_into")
     }
 
     pub fn Set_CalcCurrent(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_Set_CalcCurrent(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::Set_CalcCurrent")
     }
 
     /// Number of branches in Active energymeter zone. (Same as sequencelist size)
     pub fn CountBranches(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_CountBranches(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::CountBranches")?;
         Ok(result)
     }
 
     /// Number of zone end elements in the active meter zone.
     pub fn CountEndElements(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_CountEndElements(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::CountEndElements")?;
         Ok(result)
     }
 
     /// Total customer interruptions for this Meter zone based on reliability calcs.
     pub fn CustInterrupts(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_CustInterrupts(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::CustInterrupts")?;
         Ok(result)
     }
 
     /// Global Flag in the DSS to indicate if Demand Interval (DI) files have been properly opened.
     pub fn DIFilesAreOpen(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Meters_Get_DIFilesAreOpen(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::DIFilesAreOpen")?;
         Ok(result)
     }
 
     /// Sum of Fault Rate time Repair Hrs in this section of the meter zone
     pub fn FaultRateXRepairHrs(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_FaultRateXRepairHrs(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::FaultRateXRepairHrs")?;
         Ok(result)
     }
 
     /// Set Name of metered element
     pub fn Get_MeteredElement(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Meters_Get_MeteredElement(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::Get_MeteredElement")?;
         Ok(result)
     }
 
-    pub fn Set_MeteredElement(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_MeteredElement(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IMeters::Set_MeteredElement")?;
         unsafe { dss_capi::ctx_Meters_Set_MeteredElement(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::Set_MeteredElement")
     }
 
     /// set Number of Metered Terminal
     pub fn Get_MeteredTerminal(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_MeteredTerminal(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::Get_MeteredTerminal")?;
         Ok(result)
     }
 
     pub fn Set_MeteredTerminal(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_Set_MeteredTerminal(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::Set_MeteredTerminal")
     }
 
     /// Number of branches (lines) in this section
     pub fn NumSectionBranches(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_NumSectionBranches(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::NumSectionBranches")?;
         Ok(result)
     }
 
     /// Number of Customers in the active section.
     pub fn NumSectionCustomers(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_NumSectionCustomers(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::NumSectionCustomers")?;
         Ok(result)
     }
 
     /// Number of feeder sections in this meter's zone
     pub fn NumSections(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_NumSections(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::NumSections")?;
         Ok(result)
     }
 
     /// Type of OCP device. 1=Fuse; 2=Recloser; 3=Relay
     pub fn OCPDeviceType(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_OCPDeviceType(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::OCPDeviceType")?;
         Ok(result)
     }
 
     /// Array of doubles to set values of Peak Current property
     pub fn Get_Peakcurrent(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Get_Peakcurrent")?;
+        unsafe { dss_capi::ctx_Meters_Get_Peakcurrent_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IMeters::Get_Peakcurrent")
+    }
+
+    pub fn Get_Peakcurrent_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Get_Peakcurrent_view")?;
+        unsafe { dss_capi::ctx_Meters_Get_Peakcurrent_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IMeters::Get_Peakcurrent_view")
+    }
+
+    pub fn Get_Peakcurrent_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Get_Peakcurrent_into")?;
         unsafe { dss_capi::ctx_Meters_Get_Peakcurrent_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IMeters::Get_Peakcurrent_into")
     }
 
     pub fn Set_Peakcurrent(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_Set_Peakcurrent(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::Set_Peakcurrent")
     }
 
     /// Array of strings containing the names of the registers.
@@ -6460,87 +7679,113 @@ impl<'a> IMeters<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Meters_Get_RegisterNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IMeters::RegisterNames")
     }
 
     /// Array of all the values contained in the Meter registers for the active Meter.
     pub fn RegisterValues(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::RegisterValues")?;
+        unsafe { dss_capi::ctx_Meters_Get_RegisterValues_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IMeters::RegisterValues")
+    }
+
+    pub fn RegisterValues_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::RegisterValues_view")?;
         unsafe { dss_capi::ctx_Meters_Get_RegisterValues_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("IMeters::RegisterValues_view")
+    }
+
+    pub fn RegisterValues_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::RegisterValues_into")?;
+        unsafe { dss_capi::ctx_Meters_Get_RegisterValues_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IMeters::RegisterValues_into")
     }
 
     /// SAIDI for this meter's zone. Execute DoReliabilityCalc first.
     pub fn SAIDI(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_SAIDI(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::SAIDI")?;
         Ok(result)
     }
 
     /// Returns SAIFI for this meter's Zone. Execute Reliability Calc method first.
     pub fn SAIFI(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_SAIFI(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::SAIFI")?;
         Ok(result)
     }
 
     /// SAIFI based on kW rather than number of customers. Get after reliability calcs.
     pub fn SAIFIKW(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_SAIFIKW(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::SAIFIKW")?;
         Ok(result)
     }
 
     /// SequenceIndex of the branch at the head of this section
     pub fn SectSeqIdx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_SectSeqIdx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::SectSeqIdx")?;
         Ok(result)
     }
 
     /// Total Customers downline from this section
     pub fn SectTotalCust(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_SectTotalCust(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::SectTotalCust")?;
         Ok(result)
     }
 
     /// Size of Sequence List
     pub fn SeqListSize(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_SeqListSize(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::SeqListSize")?;
         Ok(result)
     }
 
     /// Get/set Index into Meter's SequenceList that contains branch pointers in lexical order. Earlier index guaranteed to be upline from later index. Sets PDelement active.
     pub fn Get_SequenceIndex(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_SequenceIndex(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::Get_SequenceIndex")?;
         Ok(result)
     }
 
     pub fn Set_SequenceIndex(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Meters_Set_SequenceIndex(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IMeters::Set_SequenceIndex")
     }
 
     /// Sum of the branch fault rates in this section of the meter's zone
     pub fn SumBranchFltRates(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_SumBranchFltRates(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::SumBranchFltRates")?;
         Ok(result)
     }
 
     /// Total Number of customers in this zone (downline from the EnergyMeter)
     pub fn TotalCustomers(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Meters_Get_TotalCustomers(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IMeters::TotalCustomers")?;
         Ok(result)
     }
 
     /// Totals of all registers of all meters
     pub fn Totals(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Totals")?;
+        unsafe { dss_capi::ctx_Meters_Get_Totals_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IMeters::Totals")
+    }
+
+    pub fn Totals_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Totals_view")?;
+        unsafe { dss_capi::ctx_Meters_Get_Totals_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IMeters::Totals_view")
+    }
+
+    pub fn Totals_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IMeters::Totals_into")?;
         unsafe { dss_capi::ctx_Meters_Get_Totals_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IMeters::Totals_into")
     }
 
 }
@@ -6563,14 +7808,14 @@ impl<'a> IPDElements<'a> {
     /// accummulated failure rate for this branch on downline
     pub fn AccumulatedL(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_AccumulatedL(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::AccumulatedL")?;
         Ok(result)
     }
 
     /// Number of PD elements (including disabled elements)
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::Count")?;
         Ok(result)
     }
 
@@ -6578,20 +7823,20 @@ impl<'a> IPDElements<'a> {
     /// For LINE elements: Number of failures per unit length per year.
     pub fn Get_FaultRate(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_FaultRate(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::Get_FaultRate")?;
         Ok(result)
     }
 
     pub fn Set_FaultRate(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_PDElements_Set_FaultRate(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPDElements::Set_FaultRate")
     }
 
     /// Set the first enabled PD element to be the active element.
     /// Returns 0 if none found.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::First")?;
         Ok(result)
     }
 
@@ -6599,7 +7844,7 @@ impl<'a> IPDElements<'a> {
     /// side. This is set after the meter zone is determined.
     pub fn FromTerminal(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_FromTerminal(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::FromTerminal")?;
         Ok(result)
     }
 
@@ -6608,14 +7853,14 @@ impl<'a> IPDElements<'a> {
     /// elements in particular.
     pub fn IsShunt(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_PDElements_Get_IsShunt(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::IsShunt")?;
         Ok(result)
     }
 
     /// Failure rate for this branch. Faults per year including length of line.
     pub fn Lambda(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_Lambda(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::Lambda")?;
         Ok(result)
     }
 
@@ -6623,28 +7868,28 @@ impl<'a> IPDElements<'a> {
     /// is not PDElement type.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_PDElements_Get_Name(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::Get_Name")?;
         Ok(result)
     }
 
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IPDElements::Set_Name")?;
         unsafe { dss_capi::ctx_PDElements_Set_Name(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPDElements::Set_Name")
     }
 
     /// Advance to the next PD element in the circuit. Enabled elements
     /// only. Returns 0 when no more elements.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::Next")?;
         Ok(result)
     }
 
     /// Number of customers, this branch
     pub fn Numcustomers(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_Numcustomers(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::Numcustomers")?;
         Ok(result)
     }
 
@@ -6652,53 +7897,53 @@ impl<'a> IPDElements<'a> {
     /// Returns 0 if no more elements upline.
     pub fn ParentPDElement(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_ParentPDElement(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::ParentPDElement")?;
         Ok(result)
     }
 
     /// Average repair time for this element in hours
     pub fn Get_RepairTime(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_RepairTime(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::Get_RepairTime")?;
         Ok(result)
     }
 
     pub fn Set_RepairTime(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_PDElements_Set_RepairTime(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPDElements::Set_RepairTime")
     }
 
     /// Integer ID of the feeder section that this PDElement branch is part of
     pub fn SectionID(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_SectionID(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::SectionID")?;
         Ok(result)
     }
 
     /// Total miles of line from this element to the end of the zone. For recloser siting algorithm.
     pub fn TotalMiles(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_TotalMiles(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::TotalMiles")?;
         Ok(result)
     }
 
     /// Total number of customers from this branch to the end of the zone
     pub fn Totalcustomers(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_Totalcustomers(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::Totalcustomers")?;
         Ok(result)
     }
 
     /// Get/Set percent of faults that are permanent (require repair). Otherwise, fault is assumed to be transient/temporary.
     pub fn Get_pctPermanent(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PDElements_Get_pctPermanent(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPDElements::Get_pctPermanent")?;
         Ok(result)
     }
 
     pub fn Set_pctPermanent(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_PDElements_Set_pctPermanent(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPDElements::Set_pctPermanent")
     }
 
     /// Array of strings consisting of all PD element names.
@@ -6708,7 +7953,7 @@ impl<'a> IPDElements<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_PDElements_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IPDElements::AllNames")
     }
     /// Array of doubles with the maximum current across the conductors, for each PD
     /// element.
@@ -6722,8 +7967,21 @@ impl<'a> IPDElements<'a> {
     ///
     /// (API Extension)
     pub fn AllMaxCurrents(&self, AllNodes: bool) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllMaxCurrents")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllMaxCurrents_GR(self.ctx_ptr, bool_to_u16(AllNodes)) };
+        self.ctx.GetFloat64ArrayGR().origin("IPDElements::AllMaxCurrents")
+    }
+
+    pub fn AllMaxCurrents_view(&self, AllNodes: bool) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllMaxCurrents_view")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllMaxCurrents_GR(self.ctx_ptr, bool_to_u16(AllNodes)) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("IPDElements::AllMaxCurrents_view")
+    }
+
+    pub fn AllMaxCurrents_into(&self, AllNodes: bool, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllMaxCurrents_into")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllMaxCurrents_GR(self.ctx_ptr, bool_to_u16(AllNodes)) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IPDElements::AllMaxCurrents_into")
     }
 
     /// Array of doubles with the maximum current across the conductors as a percentage
@@ -6738,8 +7996,21 @@ impl<'a> IPDElements<'a> {
     ///
     /// (API Extension)
     pub fn AllPctNorm(&self, AllNodes: bool) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllPctNorm")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllPctNorm_GR(self.ctx_ptr, bool_to_u16(AllNodes)) };
+        self.ctx.GetFloat64ArrayGR().origin("IPDElements::AllPctNorm")
+    }
+
+    pub fn AllPctNorm_view(&self, AllNodes: bool) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllPctNorm_view")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllPctNorm_GR(self.ctx_ptr, bool_to_u16(AllNodes)) };
+        self.ctx.GetFloat64ArrayGRView().origin("IPDElements::AllPctNorm_view")
+    }
+
+    pub fn AllPctNorm_into(&self, AllNodes: bool, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllPctNorm_into")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllPctNorm_GR(self.ctx_ptr, bool_to_u16(AllNodes)) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IPDElements::AllPctNorm_into")
     }
 
     /// Array of doubles with the maximum current across the conductors as a percentage
@@ -6754,80 +8025,210 @@ impl<'a> IPDElements<'a> {
     ///
     /// (API Extension)
     pub fn AllPctEmerg(&self, AllNodes: bool) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllPctEmerg")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllPctEmerg_GR(self.ctx_ptr, bool_to_u16(AllNodes)) };
+        self.ctx.GetFloat64ArrayGR().origin("IPDElements::AllPctEmerg")
+    }
+
+    pub fn AllPctEmerg_view(&self, AllNodes: bool) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllPctEmerg_view")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllPctEmerg_GR(self.ctx_ptr, bool_to_u16(AllNodes)) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("IPDElements::AllPctEmerg_view")
+    }
+
+    pub fn AllPctEmerg_into(&self, AllNodes: bool, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllPctEmerg_into")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllPctEmerg_GR(self.ctx_ptr, bool_to_u16(AllNodes)) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IPDElements::AllPctEmerg_into")
     }
 
     /// Complex array of currents for all conductors, all terminals, for each PD element.
     ///
     /// (API Extension)
     pub fn AllCurrents(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllCurrents")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("IPDElements::AllCurrents")
+    }
+
+    pub fn AllCurrents_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllCurrents_view")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("IPDElements::AllCurrents_view")
+    }
+
+    pub fn AllCurrents_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllCurrents_into")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllCurrents_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("IPDElements::AllCurrents_into")
     }
 
     /// Complex array (magnitude and angle format) of currents for all conductors, all terminals, for each PD element.
     ///
     /// (API Extension)
     pub fn AllCurrentsMagAng(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllCurrentsMagAng")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllCurrentsMagAng_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IPDElements::AllCurrentsMagAng")
+    }
+
+    pub fn AllCurrentsMagAng_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllCurrentsMagAng_view")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllCurrentsMagAng_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("IPDElements::AllCurrentsMagAng_view")
+    }
+
+    pub fn AllCurrentsMagAng_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllCurrentsMagAng_into")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllCurrentsMagAng_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IPDElements::AllCurrentsMagAng_into")
     }
 
     /// Complex double array of Sequence Currents for all conductors of all terminals, for each PD elements.
     ///
     /// (API Extension)
     pub fn AllCplxSeqCurrents(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllCplxSeqCurrents")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllCplxSeqCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("IPDElements::AllCplxSeqCurrents")
+    }
+
+    pub fn AllCplxSeqCurrents_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllCplxSeqCurrents_view")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllCplxSeqCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("IPDElements::AllCplxSeqCurrents_view")
+    }
+
+    pub fn AllCplxSeqCurrents_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllCplxSeqCurrents_into")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllCplxSeqCurrents_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("IPDElements::AllCplxSeqCurrents_into")
     }
 
     /// Double array of the symmetrical component currents (magnitudes only) into each 3-phase terminal, for each PD element.
     ///
     /// (API Extension)
     pub fn AllSeqCurrents(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllSeqCurrents")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllSeqCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IPDElements::AllSeqCurrents")
+    }
+
+    pub fn AllSeqCurrents_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllSeqCurrents_view")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllSeqCurrents_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("IPDElements::AllSeqCurrents_view")
+    }
+
+    pub fn AllSeqCurrents_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllSeqCurrents_into")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllSeqCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IPDElements::AllSeqCurrents_into")
     }
 
     /// Complex array of powers into each conductor of each terminal, for each PD element.
     ///
     /// (API Extension)
     pub fn AllPowers(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllPowers")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllPowers_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("IPDElements::AllPowers")
+    }
+
+    pub fn AllPowers_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllPowers_view")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllPowers_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("IPDElements::AllPowers_view")
+    }
+
+    pub fn AllPowers_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllPowers_into")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllPowers_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("IPDElements::AllPowers_into")
     }
 
     /// Complex array of sequence powers into each 3-phase teminal, for each PD element
     ///
     /// (API Extension)
     pub fn AllSeqPowers(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllSeqPowers")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllSeqPowers_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("IPDElements::AllSeqPowers")
+    }
+
+    pub fn AllSeqPowers_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllSeqPowers_view")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllSeqPowers_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRView().origin("IPDElements::AllSeqPowers_view")
+    }
+
+    pub fn AllSeqPowers_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllSeqPowers_into")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllSeqPowers_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("IPDElements::AllSeqPowers_into")
     }
 
     /// Integer array listing the number of phases of all PD elements
     ///
     /// (API Extension)
     pub fn AllNumPhases(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllNumPhases")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllNumPhases_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGR().origin("IPDElements::AllNumPhases")
+    }
+
+    pub fn AllNumPhases_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllNumPhases_view")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllNumPhases_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRView().origin("IPDElements::AllNumPhases_view")
+    }
+
+    pub fn AllNumPhases_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllNumPhases_into")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllNumPhases_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGRInto(out).origin("IPDElements::AllNumPhases_into")
     }
 
     /// Integer array listing the number of conductors of all PD elements
     ///
     /// (API Extension)
     pub fn AllNumConductors(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllNumConductors")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllNumConductors_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGR().origin("IPDElements::AllNumConductors")
+    }
+
+    pub fn AllNumConductors_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllNumConductors_view")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllNumConductors_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGRView().origin("IPDElements::AllNumConductors_view")
+    }
+
+    pub fn AllNumConductors_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllNumConductors_into")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllNumConductors_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRInto(out).origin("IPDElements::AllNumConductors_into")
     }
 
     /// Integer array listing the number of terminals of all PD elements
     ///
     /// (API Extension)
     pub fn AllNumTerminals(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllNumTerminals")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllNumTerminals_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGR().origin("IPDElements::AllNumTerminals")
+    }
+
+    pub fn AllNumTerminals_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllNumTerminals_view")?;
+        unsafe { dss_capi::ctx_PDElements_Get_AllNumTerminals_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRView().origin("IPDElements::AllNumTerminals_view")
+    }
+
+    pub fn AllNumTerminals_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPDElements::AllNumTerminals_into")?;
         unsafe { dss_capi::ctx_PDElements_Get_AllNumTerminals_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGRInto(out).origin("IPDElements::AllNumTerminals_into")
     }
 }
 
@@ -6851,79 +8252,79 @@ impl<'a> IPVSystems<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_PVSystems_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IPVSystems::AllNames")
     }
 
     /// Number of PVSystem objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PVSystems_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Count")?;
         Ok(result)
     }
 
     /// Sets the first PVSystem active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PVSystems_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::First")?;
         Ok(result)
     }
 
     /// Sets the active PVSystem by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_PVSystems_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active PVSystem.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IPVSystems::Set_Name")?;
         unsafe { dss_capi::ctx_PVSystems_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_Name")
     }
 
     /// Sets the next PVSystem active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PVSystems_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active PVSystem; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_PVSystems_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active PVSystem by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_PVSystems_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_idx")
     }
 
     /// Get/set the present value of the Irradiance property in kW/m²
     pub fn Get_Irradiance(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PVSystems_Get_Irradiance(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_Irradiance")?;
         Ok(result)
     }
 
     pub fn Set_Irradiance(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_PVSystems_Set_Irradiance(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_Irradiance")
     }
 
     /// Get/set the power factor for the active PVSystem
     pub fn Get_PF(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PVSystems_Get_PF(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_PF")?;
         Ok(result)
     }
 
     pub fn Set_PF(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_PVSystems_Set_PF(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_PF")
     }
 
     /// Array of PVSYSTEM energy meter register names
@@ -6931,44 +8332,57 @@ impl<'a> IPVSystems<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_PVSystems_Get_RegisterNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IPVSystems::RegisterNames")
     }
 
     /// Array of doubles containing values in PVSystem registers.
     pub fn RegisterValues(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPVSystems::RegisterValues")?;
+        unsafe { dss_capi::ctx_PVSystems_Get_RegisterValues_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IPVSystems::RegisterValues")
+    }
+
+    pub fn RegisterValues_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPVSystems::RegisterValues_view")?;
         unsafe { dss_capi::ctx_PVSystems_Get_RegisterValues_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("IPVSystems::RegisterValues_view")
+    }
+
+    pub fn RegisterValues_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IPVSystems::RegisterValues_into")?;
+        unsafe { dss_capi::ctx_PVSystems_Get_RegisterValues_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IPVSystems::RegisterValues_into")
     }
 
     /// Get/set Rated kVA of the PVSystem
     pub fn Get_kVArated(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PVSystems_Get_kVArated(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_kVArated")?;
         Ok(result)
     }
 
     pub fn Set_kVArated(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_PVSystems_Set_kVArated(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_kVArated")
     }
 
     /// Get kW output
     pub fn kW(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PVSystems_Get_kW(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::kW")?;
         Ok(result)
     }
 
     /// Get/set kvar output value
     pub fn Get_kvar(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PVSystems_Get_kvar(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_kvar")?;
         Ok(result)
     }
 
     pub fn Set_kvar(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_PVSystems_Set_kvar(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_kvar")
     }
 
     /// Name of the dispatch shape to use for daily simulations. Must be previously
@@ -6978,14 +8392,14 @@ impl<'a> IPVSystems<'a> {
     /// (API Extension)
     pub fn Get_daily(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_PVSystems_Get_daily(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_daily")?;
         Ok(result)
     }
 
-    pub fn Set_daily(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_daily(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IPVSystems::Set_daily")?;
         unsafe { dss_capi::ctx_PVSystems_Set_daily(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_daily")
     }
 
     /// Name of the load shape to use for duty cycle dispatch simulations such as
@@ -6995,14 +8409,14 @@ impl<'a> IPVSystems<'a> {
     /// (API Extension)
     pub fn Get_duty(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_PVSystems_Get_duty(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_duty")?;
         Ok(result)
     }
 
-    pub fn Set_duty(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_duty(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IPVSystems::Set_duty")?;
         unsafe { dss_capi::ctx_PVSystems_Set_duty(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_duty")
     }
 
     /// Dispatch shape to use for yearly simulations. Must be previously defined
@@ -7013,14 +8427,14 @@ impl<'a> IPVSystems<'a> {
     /// (API Extension)
     pub fn Get_yearly(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_PVSystems_Get_yearly(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_yearly")?;
         Ok(result)
     }
 
-    pub fn Set_yearly(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_yearly(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IPVSystems::Set_yearly")?;
         unsafe { dss_capi::ctx_PVSystems_Set_yearly(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_yearly")
     }
 
     /// Temperature shape to use for daily simulations. Must be previously defined
@@ -7031,14 +8445,14 @@ impl<'a> IPVSystems<'a> {
     /// (API Extension)
     pub fn Get_Tdaily(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_PVSystems_Get_Tdaily(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_Tdaily")?;
         Ok(result)
     }
 
-    pub fn Set_Tdaily(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Tdaily(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IPVSystems::Set_Tdaily")?;
         unsafe { dss_capi::ctx_PVSystems_Set_Tdaily(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_Tdaily")
     }
 
     /// Temperature shape to use for duty cycle dispatch simulations such as for
@@ -7052,14 +8466,14 @@ impl<'a> IPVSystems<'a> {
     /// (API Extension)
     pub fn Get_Tduty(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_PVSystems_Get_Tduty(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_Tduty")?;
         Ok(result)
     }
 
-    pub fn Set_Tduty(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Tduty(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IPVSystems::Set_Tduty")?;
         unsafe { dss_capi::ctx_PVSystems_Set_Tduty(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_Tduty")
     }
 
     /// Temperature shape to use for yearly simulations. Must be previously defined
@@ -7071,21 +8485,21 @@ impl<'a> IPVSystems<'a> {
     /// (API Extension)
     pub fn Get_Tyearly(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_PVSystems_Get_Tyearly(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_Tyearly")?;
         Ok(result)
     }
 
-    pub fn Set_Tyearly(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Tyearly(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IPVSystems::Set_Tyearly")?;
         unsafe { dss_capi::ctx_PVSystems_Set_Tyearly(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_Tyearly")
     }
 
     /// Returns the current irradiance value for the active PVSystem. Use it to
     /// know what's the current irradiance value for the PV during a simulation.
     pub fn IrradianceNow(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PVSystems_Get_IrradianceNow(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::IrradianceNow")?;
         Ok(result)
     }
 
@@ -7093,19 +8507,19 @@ impl<'a> IPVSystems<'a> {
     /// and a user-selected array temperature of the active PVSystem.
     pub fn Get_Pmpp(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_PVSystems_Get_Pmpp(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Get_Pmpp")?;
         Ok(result)
     }
 
     pub fn Set_Pmpp(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_PVSystems_Set_Pmpp(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IPVSystems::Set_Pmpp")
     }
 
     /// Name of the sensor monitoring this element.
     pub fn Sensor(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_PVSystems_Get_Sensor(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IPVSystems::Sensor")?;
         Ok(result)
     }
 }
@@ -7130,135 +8544,135 @@ impl<'a> IReactors<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Reactors_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IReactors::AllNames")
     }
 
     /// Number of Reactor objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Count")?;
         Ok(result)
     }
 
     /// Sets the first Reactor active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::First")?;
         Ok(result)
     }
 
     /// Sets the active Reactor by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Reactors_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Reactor.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IReactors::Set_Name")?;
         unsafe { dss_capi::ctx_Reactors_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Name")
     }
 
     /// Sets the next Reactor active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Reactor; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Reactor by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_idx")
     }
 
     /// How the reactor data was provided: 1=kvar, 2=R+jX, 3=R and X matrices, 4=sym components.
     /// Depending on this value, only some properties are filled or make sense in the context.
     pub fn SpecType(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_SpecType(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::SpecType")?;
         Ok(result)
     }
 
     /// Delta connection or wye?
     pub fn Get_IsDelta(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Reactors_Get_IsDelta(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_IsDelta")?;
         Ok(result)
     }
 
     pub fn Set_IsDelta(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_IsDelta(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_IsDelta")
     }
 
     /// Indicates whether Rmatrix and Xmatrix are to be considered in parallel.
     pub fn Get_Parallel(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Reactors_Get_Parallel(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_Parallel")?;
         Ok(result)
     }
 
     pub fn Set_Parallel(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_Parallel(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Parallel")
     }
 
     /// Inductance, mH. Alternate way to define the reactance, X, property.
     pub fn Get_LmH(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_LmH(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_LmH")?;
         Ok(result)
     }
 
     pub fn Set_LmH(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_LmH(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_LmH")
     }
 
     /// For 2, 3-phase, kV phase-phase. Otherwise specify actual coil rating.
     pub fn Get_kV(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_kV(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_kV")?;
         Ok(result)
     }
 
     pub fn Set_kV(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_kV(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_kV")
     }
 
     /// Total kvar, all phases.  Evenly divided among phases. Only determines X. Specify R separately
     pub fn Get_kvar(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_kvar(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_kvar")?;
         Ok(result)
     }
 
     pub fn Set_kvar(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_kvar(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_kvar")
     }
 
     /// Number of phases.
     pub fn Get_Phases(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_Phases(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_Phases")?;
         Ok(result)
     }
 
     pub fn Set_Phases(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_Phases(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Phases")
     }
 
     /// Name of first bus.
@@ -7266,123 +8680,150 @@ impl<'a> IReactors<'a> {
     /// Only Bus1 need be specified for a Yg shunt reactor.
     pub fn Get_Bus1(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Reactors_Get_Bus1(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_Bus1")?;
         Ok(result)
     }
 
-    pub fn Set_Bus1(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Bus1(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IReactors::Set_Bus1")?;
         unsafe { dss_capi::ctx_Reactors_Set_Bus1(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Bus1")
     }
 
     /// Name of 2nd bus. Defaults to all phases connected to first bus, node 0, (Shunt Wye Connection) except when Bus2 is specifically defined.
     /// Not necessary to specify for delta (LL) connection
     pub fn Get_Bus2(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Reactors_Get_Bus2(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_Bus2")?;
         Ok(result)
     }
 
-    pub fn Set_Bus2(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Bus2(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IReactors::Set_Bus2")?;
         unsafe { dss_capi::ctx_Reactors_Set_Bus2(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Bus2")
     }
 
     /// Name of XYCurve object, previously defined, describing per-unit variation of phase inductance, L=X/w, vs. frequency. Applies to reactance specified by X, LmH, Z, or kvar property. L generally decreases somewhat with frequency above the base frequency, approaching a limit at a few kHz.
     pub fn Get_LCurve(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Reactors_Get_LCurve(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_LCurve")?;
         Ok(result)
     }
 
-    pub fn Set_LCurve(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_LCurve(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IReactors::Set_LCurve")?;
         unsafe { dss_capi::ctx_Reactors_Set_LCurve(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_LCurve")
     }
 
     /// Name of XYCurve object, previously defined, describing per-unit variation of phase resistance, R, vs. frequency. Applies to resistance specified by R or Z property. If actual values are not known, R often increases by approximately the square root of frequency.
     pub fn Get_RCurve(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Reactors_Get_RCurve(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_RCurve")?;
         Ok(result)
     }
 
-    pub fn Set_RCurve(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_RCurve(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IReactors::Set_RCurve")?;
         unsafe { dss_capi::ctx_Reactors_Set_RCurve(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_RCurve")
     }
 
     /// Resistance (in series with reactance), each phase, ohms. This property applies to REACTOR specified by either kvar or X. See also help on Z.
     pub fn Get_R(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_R(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_R")?;
         Ok(result)
     }
 
     pub fn Set_R(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_R(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_R")
     }
 
     /// Reactance, each phase, ohms at base frequency. See also help on Z and LmH properties.
     pub fn Get_X(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_X(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_X")?;
         Ok(result)
     }
 
     pub fn Set_X(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_X(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_X")
     }
 
     /// Resistance in parallel with R and X (the entire branch). Assumed infinite if not specified.
     pub fn Get_Rp(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Reactors_Get_Rp(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReactors::Get_Rp")?;
         Ok(result)
     }
 
     pub fn Set_Rp(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_Rp(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Rp")
     }
 
     /// Resistance matrix, ohms at base frequency. Order of the matrix is the number of phases. Mutually exclusive to specifying parameters by kvar or X.
     pub fn Get_Rmatrix(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReactors::Get_Rmatrix")?;
+        unsafe { dss_capi::ctx_Reactors_Get_Rmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IReactors::Get_Rmatrix")
+    }
+
+    pub fn Get_Rmatrix_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReactors::Get_Rmatrix_view")?;
+        unsafe { dss_capi::ctx_Reactors_Get_Rmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IReactors::Get_Rmatrix_view")
+    }
+
+    pub fn Get_Rmatrix_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReactors::Get_Rmatrix_into")?;
         unsafe { dss_capi::ctx_Reactors_Get_Rmatrix_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IReactors::Get_Rmatrix_into")
     }
 
     pub fn Set_Rmatrix(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_Rmatrix(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Rmatrix")
     }
 
     /// Reactance matrix, ohms at base frequency. Order of the matrix is the number of phases. Mutually exclusive to specifying parameters by kvar or X.
     pub fn Get_Xmatrix(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReactors::Get_Xmatrix")?;
+        unsafe { dss_capi::ctx_Reactors_Get_Xmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IReactors::Get_Xmatrix")
+    }
+
+    pub fn Get_Xmatrix_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReactors::Get_Xmatrix_view")?;
         unsafe { dss_capi::ctx_Reactors_Get_Xmatrix_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("IReactors::Get_Xmatrix_view")
+    }
+
+    pub fn Get_Xmatrix_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReactors::Get_Xmatrix_into")?;
+        unsafe { dss_capi::ctx_Reactors_Get_Xmatrix_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IReactors::Get_Xmatrix_into")
     }
 
     pub fn Set_Xmatrix(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_Xmatrix(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Xmatrix")
     }
 
     /// Alternative way of defining R and X properties. Enter a 2-element array representing R +jX in ohms.
     pub fn Get_Z(&self) -> Result<Complex<f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReactors::Get_Z")?;
         unsafe { dss_capi::ctx_Reactors_Get_Z_GR(self.ctx_ptr) };
-        self.ctx.GetComplexSimpleGR()
+        self.ctx.GetComplexSimpleGR().origin("IReactors::Get_Z")
     }
 
     pub fn Set_Z(&self, value: Complex<f64>) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_Z(self.ctx_ptr, &value.re, 2) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Z")
     }
 
     /// Positive-sequence impedance, ohms, as a 2-element array representing a complex number.
@@ -7393,13 +8834,14 @@ impl<'a> IReactors<'a> {
     ///
     /// Side Effect: Sets Z2 and Z0 to same values unless they were previously defined.
     pub fn Get_Z1(&self) -> Result<Complex<f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReactors::Get_Z1")?;
         unsafe { dss_capi::ctx_Reactors_Get_Z1_GR(self.ctx_ptr) };
-        self.ctx.GetComplexSimpleGR()
+        self.ctx.GetComplexSimpleGR().origin("IReactors::Get_Z1")
     }
 
     pub fn Set_Z1(&self, value: Complex<f64>) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_Z1(self.ctx_ptr, &value.re, 2) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Z1")
     }
 
     /// Negative-sequence impedance, ohms, as a 2-element array representing a complex number.
@@ -7408,13 +8850,14 @@ impl<'a> IReactors<'a> {
     ///
     /// Note: Z2 defaults to Z1 if it is not specifically defined. If Z2 is not equal to Z1, the impedance matrix is asymmetrical.
     pub fn Get_Z2(&self) -> Result<Complex<f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReactors::Get_Z2")?;
         unsafe { dss_capi::ctx_Reactors_Get_Z2_GR(self.ctx_ptr) };
-        self.ctx.GetComplexSimpleGR()
+        self.ctx.GetComplexSimpleGR().origin("IReactors::Get_Z2")
     }
 
     pub fn Set_Z2(&self, value: Complex<f64>) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_Z2(self.ctx_ptr, &value.re, 2) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Z2")
     }
 
     /// Zero-sequence impedance, ohms, as a 2-element array representing a complex number.
@@ -7423,13 +8866,14 @@ impl<'a> IReactors<'a> {
     ///
     /// Note: Z0 defaults to Z1 if it is not specifically defined.
     pub fn Get_Z0(&self) -> Result<Complex<f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReactors::Get_Z0")?;
         unsafe { dss_capi::ctx_Reactors_Get_Z0_GR(self.ctx_ptr) };
-        self.ctx.GetComplexSimpleGR()
+        self.ctx.GetComplexSimpleGR().origin("IReactors::Get_Z0")
     }
 
     pub fn Set_Z0(&self, value: Complex<f64>) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reactors_Set_Z0(self.ctx_ptr, &value.re, 2) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReactors::Set_Z0")
     }
 }
 
@@ -7453,193 +8897,206 @@ impl<'a> IReclosers<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Reclosers_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IReclosers::AllNames")
     }
 
     /// Number of Recloser objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Count")?;
         Ok(result)
     }
 
     /// Sets the first Recloser active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::First")?;
         Ok(result)
     }
 
     /// Sets the active Recloser by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Reclosers_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Recloser.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IReclosers::Set_Name")?;
         unsafe { dss_capi::ctx_Reclosers_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_Name")
     }
 
     /// Sets the next Recloser active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Recloser; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Recloser by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_idx")
     }
 
     pub fn Close(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Close(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Close")
     }
 
     pub fn Open(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Open(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Open")
     }
 
     /// Ground (3I0) instantaneous trip setting - curve multipler or actual amps.
     pub fn Get_GroundInst(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_GroundInst(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_GroundInst")?;
         Ok(result)
     }
 
     pub fn Set_GroundInst(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Set_GroundInst(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_GroundInst")
     }
 
     /// Ground (3I0) trip multiplier or actual amps
     pub fn Get_GroundTrip(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_GroundTrip(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_GroundTrip")?;
         Ok(result)
     }
 
     pub fn Set_GroundTrip(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Set_GroundTrip(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_GroundTrip")
     }
 
     /// Full name of object this Recloser to be monitored.
     pub fn Get_MonitoredObj(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Reclosers_Get_MonitoredObj(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_MonitoredObj")?;
         Ok(result)
     }
 
-    pub fn Set_MonitoredObj(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_MonitoredObj(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IReclosers::Set_MonitoredObj")?;
         unsafe { dss_capi::ctx_Reclosers_Set_MonitoredObj(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_MonitoredObj")
     }
 
     /// Terminal number of Monitored object for the Recloser
     pub fn Get_MonitoredTerm(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_MonitoredTerm(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_MonitoredTerm")?;
         Ok(result)
     }
 
     pub fn Set_MonitoredTerm(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Set_MonitoredTerm(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_MonitoredTerm")
     }
 
     /// Number of fast shots
     pub fn Get_NumFast(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_NumFast(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_NumFast")?;
         Ok(result)
     }
 
     pub fn Set_NumFast(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Set_NumFast(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_NumFast")
     }
 
     /// Phase instantaneous curve multipler or actual amps
     pub fn Get_PhaseInst(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_PhaseInst(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_PhaseInst")?;
         Ok(result)
     }
 
     pub fn Set_PhaseInst(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Set_PhaseInst(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_PhaseInst")
     }
 
     /// Phase trip curve multiplier or actual amps
     pub fn Get_PhaseTrip(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_PhaseTrip(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_PhaseTrip")?;
         Ok(result)
     }
 
     pub fn Set_PhaseTrip(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Set_PhaseTrip(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_PhaseTrip")
     }
 
     /// Array of Doubles: reclose intervals, s, between shots.
     pub fn RecloseIntervals(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReclosers::RecloseIntervals")?;
+        unsafe { dss_capi::ctx_Reclosers_Get_RecloseIntervals_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IReclosers::RecloseIntervals")
+    }
+
+    pub fn RecloseIntervals_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReclosers::RecloseIntervals_view")?;
+        unsafe { dss_capi::ctx_Reclosers_Get_RecloseIntervals_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IReclosers::RecloseIntervals_view")
+    }
+
+    pub fn RecloseIntervals_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IReclosers::RecloseIntervals_into")?;
         unsafe { dss_capi::ctx_Reclosers_Get_RecloseIntervals_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IReclosers::RecloseIntervals_into")
     }
 
     /// Number of shots to lockout (fast + delayed)
     pub fn Get_Shots(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_Shots(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_Shots")?;
         Ok(result)
     }
 
     pub fn Set_Shots(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Set_Shots(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_Shots")
     }
 
     /// Full name of the circuit element that is being switched by the Recloser.
     pub fn Get_SwitchedObj(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Reclosers_Get_SwitchedObj(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_SwitchedObj")?;
         Ok(result)
     }
 
-    pub fn Set_SwitchedObj(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_SwitchedObj(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IReclosers::Set_SwitchedObj")?;
         unsafe { dss_capi::ctx_Reclosers_Set_SwitchedObj(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_SwitchedObj")
     }
 
     /// Terminal number of the controlled device being switched by the Recloser
     pub fn Get_SwitchedTerm(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_SwitchedTerm(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_SwitchedTerm")?;
         Ok(result)
     }
 
     pub fn Set_SwitchedTerm(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Set_SwitchedTerm(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_SwitchedTerm")
     }
 
     /// Reset recloser to normal state.
@@ -7647,7 +9104,7 @@ impl<'a> IReclosers<'a> {
     /// If closed, resets recloser to first operation.
     pub fn Reset(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Reset(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Reset")
     }
 
     /// Get/Set present state of recloser.
@@ -7655,25 +9112,25 @@ impl<'a> IReclosers<'a> {
     /// If set to close (ActionCodes.Close=2), close recloser's controlled element and resets recloser to first operation.
     pub fn Get_State(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_State(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_State")?;
         Ok(result)
     }
 
     pub fn Set_State(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Set_State(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_State")
     }
 
     /// Get/set normal state (ActionCodes.Open=1, ActionCodes.Close=2) of the recloser.
     pub fn Get_NormalState(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Reclosers_Get_NormalState(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IReclosers::Get_NormalState")?;
         Ok(result)
     }
 
     pub fn Set_NormalState(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Reclosers_Set_NormalState(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IReclosers::Set_NormalState")
     }
 }
 
@@ -7697,314 +9154,314 @@ impl<'a> IRegControls<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_RegControls_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IRegControls::AllNames")
     }
 
     /// Number of RegControl objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Count")?;
         Ok(result)
     }
 
     /// Sets the first RegControl active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::First")?;
         Ok(result)
     }
 
     /// Sets the active RegControl by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_RegControls_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active RegControl.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IRegControls::Set_Name")?;
         unsafe { dss_capi::ctx_RegControls_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_Name")
     }
 
     /// Sets the next RegControl active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active RegControl; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active RegControl by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_idx")
     }
 
     pub fn Reset(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Reset(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Reset")
     }
 
     /// CT primary ampere rating (secondary is 0.2 amperes)
     pub fn Get_CTPrimary(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_CTPrimary(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_CTPrimary")?;
         Ok(result)
     }
 
     pub fn Set_CTPrimary(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_CTPrimary(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_CTPrimary")
     }
 
     /// Time delay [s] after arming before the first tap change. Control may reset before actually changing taps.
     pub fn Get_Delay(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_Delay(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_Delay")?;
         Ok(result)
     }
 
     pub fn Set_Delay(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_Delay(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_Delay")
     }
 
     /// Regulation bandwidth in forward direciton, centered on Vreg
     pub fn Get_ForwardBand(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_ForwardBand(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_ForwardBand")?;
         Ok(result)
     }
 
     pub fn Set_ForwardBand(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_ForwardBand(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_ForwardBand")
     }
 
     /// LDC R setting in Volts
     pub fn Get_ForwardR(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_ForwardR(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_ForwardR")?;
         Ok(result)
     }
 
     pub fn Set_ForwardR(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_ForwardR(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_ForwardR")
     }
 
     /// Target voltage in the forward direction, on PT secondary base.
     pub fn Get_ForwardVreg(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_ForwardVreg(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_ForwardVreg")?;
         Ok(result)
     }
 
     pub fn Set_ForwardVreg(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_ForwardVreg(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_ForwardVreg")
     }
 
     /// LDC X setting in Volts
     pub fn Get_ForwardX(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_ForwardX(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_ForwardX")?;
         Ok(result)
     }
 
     pub fn Set_ForwardX(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_ForwardX(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_ForwardX")
     }
 
     /// Time delay is inversely adjsuted, proportinal to the amount of voltage outside the regulating band.
     pub fn Get_IsInverseTime(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_RegControls_Get_IsInverseTime(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_IsInverseTime")?;
         Ok(result)
     }
 
     pub fn Set_IsInverseTime(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_IsInverseTime(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_IsInverseTime")
     }
 
     /// Regulator can use different settings in the reverse direction.  Usually not applicable to substation transformers.
     pub fn Get_IsReversible(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_RegControls_Get_IsReversible(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_IsReversible")?;
         Ok(result)
     }
 
     pub fn Set_IsReversible(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_IsReversible(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_IsReversible")
     }
 
     /// Maximum tap change per iteration in STATIC solution mode. 1 is more realistic, 16 is the default for a faster soluiton.
     pub fn Get_MaxTapChange(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_MaxTapChange(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_MaxTapChange")?;
         Ok(result)
     }
 
     pub fn Set_MaxTapChange(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_MaxTapChange(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_MaxTapChange")
     }
 
     /// Name of a remote regulated bus, in lieu of LDC settings
     pub fn Get_MonitoredBus(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_RegControls_Get_MonitoredBus(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_MonitoredBus")?;
         Ok(result)
     }
 
-    pub fn Set_MonitoredBus(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_MonitoredBus(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IRegControls::Set_MonitoredBus")?;
         unsafe { dss_capi::ctx_RegControls_Set_MonitoredBus(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_MonitoredBus")
     }
 
     /// PT ratio for voltage control settings
     pub fn Get_PTratio(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_PTratio(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_PTratio")?;
         Ok(result)
     }
 
     pub fn Set_PTratio(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_PTratio(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_PTratio")
     }
 
     /// Bandwidth in reverse direction, centered on reverse Vreg.
     pub fn Get_ReverseBand(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_ReverseBand(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_ReverseBand")?;
         Ok(result)
     }
 
     pub fn Set_ReverseBand(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_ReverseBand(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_ReverseBand")
     }
 
     /// Reverse LDC R setting in Volts.
     pub fn Get_ReverseR(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_ReverseR(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_ReverseR")?;
         Ok(result)
     }
 
     pub fn Set_ReverseR(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_ReverseR(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_ReverseR")
     }
 
     /// Target voltage in the revese direction, on PT secondary base.
     pub fn Get_ReverseVreg(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_ReverseVreg(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_ReverseVreg")?;
         Ok(result)
     }
 
     pub fn Set_ReverseVreg(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_ReverseVreg(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_ReverseVreg")
     }
 
     /// Reverse LDC X setting in volts.
     pub fn Get_ReverseX(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_ReverseX(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_ReverseX")?;
         Ok(result)
     }
 
     pub fn Set_ReverseX(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_ReverseX(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_ReverseX")
     }
 
     /// Time delay [s] for subsequent tap changes in a set. Control may reset before actually changing taps.
     pub fn Get_TapDelay(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_TapDelay(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_TapDelay")?;
         Ok(result)
     }
 
     pub fn Set_TapDelay(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_TapDelay(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_TapDelay")
     }
 
     /// Integer number of the tap that the controlled transformer winding is currentliy on.
     pub fn Get_TapNumber(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_TapNumber(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_TapNumber")?;
         Ok(result)
     }
 
     pub fn Set_TapNumber(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_TapNumber(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_TapNumber")
     }
 
     /// Tapped winding number
     pub fn Get_TapWinding(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_TapWinding(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_TapWinding")?;
         Ok(result)
     }
 
     pub fn Set_TapWinding(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_TapWinding(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_TapWinding")
     }
 
     /// Name of the transformer this regulator controls
     pub fn Get_Transformer(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_RegControls_Get_Transformer(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_Transformer")?;
         Ok(result)
     }
 
-    pub fn Set_Transformer(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Transformer(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IRegControls::Set_Transformer")?;
         unsafe { dss_capi::ctx_RegControls_Set_Transformer(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_Transformer")
     }
 
     /// First house voltage limit on PT secondary base.  Setting to 0 disables this function.
     pub fn Get_VoltageLimit(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_VoltageLimit(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_VoltageLimit")?;
         Ok(result)
     }
 
     pub fn Set_VoltageLimit(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_VoltageLimit(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_VoltageLimit")
     }
 
     /// Winding number for PT and CT connections
     pub fn Get_Winding(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_RegControls_Get_Winding(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRegControls::Get_Winding")?;
         Ok(result)
     }
 
     pub fn Set_Winding(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_RegControls_Set_Winding(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRegControls::Set_Winding")
     }
 }
 
@@ -8028,117 +9485,117 @@ impl<'a> IRelays<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Relays_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IRelays::AllNames")
     }
 
     /// Number of Relay objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Relays_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRelays::Count")?;
         Ok(result)
     }
 
     /// Sets the first Relay active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Relays_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRelays::First")?;
         Ok(result)
     }
 
     /// Sets the active Relay by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Relays_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRelays::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Relay.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IRelays::Set_Name")?;
         unsafe { dss_capi::ctx_Relays_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRelays::Set_Name")
     }
 
     /// Sets the next Relay active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Relays_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRelays::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Relay; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Relays_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRelays::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Relay by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Relays_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRelays::Set_idx")
     }
 
     /// Full name of object this Relay is monitoring.
     pub fn Get_MonitoredObj(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Relays_Get_MonitoredObj(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRelays::Get_MonitoredObj")?;
         Ok(result)
     }
 
-    pub fn Set_MonitoredObj(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_MonitoredObj(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IRelays::Set_MonitoredObj")?;
         unsafe { dss_capi::ctx_Relays_Set_MonitoredObj(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRelays::Set_MonitoredObj")
     }
 
     /// Number of terminal of monitored element that this Relay is monitoring.
     pub fn Get_MonitoredTerm(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Relays_Get_MonitoredTerm(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRelays::Get_MonitoredTerm")?;
         Ok(result)
     }
 
     pub fn Set_MonitoredTerm(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Relays_Set_MonitoredTerm(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRelays::Set_MonitoredTerm")
     }
 
     /// Full name of element that will be switched when relay trips.
     pub fn Get_SwitchedObj(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Relays_Get_SwitchedObj(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRelays::Get_SwitchedObj")?;
         Ok(result)
     }
 
-    pub fn Set_SwitchedObj(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_SwitchedObj(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IRelays::Set_SwitchedObj")?;
         unsafe { dss_capi::ctx_Relays_Set_SwitchedObj(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRelays::Set_SwitchedObj")
     }
 
     /// Terminal number of the switched object that will be opened when the relay trips.
     pub fn Get_SwitchedTerm(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Relays_Get_SwitchedTerm(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRelays::Get_SwitchedTerm")?;
         Ok(result)
     }
 
     pub fn Set_SwitchedTerm(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Relays_Set_SwitchedTerm(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRelays::Set_SwitchedTerm")
     }
 
     /// Open relay's controlled element and lock out the relay.
     pub fn Open(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Relays_Open(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRelays::Open")
     }
 
     /// Close the switched object controlled by the relay. Resets relay to first operation.
     pub fn Close(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Relays_Close(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRelays::Close")
     }
 
     /// Reset relay to normal state.
@@ -8146,7 +9603,7 @@ impl<'a> IRelays<'a> {
     /// If closed, resets relay to first operation.
     pub fn Reset(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Relays_Reset(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRelays::Reset")
     }
 
     /// Get/Set present state of relay.
@@ -8154,25 +9611,25 @@ impl<'a> IRelays<'a> {
     /// If set to close, close relay's controlled element and resets relay to first operation.
     pub fn Get_State(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Relays_Get_State(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRelays::Get_State")?;
         Ok(result)
     }
 
     pub fn Set_State(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Relays_Set_State(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRelays::Set_State")
     }
 
     /// Normal state of relay.
     pub fn Get_NormalState(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Relays_Get_NormalState(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IRelays::Get_NormalState")?;
         Ok(result)
     }
 
     pub fn Set_NormalState(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Relays_Set_NormalState(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IRelays::Set_NormalState")
     }
 }
 
@@ -8196,200 +9653,265 @@ impl<'a> ISensors<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Sensors_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ISensors::AllNames")
     }
 
     /// Number of Sensor objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Sensors_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::Count")?;
         Ok(result)
     }
 
     /// Sets the first Sensor active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Sensors_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::First")?;
         Ok(result)
     }
 
     /// Sets the active Sensor by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Sensors_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Sensor.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ISensors::Set_Name")?;
         unsafe { dss_capi::ctx_Sensors_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_Name")
     }
 
     /// Sets the next Sensor active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Sensors_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Sensor; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Sensors_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Sensor by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_idx")
     }
 
     pub fn Reset(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Reset(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Reset")
     }
 
     pub fn ResetAll(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_ResetAll(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::ResetAll")
     }
 
     /// Array of doubles for the line current measurements; don't use with kWS and kVARS.
     pub fn Get_Currents(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_Currents")?;
+        unsafe { dss_capi::ctx_Sensors_Get_Currents_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ISensors::Get_Currents")
+    }
+
+    pub fn Get_Currents_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_Currents_view")?;
         unsafe { dss_capi::ctx_Sensors_Get_Currents_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("ISensors::Get_Currents_view")
+    }
+
+    pub fn Get_Currents_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_Currents_into")?;
+        unsafe { dss_capi::ctx_Sensors_Get_Currents_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ISensors::Get_Currents_into")
     }
 
     pub fn Set_Currents(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Set_Currents(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_Currents")
     }
 
     /// True if measured voltages are line-line. Currents are always line currents.
     pub fn Get_IsDelta(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Sensors_Get_IsDelta(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::Get_IsDelta")?;
         Ok(result)
     }
 
     pub fn Set_IsDelta(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Set_IsDelta(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_IsDelta")
     }
 
     /// Full Name of the measured element
     pub fn Get_MeteredElement(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Sensors_Get_MeteredElement(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::Get_MeteredElement")?;
         Ok(result)
     }
 
-    pub fn Set_MeteredElement(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_MeteredElement(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ISensors::Set_MeteredElement")?;
         unsafe { dss_capi::ctx_Sensors_Set_MeteredElement(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_MeteredElement")
     }
 
     /// Number of the measured terminal in the measured element.
     pub fn Get_MeteredTerminal(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Sensors_Get_MeteredTerminal(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::Get_MeteredTerminal")?;
         Ok(result)
     }
 
     pub fn Set_MeteredTerminal(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Set_MeteredTerminal(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_MeteredTerminal")
     }
 
     /// Assumed percent error in the Sensor measurement. Default is 1.
     pub fn Get_PctError(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Sensors_Get_PctError(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::Get_PctError")?;
         Ok(result)
     }
 
     pub fn Set_PctError(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Set_PctError(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_PctError")
     }
 
     /// True if voltage measurements are 1-3, 3-2, 2-1.
     pub fn Get_ReverseDelta(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Sensors_Get_ReverseDelta(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::Get_ReverseDelta")?;
         Ok(result)
     }
 
     pub fn Set_ReverseDelta(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Set_ReverseDelta(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_ReverseDelta")
     }
 
     /// Weighting factor for this Sensor measurement with respect to other Sensors. Default is 1.
     pub fn Get_Weight(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Sensors_Get_Weight(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::Get_Weight")?;
         Ok(result)
     }
 
     pub fn Set_Weight(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Set_Weight(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_Weight")
     }
 
     /// Array of doubles for Q measurements. Overwrites Currents with a new estimate using kWS.
     pub fn Get_kVARS(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_kVARS")?;
+        unsafe { dss_capi::ctx_Sensors_Get_kVARS_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ISensors::Get_kVARS")
+    }
+
+    pub fn Get_kVARS_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_kVARS_view")?;
+        unsafe { dss_capi::ctx_Sensors_Get_kVARS_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ISensors::Get_kVARS_view")
+    }
+
+    pub fn Get_kVARS_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_kVARS_into")?;
         unsafe { dss_capi::ctx_Sensors_Get_kVARS_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ISensors::Get_kVARS_into")
     }
 
     pub fn Set_kVARS(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Set_kVARS(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_kVARS")
     }
 
     /// Array of doubles for the LL or LN (depending on Delta connection) voltage measurements.
     pub fn Get_kVS(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_kVS")?;
+        unsafe { dss_capi::ctx_Sensors_Get_kVS_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ISensors::Get_kVS")
+    }
+
+    pub fn Get_kVS_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_kVS_view")?;
         unsafe { dss_capi::ctx_Sensors_Get_kVS_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("ISensors::Get_kVS_view")
+    }
+
+    pub fn Get_kVS_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_kVS_into")?;
+        unsafe { dss_capi::ctx_Sensors_Get_kVS_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ISensors::Get_kVS_into")
     }
 
     pub fn Set_kVS(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Set_kVS(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_kVS")
     }
 
     /// Voltage base for the sensor measurements. LL for 2 and 3-phase sensors, LN for 1-phase sensors.
     pub fn Get_kVbase(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Sensors_Get_kVbase(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISensors::Get_kVbase")?;
         Ok(result)
     }
 
     pub fn Set_kVbase(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Set_kVbase(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_kVbase")
     }
 
     /// Array of doubles for P measurements. Overwrites Currents with a new estimate using kVARS.
     pub fn Get_kWS(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_kWS")?;
+        unsafe { dss_capi::ctx_Sensors_Get_kWS_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ISensors::Get_kWS")
+    }
+
+    pub fn Get_kWS_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_kWS_view")?;
+        unsafe { dss_capi::ctx_Sensors_Get_kWS_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("ISensors::Get_kWS_view")
+    }
+
+    pub fn Get_kWS_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::Get_kWS_into")?;
         unsafe { dss_capi::ctx_Sensors_Get_kWS_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ISensors::Get_kWS_into")
     }
 
     pub fn Set_kWS(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Sensors_Set_kWS(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISensors::Set_kWS")
     }
 
     /// Array of doubles for the allocation factors for each phase.
     pub fn AllocationFactor(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::AllocationFactor")?;
+        unsafe { dss_capi::ctx_Sensors_Get_AllocationFactor_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("ISensors::AllocationFactor")
+    }
+
+    pub fn AllocationFactor_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::AllocationFactor_view")?;
         unsafe { dss_capi::ctx_Sensors_Get_AllocationFactor_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRView().origin("ISensors::AllocationFactor_view")
+    }
+
+    pub fn AllocationFactor_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ISensors::AllocationFactor_into")?;
+        unsafe { dss_capi::ctx_Sensors_Get_AllocationFactor_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRInto(out).origin("ISensors::AllocationFactor_into")
     }
 }
 
@@ -8413,145 +9935,145 @@ impl<'a> ISwtControls<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_SwtControls_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ISwtControls::AllNames")
     }
 
     /// Number of SwtControl objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_SwtControls_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISwtControls::Count")?;
         Ok(result)
     }
 
     /// Sets the first SwtControl active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_SwtControls_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISwtControls::First")?;
         Ok(result)
     }
 
     /// Sets the active SwtControl by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_SwtControls_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISwtControls::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active SwtControl.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ISwtControls::Set_Name")?;
         unsafe { dss_capi::ctx_SwtControls_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISwtControls::Set_Name")
     }
 
     /// Sets the next SwtControl active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_SwtControls_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISwtControls::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active SwtControl; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_SwtControls_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISwtControls::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active SwtControl by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_SwtControls_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISwtControls::Set_idx")
     }
 
     pub fn Reset(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_SwtControls_Reset(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISwtControls::Reset")
     }
 
     /// Open or Close the switch. No effect if switch is locked.  However, Reset removes any lock and then closes the switch (shelf state).
     pub fn Get_Action(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_SwtControls_Get_Action(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISwtControls::Get_Action")?;
         Ok(result)
     }
 
     pub fn Set_Action(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_SwtControls_Set_Action(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISwtControls::Set_Action")
     }
 
     /// Time delay [s] betwen arming and opening or closing the switch.  Control may reset before actually operating the switch.
     pub fn Get_Delay(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_SwtControls_Get_Delay(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISwtControls::Get_Delay")?;
         Ok(result)
     }
 
     pub fn Set_Delay(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_SwtControls_Set_Delay(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISwtControls::Set_Delay")
     }
 
     /// The lock prevents both manual and automatic switch operation.
     pub fn Get_IsLocked(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_SwtControls_Get_IsLocked(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISwtControls::Get_IsLocked")?;
         Ok(result)
     }
 
     pub fn Set_IsLocked(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_SwtControls_Set_IsLocked(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISwtControls::Set_IsLocked")
     }
 
     /// Get/set Normal state of switch (see actioncodes) dssActionOpen or dssActionClose
     pub fn Get_NormalState(&self) -> Result<ActionCodes, DSSError> {
         let result = unsafe { dss_capi::ctx_SwtControls_Get_NormalState(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ISwtControls::Get_NormalState")?;
+        result.try_into().origin("ISwtControls::Get_NormalState")
     }
 
     pub fn Set_NormalState(&self, value: ActionCodes) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_SwtControls_Set_NormalState(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISwtControls::Set_NormalState")
     }
 
     /// Set it to force the switch to a specified state, otherwise read its present state.
     pub fn Get_State(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_SwtControls_Get_State(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISwtControls::Get_State")?;
         Ok(result)
     }
 
     pub fn Set_State(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_SwtControls_Set_State(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISwtControls::Set_State")
     }
 
     /// Full name of the switched element.
     pub fn Get_SwitchedObj(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_SwtControls_Get_SwitchedObj(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISwtControls::Get_SwitchedObj")?;
         Ok(result)
     }
 
-    pub fn Set_SwitchedObj(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_SwitchedObj(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ISwtControls::Set_SwitchedObj")?;
         unsafe { dss_capi::ctx_SwtControls_Set_SwitchedObj(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISwtControls::Set_SwitchedObj")
     }
 
     /// Terminal number where the switch is located on the SwitchedObj
     pub fn Get_SwitchedTerm(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_SwtControls_Get_SwitchedTerm(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ISwtControls::Get_SwitchedTerm")?;
         Ok(result)
     }
 
     pub fn Set_SwitchedTerm(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_SwtControls_Set_SwitchedTerm(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ISwtControls::Set_SwitchedTerm")
     }
 }
 
@@ -8575,244 +10097,244 @@ impl<'a> ITSData<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_TSData_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ITSData::AllNames")
     }
 
     /// Number of TSData objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Count")?;
         Ok(result)
     }
 
     /// Sets the first TSData active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::First")?;
         Ok(result)
     }
 
     /// Sets the active TSData by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_TSData_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active TSData.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ITSData::Set_Name")?;
         unsafe { dss_capi::ctx_TSData_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_Name")
     }
 
     /// Sets the next TSData active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active TSData; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active TSData by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_idx")
     }
 
     /// Emergency ampere rating
     pub fn Get_EmergAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_EmergAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_EmergAmps")?;
         Ok(result)
     }
 
     pub fn Set_EmergAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_EmergAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_EmergAmps")
     }
 
     /// Normal Ampere rating
     pub fn Get_NormAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_NormAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_NormAmps")?;
         Ok(result)
     }
 
     pub fn Set_NormAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_NormAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_NormAmps")
     }
 
     pub fn Get_Rdc(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_Rdc(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_Rdc")?;
         Ok(result)
     }
 
     pub fn Set_Rdc(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_Rdc(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_Rdc")
     }
 
     pub fn Get_Rac(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_Rac(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_Rac")?;
         Ok(result)
     }
 
     pub fn Set_Rac(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_Rac(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_Rac")
     }
 
     pub fn Get_GMRac(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_GMRac(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_GMRac")?;
         Ok(result)
     }
 
     pub fn Set_GMRac(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_GMRac(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_GMRac")
     }
 
     pub fn Get_GMRUnits(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_GMRUnits(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_GMRUnits")?;
         Ok(result)
     }
 
     pub fn Set_GMRUnits(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_GMRUnits(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_GMRUnits")
     }
 
     pub fn Get_Radius(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_Radius(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_Radius")?;
         Ok(result)
     }
 
     pub fn Set_Radius(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_Radius(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_Radius")
     }
 
     pub fn Get_RadiusUnits(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_RadiusUnits(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_RadiusUnits")?;
         Ok(result)
     }
 
     pub fn Set_RadiusUnits(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_RadiusUnits(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_RadiusUnits")
     }
 
     pub fn Get_ResistanceUnits(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_ResistanceUnits(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_ResistanceUnits")?;
         Ok(result)
     }
 
     pub fn Set_ResistanceUnits(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_ResistanceUnits(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_ResistanceUnits")
     }
 
     pub fn Get_Diameter(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_Diameter(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_Diameter")?;
         Ok(result)
     }
 
     pub fn Set_Diameter(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_Diameter(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_Diameter")
     }
 
     pub fn Get_EpsR(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_EpsR(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_EpsR")?;
         Ok(result)
     }
 
     pub fn Set_EpsR(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_EpsR(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_EpsR")
     }
 
     pub fn Get_InsLayer(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_InsLayer(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_InsLayer")?;
         Ok(result)
     }
 
     pub fn Set_InsLayer(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_InsLayer(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_InsLayer")
     }
 
     pub fn Get_DiaIns(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_DiaIns(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_DiaIns")?;
         Ok(result)
     }
 
     pub fn Set_DiaIns(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_DiaIns(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_DiaIns")
     }
 
     pub fn Get_DiaCable(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_DiaCable(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_DiaCable")?;
         Ok(result)
     }
 
     pub fn Set_DiaCable(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_DiaCable(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_DiaCable")
     }
 
     pub fn Get_DiaShield(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_DiaShield(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_DiaShield")?;
         Ok(result)
     }
 
     pub fn Set_DiaShield(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_DiaShield(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_DiaShield")
     }
 
     pub fn Get_TapeLayer(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_TapeLayer(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_TapeLayer")?;
         Ok(result)
     }
 
     pub fn Set_TapeLayer(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_TapeLayer(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_TapeLayer")
     }
 
     pub fn Get_TapeLap(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_TSData_Get_TapeLap(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITSData::Get_TapeLap")?;
         Ok(result)
     }
 
     pub fn Set_TapeLap(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_TSData_Set_TapeLap(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITSData::Set_TapeLap")
     }
 }
 
@@ -8834,40 +10356,62 @@ impl<'a> IText<'a> {
     /// Runs a list of strings as commands directly in the DSS engine.
     /// Intermediate results are ignored.
     ///
+    /// The whole list is passed to the engine in a single call. On errors, the last
+    /// command run by the engine (see `Get_Command`) is recorded in the error; use
+    /// `CommandsEach` to run and check the commands one by one.
+    ///
     /// (API Extension)
-    pub fn Commands(&self, value: &[String]) -> Result<(), DSSError> {
-        let (_value_cstrs, value_c) = self.ctx.PrepareStringArray(value);
+    pub fn Commands(&self, value: &[impl AsRef<str>]) -> Result<(), DSSError> {
+        let (_value_cstrs, value_c) = self.ctx.PrepareStringArray(value).origin("IText::Commands")?;
         unsafe { dss_capi::ctx_Text_CommandArray(self.ctx_ptr, value_c.as_ptr() as *mut *const c_char, value.len() as i32); }
-        self.ctx.DSSError()
+        let Err(err) = self.ctx.DSSError() else {
+            return Ok(());
+        };
+        match self.Get_Command() {
+            Ok(command) if !command.is_empty() => Err(err).origin("IText::Commands").command(&command),
+            _ => Err(err).origin("IText::Commands"),
+        }
+    }
+
+    /// Runs a list of strings as commands, one engine call per command, stopping at
+    /// the first one that fails; that command is recorded in the error.
+    pub fn CommandsEach(&self, value: &[impl AsRef<str>]) -> Result<(), DSSError> {
+        // All the strings are checked before running any command
+        let value_c = value.iter().map(|command| self.ctx.PrepareString(command)).collect::<Result<Vec<_>, _>>().origin("IText::CommandsEach")?;
+        for (command, command_c) in value.iter().zip(value_c.iter()) {
+            unsafe { dss_capi::ctx_Text_Set_Command(self.ctx_ptr, command_c.as_ptr()) };
+            self.ctx.DSSError().origin("IText::CommandsEach").command(command.as_ref())?;
+        }
+        Ok(())
     }
 
     /// Runs a large string as commands directly in the DSS engine.
     /// Intermediate results are ignored.
     ///
     /// (API Extension)
-    pub fn CommandBlock(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn CommandBlock(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IText::CommandBlock")?;
         unsafe { dss_capi::ctx_Text_CommandBlock(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IText::CommandBlock").command(&value_c.to_string_lossy())
     }
 
     /// Input command string for the DSS.
     pub fn Get_Command(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Text_Get_Command(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IText::Get_Command")?;
         Ok(result)
     }
 
-    pub fn Set_Command(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Command(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IText::Set_Command")?;
         unsafe { dss_capi::ctx_Text_Set_Command(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IText::Set_Command").command(&value_c.to_string_lossy())
     }
 
     /// Result string for the last command.
     pub fn Result(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Text_Get_Result(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IText::Result")?;
         Ok(result)
     }
 }
@@ -8890,14 +10434,14 @@ impl<'a> ITopology<'a> {
     /// Returns index of the active branch
     pub fn ActiveBranch(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_ActiveBranch(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::ActiveBranch")?;
         Ok(result)
     }
 
     /// Topological depth of the active branch
     pub fn ActiveLevel(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_ActiveLevel(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::ActiveLevel")?;
         Ok(result)
     }
 
@@ -8906,7 +10450,7 @@ impl<'a> ITopology<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Topology_Get_AllIsolatedBranches(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ITopology::AllIsolatedBranches")
     }
 
     /// Array of all isolated load names.
@@ -8914,7 +10458,7 @@ impl<'a> ITopology<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Topology_Get_AllIsolatedLoads(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ITopology::AllIsolatedLoads")
     }
 
     /// Array of all looped element names, by pairs.
@@ -8922,109 +10466,109 @@ impl<'a> ITopology<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Topology_Get_AllLoopedPairs(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ITopology::AllLoopedPairs")
     }
 
     /// Move back toward the source, return index of new active branch, or 0 if no more.
     pub fn BackwardBranch(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_BackwardBranch(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::BackwardBranch")?;
         Ok(result)
     }
 
     /// Name of the active branch.
     pub fn Get_BranchName(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Topology_Get_BranchName(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::Get_BranchName")?;
         Ok(result)
     }
 
-    pub fn Set_BranchName(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_BranchName(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ITopology::Set_BranchName")?;
         unsafe { dss_capi::ctx_Topology_Set_BranchName(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITopology::Set_BranchName")
     }
 
     /// Set the active branch to one containing this bus, return index or 0 if not found
     pub fn Get_BusName(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Topology_Get_BusName(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::Get_BusName")?;
         Ok(result)
     }
 
-    pub fn Set_BusName(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_BusName(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ITopology::Set_BusName")?;
         unsafe { dss_capi::ctx_Topology_Set_BusName(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITopology::Set_BusName")
     }
 
     /// Sets the first branch active, returns 0 if none.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::First")?;
         Ok(result)
     }
 
     /// First load at the active branch, return index or 0 if none.
     pub fn FirstLoad(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_FirstLoad(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::FirstLoad")?;
         Ok(result)
     }
 
     /// Move forward in the tree, return index of new active branch or 0 if no more
     pub fn ForwardBranch(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_ForwardBranch(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::ForwardBranch")?;
         Ok(result)
     }
 
     /// Move to looped branch, return index or 0 if none.
     pub fn LoopedBranch(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_LoopedBranch(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::LoopedBranch")?;
         Ok(result)
     }
 
     /// Sets the next branch active, returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::Next")?;
         Ok(result)
     }
 
     /// Next load at the active branch, return index or 0 if no more.
     pub fn NextLoad(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_NextLoad(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::NextLoad")?;
         Ok(result)
     }
 
     /// Number of isolated branches (PD elements and capacitors).
     pub fn NumIsolatedBranches(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_NumIsolatedBranches(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::NumIsolatedBranches")?;
         Ok(result)
     }
 
     /// Number of isolated loads
     pub fn NumIsolatedLoads(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_NumIsolatedLoads(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::NumIsolatedLoads")?;
         Ok(result)
     }
 
     /// Number of loops
     pub fn NumLoops(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_NumLoops(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::NumLoops")?;
         Ok(result)
     }
 
     /// Move to directly parallel branch, return index or 0 if none.
     pub fn ParallelBranch(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Topology_Get_ParallelBranch(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITopology::ParallelBranch")?;
         Ok(result)
     }
 }
@@ -9049,248 +10593,248 @@ impl<'a> ITransformers<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Transformers_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("ITransformers::AllNames")
     }
 
     /// Number of Transformer objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Count")?;
         Ok(result)
     }
 
     /// Sets the first Transformer active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::First")?;
         Ok(result)
     }
 
     /// Sets the active Transformer by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Transformers_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Transformer.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ITransformers::Set_Name")?;
         unsafe { dss_capi::ctx_Transformers_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_Name")
     }
 
     /// Sets the next Transformer active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Transformer; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Transformer by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_idx")
     }
 
     /// Active Winding delta or wye connection?
     pub fn Get_IsDelta(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_Transformers_Get_IsDelta(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_IsDelta")?;
         Ok(result)
     }
 
     pub fn Set_IsDelta(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_IsDelta(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_IsDelta")
     }
 
     /// Active Winding maximum tap in per-unit.
     pub fn Get_MaxTap(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_MaxTap(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_MaxTap")?;
         Ok(result)
     }
 
     pub fn Set_MaxTap(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_MaxTap(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_MaxTap")
     }
 
     /// Active Winding minimum tap in per-unit.
     pub fn Get_MinTap(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_MinTap(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_MinTap")?;
         Ok(result)
     }
 
     pub fn Set_MinTap(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_MinTap(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_MinTap")
     }
 
     /// Active Winding number of tap steps betwein MinTap and MaxTap.
     pub fn Get_NumTaps(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_NumTaps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_NumTaps")?;
         Ok(result)
     }
 
     pub fn Set_NumTaps(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_NumTaps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_NumTaps")
     }
 
     /// Number of windings on this transformer. Allocates memory; set or change this property first.
     pub fn Get_NumWindings(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_NumWindings(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_NumWindings")?;
         Ok(result)
     }
 
     pub fn Set_NumWindings(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_NumWindings(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_NumWindings")
     }
 
     /// Active Winding resistance in %
     pub fn Get_R(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_R(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_R")?;
         Ok(result)
     }
 
     pub fn Set_R(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_R(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_R")
     }
 
     /// Active Winding neutral resistance [ohms] for wye connections. Set less than zero for ungrounded wye.
     pub fn Get_Rneut(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_Rneut(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_Rneut")?;
         Ok(result)
     }
 
     pub fn Set_Rneut(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_Rneut(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_Rneut")
     }
 
     /// Active Winding tap in per-unit.
     pub fn Get_Tap(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_Tap(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_Tap")?;
         Ok(result)
     }
 
     pub fn Set_Tap(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_Tap(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_Tap")
     }
 
     /// Active Winding Number from 1..NumWindings. Update this before reading or setting a sequence of winding properties (R, Tap, kV, kVA, etc.)
     pub fn Get_Wdg(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_Wdg(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_Wdg")?;
         Ok(result)
     }
 
     pub fn Set_Wdg(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_Wdg(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_Wdg")
     }
 
     /// Name of an XfrmCode that supplies electircal parameters for this Transformer.
     pub fn Get_XfmrCode(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Transformers_Get_XfmrCode(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_XfmrCode")?;
         Ok(result)
     }
 
-    pub fn Set_XfmrCode(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_XfmrCode(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("ITransformers::Set_XfmrCode")?;
         unsafe { dss_capi::ctx_Transformers_Set_XfmrCode(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_XfmrCode")
     }
 
     /// Percent reactance between windings 1 and 2, on winding 1 kVA base. Use for 2-winding or 3-winding transformers.
     pub fn Get_Xhl(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_Xhl(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_Xhl")?;
         Ok(result)
     }
 
     pub fn Set_Xhl(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_Xhl(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_Xhl")
     }
 
     /// Percent reactance between windigns 1 and 3, on winding 1 kVA base.  Use for 3-winding transformers only.
     pub fn Get_Xht(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_Xht(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_Xht")?;
         Ok(result)
     }
 
     pub fn Set_Xht(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_Xht(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_Xht")
     }
 
     /// Percent reactance between windings 2 and 3, on winding 1 kVA base. Use for 3-winding transformers only.
     pub fn Get_Xlt(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_Xlt(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_Xlt")?;
         Ok(result)
     }
 
     pub fn Set_Xlt(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_Xlt(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_Xlt")
     }
 
     /// Active Winding neutral reactance [ohms] for wye connections.
     pub fn Get_Xneut(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_Xneut(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_Xneut")?;
         Ok(result)
     }
 
     pub fn Set_Xneut(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_Xneut(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_Xneut")
     }
 
     /// Active Winding kV rating.  Phase-phase for 2 or 3 phases, actual winding kV for 1 phase transformer.
     pub fn Get_kV(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_kV(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_kV")?;
         Ok(result)
     }
 
     pub fn Set_kV(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_kV(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_kV")
     }
 
     /// Active Winding kVA rating. On winding 1, this also determines normal and emergency current ratings for all windings.
     pub fn Get_kVA(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_kVA(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_kVA")?;
         Ok(result)
     }
 
     pub fn Set_kVA(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_kVA(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_kVA")
     }
 
     /// Complex array of voltages for active winding
@@ -9298,8 +10842,21 @@ impl<'a> ITransformers<'a> {
     /// WARNING: If the transformer has open terminal(s), results may be wrong, i.e. avoid using this
     /// in those situations. For more information, see https://github.com/dss-extensions/dss-extensions/issues/24
     pub fn WdgVoltages(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::WdgVoltages")?;
+        unsafe { dss_capi::ctx_Transformers_Get_WdgVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ITransformers::WdgVoltages")
+    }
+
+    pub fn WdgVoltages_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::WdgVoltages_view")?;
+        unsafe { dss_capi::ctx_Transformers_Get_WdgVoltages_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ITransformers::WdgVoltages_view")
+    }
+
+    pub fn WdgVoltages_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::WdgVoltages_into")?;
         unsafe { dss_capi::ctx_Transformers_Get_WdgVoltages_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("ITransformers::WdgVoltages_into")
     }
 
     /// All Winding currents (ph1, wdg1, wdg2,... ph2, wdg1, wdg2 ...)
@@ -9307,8 +10864,21 @@ impl<'a> ITransformers<'a> {
     /// WARNING: If the transformer has open terminal(s), results may be wrong, i.e. avoid using this
     /// in those situations. For more information, see https://github.com/dss-extensions/dss-extensions/issues/24
     pub fn WdgCurrents(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::WdgCurrents")?;
+        unsafe { dss_capi::ctx_Transformers_Get_WdgCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ITransformers::WdgCurrents")
+    }
+
+    pub fn WdgCurrents_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::WdgCurrents_view")?;
+        unsafe { dss_capi::ctx_Transformers_Get_WdgCurrents_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ITransformers::WdgCurrents_view")
+    }
+
+    pub fn WdgCurrents_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::WdgCurrents_into")?;
         unsafe { dss_capi::ctx_Transformers_Get_WdgCurrents_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("ITransformers::WdgCurrents_into")
     }
 
     /// All winding currents in CSV string form like the WdgCurrents property
@@ -9317,48 +10887,74 @@ impl<'a> ITransformers<'a> {
     /// in those situations. For more information, see https://github.com/dss-extensions/dss-extensions/issues/24
     pub fn strWdgCurrents(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Transformers_Get_strWdgCurrents(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::strWdgCurrents")?;
         Ok(result)
     }
 
     /// Transformer Core Type: 0=Shell; 1=1ph; 3-3leg; 4=4-Leg; 5=5-leg; 9=Core-1-phase
     pub fn Get_CoreType(&self) -> Result<CoreType, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_CoreType(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("ITransformers::Get_CoreType")?;
+        result.try_into().origin("ITransformers::Get_CoreType")
     }
 
     pub fn Set_CoreType(&self, value: CoreType) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_CoreType(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_CoreType")
     }
 
     /// dc Resistance of active winding in ohms for GIC analysis
     pub fn Get_RdcOhms(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Transformers_Get_RdcOhms(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("ITransformers::Get_RdcOhms")?;
         Ok(result)
     }
 
     pub fn Set_RdcOhms(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Transformers_Set_RdcOhms(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("ITransformers::Set_RdcOhms")
     }
 
     /// Complex array with the losses by type (total losses, load losses, no-load losses), in VA
     ///
     /// (API Extension)
     pub fn LossesByType(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::LossesByType")?;
         unsafe { dss_capi::ctx_Transformers_Get_LossesByType_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGR().origin("ITransformers::LossesByType")
+    }
+
+    pub fn LossesByType_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::LossesByType_view")?;
+        unsafe { dss_capi::ctx_Transformers_Get_LossesByType_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ITransformers::LossesByType_view")
+    }
+
+    pub fn LossesByType_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::LossesByType_into")?;
+        unsafe { dss_capi::ctx_Transformers_Get_LossesByType_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRInto(out).origin("ITransformers::LossesByType_into")
     }
 
     /// Complex array with the losses by type (total losses, load losses, no-load losses), in VA, concatenated for ALL transformers
     ///
     /// (API Extension)
     pub fn AllLossesByType(&self) -> Result<Box::<[Complex<f64>]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::AllLossesByType")?;
+        unsafe { dss_capi::ctx_Transformers_Get_AllLossesByType_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGR().origin("ITransformers::AllLossesByType")
+    }
+
+    pub fn AllLossesByType_view(&self) -> Result<GRView<'a, Complex<f64>>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::AllLossesByType_view")?;
+        unsafe { dss_capi::ctx_Transformers_Get_AllLossesByType_GR(self.ctx_ptr) };
+        self.ctx.GetComplexArrayGRView().origin("ITransformers::AllLossesByType_view")
+    }
+
+    pub fn AllLossesByType_into(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("ITransformers::AllLossesByType_into")?;
         unsafe { dss_capi::ctx_Transformers_Get_AllLossesByType_GR(self.ctx_ptr) };
-        self.ctx.GetComplexArrayGR()
+        self.ctx.GetComplexArrayGRInto(out).origin("ITransformers::AllLossesByType_into")
     }
 }
 
@@ -9382,115 +10978,115 @@ impl<'a> IVsources<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Vsources_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IVsources::AllNames")
     }
 
     /// Number of Vsource objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Vsources_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IVsources::Count")?;
         Ok(result)
     }
 
     /// Sets the first Vsource active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Vsources_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IVsources::First")?;
         Ok(result)
     }
 
     /// Sets the active Vsource by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Vsources_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IVsources::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Vsource.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IVsources::Set_Name")?;
         unsafe { dss_capi::ctx_Vsources_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IVsources::Set_Name")
     }
 
     /// Sets the next Vsource active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Vsources_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IVsources::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Vsource; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Vsources_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IVsources::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Vsource by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Vsources_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IVsources::Set_idx")
     }
 
     /// Phase angle of first phase in degrees
     pub fn Get_AngleDeg(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Vsources_Get_AngleDeg(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IVsources::Get_AngleDeg")?;
         Ok(result)
     }
 
     pub fn Set_AngleDeg(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Vsources_Set_AngleDeg(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IVsources::Set_AngleDeg")
     }
 
     /// Source voltage in kV
     pub fn Get_BasekV(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Vsources_Get_BasekV(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IVsources::Get_BasekV")?;
         Ok(result)
     }
 
     pub fn Set_BasekV(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Vsources_Set_BasekV(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IVsources::Set_BasekV")
     }
 
     /// Source frequency in Hz
     pub fn Get_Frequency(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Vsources_Get_Frequency(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IVsources::Get_Frequency")?;
         Ok(result)
     }
 
     pub fn Set_Frequency(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Vsources_Set_Frequency(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IVsources::Set_Frequency")
     }
 
     /// Number of phases
     pub fn Get_Phases(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Vsources_Get_Phases(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IVsources::Get_Phases")?;
         Ok(result)
     }
 
     pub fn Set_Phases(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Vsources_Set_Phases(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IVsources::Set_Phases")
     }
 
     /// Per-unit value of source voltage
     pub fn Get_pu(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Vsources_Get_pu(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IVsources::Get_pu")?;
         Ok(result)
     }
 
     pub fn Set_pu(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Vsources_Set_pu(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IVsources::Set_pu")
     }
 }
 
@@ -9514,179 +11110,179 @@ impl<'a> IWireData<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_WireData_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IWireData::AllNames")
     }
 
     /// Number of WireData objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Count")?;
         Ok(result)
     }
 
     /// Sets the first WireData active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::First")?;
         Ok(result)
     }
 
     /// Sets the active WireData by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_WireData_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active WireData.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IWireData::Set_Name")?;
         unsafe { dss_capi::ctx_WireData_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_Name")
     }
 
     /// Sets the next WireData active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active WireData; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active WireData by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_idx")
     }
 
     /// Emergency ampere rating
     pub fn Get_EmergAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_EmergAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Get_EmergAmps")?;
         Ok(result)
     }
 
     pub fn Set_EmergAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_EmergAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_EmergAmps")
     }
 
     /// Normal Ampere rating
     pub fn Get_NormAmps(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_NormAmps(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Get_NormAmps")?;
         Ok(result)
     }
 
     pub fn Set_NormAmps(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_NormAmps(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_NormAmps")
     }
 
     pub fn Get_Rdc(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_Rdc(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Get_Rdc")?;
         Ok(result)
     }
 
     pub fn Set_Rdc(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_Rdc(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_Rdc")
     }
 
     pub fn Get_Rac(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_Rac(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Get_Rac")?;
         Ok(result)
     }
 
     pub fn Set_Rac(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_Rac(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_Rac")
     }
 
     pub fn Get_GMRac(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_GMRac(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Get_GMRac")?;
         Ok(result)
     }
 
     pub fn Set_GMRac(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_GMRac(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_GMRac")
     }
 
     pub fn Get_GMRUnits(&self) -> Result<LineUnits, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_GMRUnits(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("IWireData::Get_GMRUnits")?;
+        result.try_into().origin("IWireData::Get_GMRUnits")
     }
 
     pub fn Set_GMRUnits(&self, value: LineUnits) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_GMRUnits(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_GMRUnits")
     }
 
     pub fn Get_Radius(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_Radius(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Get_Radius")?;
         Ok(result)
     }
 
     pub fn Set_Radius(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_Radius(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_Radius")
     }
 
     pub fn Get_RadiusUnits(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_RadiusUnits(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Get_RadiusUnits")?;
         Ok(result)
     }
 
     pub fn Set_RadiusUnits(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_RadiusUnits(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_RadiusUnits")
     }
 
     pub fn Get_ResistanceUnits(&self) -> Result<LineUnits, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_ResistanceUnits(self.ctx_ptr) };
-        self.ctx.DSSError()?;
-        Ok(unsafe { transmute(result) })
+        self.ctx.DSSError().origin("IWireData::Get_ResistanceUnits")?;
+        result.try_into().origin("IWireData::Get_ResistanceUnits")
     }
 
     pub fn Set_ResistanceUnits(&self, value: LineUnits) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_ResistanceUnits(self.ctx_ptr, (value) as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_ResistanceUnits")
     }
 
     pub fn Get_Diameter(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_Diameter(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Get_Diameter")?;
         Ok(result)
     }
 
     pub fn Set_Diameter(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_Diameter(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_Diameter")
     }
 
     /// Equivalent conductor radius for capacitance calcs. Specify this for bundled conductors. Defaults to same value as radius.
     pub fn Get_CapRadius(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_WireData_Get_CapRadius(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IWireData::Get_CapRadius")?;
         Ok(result)
     }
 
     pub fn Set_CapRadius(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_WireData_Set_CapRadius(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IWireData::Set_CapRadius")
     }
 }
 
@@ -9710,161 +11306,187 @@ impl<'a> IXYCurves<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_XYCurves_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IXYCurves::AllNames")
     }
 
     /// Number of XYCurve objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_XYCurves_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::Count")?;
         Ok(result)
     }
 
     /// Sets the first XYCurve active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_XYCurves_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::First")?;
         Ok(result)
     }
 
     /// Sets the active XYCurve by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_XYCurves_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active XYCurve.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IXYCurves::Set_Name")?;
         unsafe { dss_capi::ctx_XYCurves_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IXYCurves::Set_Name")
     }
 
     /// Sets the next XYCurve active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_XYCurves_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active XYCurve; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_XYCurves_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active XYCurve by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_XYCurves_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IXYCurves::Set_idx")
     }
 
     /// Get/Set Number of points in X-Y curve
     pub fn Get_Npts(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_XYCurves_Get_Npts(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::Get_Npts")?;
         Ok(result)
     }
 
     pub fn Set_Npts(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_XYCurves_Set_Npts(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IXYCurves::Set_Npts")
     }
 
     /// Get/set X values as a Array of doubles. Set Npts to max number expected if setting
     pub fn Get_Xarray(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IXYCurves::Get_Xarray")?;
+        unsafe { dss_capi::ctx_XYCurves_Get_Xarray_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IXYCurves::Get_Xarray")
+    }
+
+    pub fn Get_Xarray_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IXYCurves::Get_Xarray_view")?;
+        unsafe { dss_capi::ctx_XYCurves_Get_Xarray_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IXYCurves::Get_Xarray_view")
+    }
+
+    pub fn Get_Xarray_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IXYCurves::Get_Xarray_into")?;
         unsafe { dss_capi::ctx_XYCurves_Get_Xarray_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IXYCurves::Get_Xarray_into")
     }
 
     pub fn Set_Xarray(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_XYCurves_Set_Xarray(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IXYCurves::Set_Xarray")
     }
 
     /// Factor to scale X values from original curve
     pub fn Get_Xscale(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_XYCurves_Get_Xscale(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::Get_Xscale")?;
         Ok(result)
     }
 
     pub fn Set_Xscale(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_XYCurves_Set_Xscale(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IXYCurves::Set_Xscale")
     }
 
     /// Amount to shift X value from original curve
     pub fn Get_Xshift(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_XYCurves_Get_Xshift(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::Get_Xshift")?;
         Ok(result)
     }
 
     pub fn Set_Xshift(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_XYCurves_Set_Xshift(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IXYCurves::Set_Xshift")
     }
 
     /// Get/Set Y values in curve; Set Npts to max number expected if setting
     pub fn Get_Yarray(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IXYCurves::Get_Yarray")?;
+        unsafe { dss_capi::ctx_XYCurves_Get_Yarray_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IXYCurves::Get_Yarray")
+    }
+
+    pub fn Get_Yarray_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IXYCurves::Get_Yarray_view")?;
+        unsafe { dss_capi::ctx_XYCurves_Get_Yarray_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IXYCurves::Get_Yarray_view")
+    }
+
+    pub fn Get_Yarray_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IXYCurves::Get_Yarray_into")?;
         unsafe { dss_capi::ctx_XYCurves_Get_Yarray_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IXYCurves::Get_Yarray_into")
     }
 
     pub fn Set_Yarray(&self, value: &[f64]) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_XYCurves_Set_Yarray(self.ctx_ptr, value.as_ptr(), value.len() as i32) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IXYCurves::Set_Yarray")
     }
 
     /// Factor to scale Y values from original curve
     pub fn Get_Yscale(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_XYCurves_Get_Yscale(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::Get_Yscale")?;
         Ok(result)
     }
 
     pub fn Set_Yscale(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_XYCurves_Set_Yscale(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IXYCurves::Set_Yscale")
     }
 
     /// Amount to shift Y value from original curve
     pub fn Get_Yshift(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_XYCurves_Get_Yshift(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::Get_Yshift")?;
         Ok(result)
     }
 
     pub fn Set_Yshift(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_XYCurves_Set_Yshift(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IXYCurves::Set_Yshift")
     }
 
     /// Set X value or get interpolated value after setting Y
     pub fn Get_x(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_XYCurves_Get_x(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::Get_x")?;
         Ok(result)
     }
 
     pub fn Set_x(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_XYCurves_Set_x(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IXYCurves::Set_x")
     }
 
     /// Set Y value or get interpolated Y value after setting X
     pub fn Get_y(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_XYCurves_Get_y(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IXYCurves::Get_y")?;
         Ok(result)
     }
 
     pub fn Set_y(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_XYCurves_Set_y(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IXYCurves::Set_y")
     }
 }
 
@@ -9885,105 +11507,105 @@ impl<'a> IYMatrix<'a> {
 
     pub fn ZeroInjCurr(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_ZeroInjCurr(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::ZeroInjCurr")
     }
 
     pub fn GetSourceInjCurrents(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_GetSourceInjCurrents(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::GetSourceInjCurrents")
     }
 
     pub fn GetPCInjCurr(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_GetPCInjCurr(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::GetPCInjCurr")
     }
 
     pub fn BuildYMatrixD(&self, BuildOps: i32, AllocateVI: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_BuildYMatrixD(self.ctx_ptr, BuildOps, AllocateVI) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::BuildYMatrixD")
     }
 
     pub fn AddInAuxCurrents(&self, SType: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_AddInAuxCurrents(self.ctx_ptr, SType) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::AddInAuxCurrents")
     }
 
     pub fn Get_SystemYChanged(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_YMatrix_Get_SystemYChanged(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IYMatrix::Get_SystemYChanged")?;
         Ok(result)
     }
 
     pub fn Set_SystemYChanged(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_Set_SystemYChanged(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::Set_SystemYChanged")
     }
 
     pub fn Get_UseAuxCurrents(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_YMatrix_Get_UseAuxCurrents(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IYMatrix::Get_UseAuxCurrents")?;
         Ok(result)
     }
 
     pub fn Set_UseAuxCurrents(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_Set_UseAuxCurrents(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::Set_UseAuxCurrents")
     }
 
     /// Sparse solver options. See the enumeration SparseSolverOptions
     pub fn Get_SolverOptions(&self) -> Result<u64, DSSError> {
         let result = unsafe { dss_capi::ctx_YMatrix_Get_SolverOptions(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IYMatrix::Get_SolverOptions")?;
         Ok(result)
     }
 
     pub fn Set_SolverOptions(&self, value: u64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_Set_SolverOptions(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::Set_SolverOptions")
     }
 
     pub fn CheckConvergence(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_YMatrix_CheckConvergence(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IYMatrix::CheckConvergence")?;
         Ok(result)
     }
 
     pub fn SetGeneratordQdV(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_SetGeneratordQdV(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::SetGeneratordQdV")
     }
 
     pub fn Get_LoadsNeedUpdating(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_YMatrix_Get_LoadsNeedUpdating(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IYMatrix::Get_LoadsNeedUpdating")?;
         Ok(result)
     }
 
     pub fn Set_LoadsNeedUpdating(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_Set_LoadsNeedUpdating(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::Set_LoadsNeedUpdating")
     }
 
     pub fn Get_SolutionInitialized(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_YMatrix_Get_SolutionInitialized(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IYMatrix::Get_SolutionInitialized")?;
         Ok(result)
     }
 
     pub fn Set_SolutionInitialized(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_Set_SolutionInitialized(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::Set_SolutionInitialized")
     }
 
     pub fn Get_Iteration(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_YMatrix_Get_Iteration(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IYMatrix::Get_Iteration")?;
         Ok(result)
     }
 
     pub fn Set_Iteration(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_YMatrix_Set_Iteration(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IYMatrix::Set_Iteration")
     }
 }
 
@@ -10006,10 +11628,25 @@ impl<'a> IZIP<'a> {
     /// Returns a byte-String.
     ///
     /// (API Extension)
-    pub fn Extract(&self, fileName: String) -> Result<Box::<[i8]>, DSSError> {
-        let fileName_c = CString::new(fileName).unwrap();
+    pub fn Extract(&self, fileName: impl AsRef<str>) -> Result<Box::<[i8]>, DSSError> {
+        let fileName_c = self.ctx.PrepareString(fileName).origin("IZIP::Extract")?;
+        self.ctx.CheckGRBuffers().origin("IZIP::Extract")?;
         unsafe { dss_capi::ctx_ZIP_Extract_GR(self.ctx_ptr, fileName_c.as_ptr()); }
-        self.ctx.GetInt8ArrayGR()
+        self.ctx.GetInt8ArrayGR().origin("IZIP::Extract")
+    }
+
+    pub fn Extract_view(&self, fileName: impl AsRef<str>) -> Result<GRView<'a, i8>, DSSError> {
+        let fileName_c = self.ctx.PrepareString(fileName).origin("IZIP::Extract_view")?;
+        self.ctx.CheckGRBuffers().origin("IZIP::Extract_view")?;
+        unsafe { dss_capi::ctx_ZIP_Extract_GR(self.ctx_ptr, fileName_c.as_ptr()); }
+        self.ctx.GetInt8ArrayGRView().origin("IZIP::Extract_view")
+    }
+
+    pub fn Extract_into(&self, fileName: impl AsRef<str>, out: &mut Vec<i8>) -> Result<(), DSSError> {
+        let fileName_c = self.ctx.PrepareString(fileName).origin("IZIP::Extract_into")?;
+        self.ctx.CheckGRBuffers().origin("IZIP::Extract_into")?;
+        unsafe { dss_capi::ctx_ZIP_Extract_GR(self.ctx_ptr, fileName_c.as_ptr()); }
+        self.ctx.GetInt8ArrayGRInto(out).origin("IZIP::Extract_into")
     }
 
     /// List of strings consisting of all names match the regular expression provided in regexp.
@@ -10019,12 +11656,12 @@ impl<'a> IZIP<'a> {
     /// the expression syntax and options.
     ///
     /// (API Extension)
-    pub fn List(&self, regexp: String) -> Result<Box::<[String]>, DSSError> {
+    pub fn List(&self, regexp: impl AsRef<str>) -> Result<Box::<[String]>, DSSError> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
-        let regexp_c = CString::new(regexp).unwrap();
+        let regexp_c = self.ctx.PrepareString(regexp).origin("IZIP::List")?;
         unsafe { dss_capi::ctx_ZIP_List(self.ctx_ptr, &mut data, &mut cnt[0], regexp_c.as_ptr()); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IZIP::List")
     }
 
     /// Opens and prepares a ZIP file to be used by the DSS text parser.
@@ -10033,10 +11670,10 @@ impl<'a> IZIP<'a> {
     /// The limitations should be removed in a future revision.
     ///
     /// (API Extension)
-    pub fn Open(&self, FileName: String) -> Result<(), DSSError> {
-        let FileName_c = CString::new(FileName).unwrap();
+    pub fn Open(&self, FileName: impl AsRef<str>) -> Result<(), DSSError> {
+        let FileName_c = self.ctx.PrepareString(FileName).origin("IZIP::Open")?;
         unsafe { dss_capi::ctx_ZIP_Open(self.ctx_ptr, FileName_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IZIP::Open")
     }
 
     /// Closes the current open ZIP file
@@ -10044,7 +11681,7 @@ impl<'a> IZIP<'a> {
     /// (API Extension)
     pub fn Close(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_ZIP_Close(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IZIP::Close")
     }
 
     /// Runs a "Redirect" command inside the current (open) ZIP file.
@@ -10053,19 +11690,19 @@ impl<'a> IZIP<'a> {
     /// memory-mapped files.
     ///
     /// (API Extension)
-    pub fn Redirect(&self, FileInZip: String) -> Result<(), DSSError> {
-        let FileInZip_c = CString::new(FileInZip).unwrap();
+    pub fn Redirect(&self, FileInZip: impl AsRef<str>) -> Result<(), DSSError> {
+        let FileInZip_c = self.ctx.PrepareString(FileInZip).origin("IZIP::Redirect")?;
         unsafe { dss_capi::ctx_ZIP_Redirect(self.ctx_ptr, FileInZip_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IZIP::Redirect")
     }
 
     /// Check if the given path name is present in the current ZIP file.
     ///
     /// (API Extension)
-    pub fn Contains(&self, Name: String) -> Result<bool, DSSError> {
-        let Name_c = CString::new(Name).unwrap();
+    pub fn Contains(&self, Name: impl AsRef<str>) -> Result<bool, DSSError> {
+        let Name_c = self.ctx.PrepareString(Name).origin("IZIP::Contains")?;
         let result = unsafe { (dss_capi::ctx_ZIP_Contains(self.ctx_ptr, Name_c.as_ptr()) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IZIP::Contains")?;
         Ok(result)
     }
 
@@ -10091,165 +11728,165 @@ impl<'a> IGICSources<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_GICSources_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IGICSources::AllNames")
     }
 
     /// Number of GICSource objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Count")?;
         Ok(result)
     }
 
     /// Sets the first GICSource active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::First")?;
         Ok(result)
     }
 
     /// Sets the active GICSource by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_GICSources_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active GICSource.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IGICSources::Set_Name")?;
         unsafe { dss_capi::ctx_GICSources_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGICSources::Set_Name")
     }
 
     /// Sets the next GICSource active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active GICSource; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active GICSource by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_GICSources_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGICSources::Set_idx")
     }
 
     /// First bus name of GICSource (Created name)
     pub fn Bus1(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_GICSources_Get_Bus1(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Bus1")?;
         Ok(result)
     }
 
     /// Second bus name
     pub fn Bus2(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_GICSources_Get_Bus2(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Bus2")?;
         Ok(result)
     }
 
     /// Number of Phases, this GICSource element.
     pub fn Get_Phases(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_Phases(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Get_Phases")?;
         Ok(result)
     }
 
     pub fn Set_Phases(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_GICSources_Set_Phases(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGICSources::Set_Phases")
     }
 
     /// Northward E Field V/km
     pub fn Get_EN(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_EN(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Get_EN")?;
         Ok(result)
     }
 
     pub fn Set_EN(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_GICSources_Set_EN(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGICSources::Set_EN")
     }
 
     /// Eastward E Field, V/km
     pub fn Get_EE(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_EE(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Get_EE")?;
         Ok(result)
     }
 
     pub fn Set_EE(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_GICSources_Set_EE(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGICSources::Set_EE")
     }
 
     /// Latitude of Bus1 (degrees)
     pub fn Get_Lat1(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_Lat1(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Get_Lat1")?;
         Ok(result)
     }
 
     pub fn Set_Lat1(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_GICSources_Set_Lat1(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGICSources::Set_Lat1")
     }
 
     /// Latitude of Bus2 (degrees)
     pub fn Get_Lat2(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_Lat2(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Get_Lat2")?;
         Ok(result)
     }
 
     pub fn Set_Lat2(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_GICSources_Set_Lat2(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGICSources::Set_Lat2")
     }
 
     /// Longitude of Bus1 (Degrees)
     pub fn Get_Lon1(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_Lon1(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Get_Lon1")?;
         Ok(result)
     }
 
     pub fn Set_Lon1(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_GICSources_Set_Lon1(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGICSources::Set_Lon1")
     }
 
     /// Longitude of Bus2 (Degrees)
     pub fn Get_Lon2(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_Lon2(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Get_Lon2")?;
         Ok(result)
     }
 
     pub fn Set_Lon2(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_GICSources_Set_Lon2(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGICSources::Set_Lon2")
     }
 
     /// Specify dc voltage directly
     pub fn Get_Volts(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_GICSources_Get_Volts(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IGICSources::Get_Volts")?;
         Ok(result)
     }
 
     pub fn Set_Volts(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_GICSources_Set_Volts(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IGICSources::Set_Volts")
     }
 }
 
@@ -10270,94 +11907,120 @@ impl<'a> IParallel<'a> {
 
     pub fn CreateActor(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Parallel_CreateActor(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParallel::CreateActor")
     }
 
     pub fn Wait(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Parallel_Wait(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParallel::Wait")
     }
 
     /// Gets/sets the ID of the Active Actor
     pub fn Get_ActiveActor(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Parallel_Get_ActiveActor(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParallel::Get_ActiveActor")?;
         Ok(result)
     }
 
     pub fn Set_ActiveActor(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Parallel_Set_ActiveActor(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParallel::Set_ActiveActor")
     }
 
     /// (read) Sets ON/OFF (1/0) Parallel features of the Engine
     /// (write) Delivers if the Parallel features of the Engine are Active
     pub fn Get_ActiveParallel(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Parallel_Get_ActiveParallel(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParallel::Get_ActiveParallel")?;
         Ok(result)
     }
 
     pub fn Set_ActiveParallel(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Parallel_Set_ActiveParallel(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParallel::Set_ActiveParallel")
     }
 
     /// Gets/sets the CPU of the Active Actor
     pub fn Get_ActorCPU(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Parallel_Get_ActorCPU(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParallel::Get_ActorCPU")?;
         Ok(result)
     }
 
     pub fn Set_ActorCPU(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Parallel_Set_ActorCPU(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParallel::Set_ActorCPU")
     }
 
     /// Gets the progress of all existing actors in pct
     pub fn ActorProgress(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParallel::ActorProgress")?;
+        unsafe { dss_capi::ctx_Parallel_Get_ActorProgress_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGR().origin("IParallel::ActorProgress")
+    }
+
+    pub fn ActorProgress_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParallel::ActorProgress_view")?;
+        unsafe { dss_capi::ctx_Parallel_Get_ActorProgress_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRView().origin("IParallel::ActorProgress_view")
+    }
+
+    pub fn ActorProgress_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParallel::ActorProgress_into")?;
         unsafe { dss_capi::ctx_Parallel_Get_ActorProgress_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGRInto(out).origin("IParallel::ActorProgress_into")
     }
 
     /// Gets the status of each actor
     pub fn ActorStatus(&self) -> Result<Box::<[i32]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParallel::ActorStatus")?;
+        unsafe { dss_capi::ctx_Parallel_Get_ActorStatus_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGR().origin("IParallel::ActorStatus")
+    }
+
+    pub fn ActorStatus_view(&self) -> Result<GRView<'a, i32>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParallel::ActorStatus_view")?;
+        unsafe { dss_capi::ctx_Parallel_Get_ActorStatus_GR(self.ctx_ptr) };
+        self.ctx.GetInt32ArrayGRView().origin("IParallel::ActorStatus_view")
+    }
+
+    pub fn ActorStatus_into(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IParallel::ActorStatus_into")?;
         unsafe { dss_capi::ctx_Parallel_Get_ActorStatus_GR(self.ctx_ptr) };
-        self.ctx.GetInt32ArrayGR()
+        self.ctx.GetInt32ArrayGRInto(out).origin("IParallel::ActorStatus_into")
     }
 
     /// (read) Reads the values of the ConcatenateReports option (1=enabled, 0=disabled)
     /// (write) Enable/Disable (1/0) the ConcatenateReports option for extracting monitors data
     pub fn Get_ConcatenateReports(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Parallel_Get_ConcatenateReports(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParallel::Get_ConcatenateReports")?;
         Ok(result)
     }
 
     pub fn Set_ConcatenateReports(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Parallel_Set_ConcatenateReports(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IParallel::Set_ConcatenateReports")
     }
 
     /// Delivers the number of CPUs on the current PC
     pub fn NumCPUs(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Parallel_Get_NumCPUs(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParallel::NumCPUs")?;
         Ok(result)
     }
 
     /// Delivers the number of Cores of the local PC
     pub fn NumCores(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Parallel_Get_NumCores(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParallel::NumCores")?;
         Ok(result)
     }
 
     /// Gets the number of Actors created
     pub fn NumOfActors(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Parallel_Get_NumOfActors(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IParallel::NumOfActors")?;
         Ok(result)
     }
 }
@@ -10382,67 +12045,67 @@ impl<'a> IStorages<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Storages_Get_AllNames(self.ctx_ptr, &mut data, &mut cnt[0]); }
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IStorages::AllNames")
     }
 
     /// Number of Storage objects in active circuit.
     pub fn Count(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Storages_Get_Count(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IStorages::Count")?;
         Ok(result)
     }
 
     /// Sets the first Storage active. Returns 0 if no more.
     pub fn First(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Storages_Get_First(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IStorages::First")?;
         Ok(result)
     }
 
     /// Sets the active Storage by Name.
     pub fn Get_Name(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_Storages_Get_Name(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IStorages::Get_Name")?;
         Ok(result)
     }
 
     /// Gets the name of the active Storage.
-    pub fn Set_Name(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_Name(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IStorages::Set_Name")?;
         unsafe { dss_capi::ctx_Storages_Set_Name(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IStorages::Set_Name")
     }
 
     /// Sets the next Storage active. Returns 0 if no more.
     pub fn Next(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Storages_Get_Next(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IStorages::Next")?;
         Ok(result)
     }
 
     /// Get the index of the active Storage; index is 1-based: 1..count
     pub fn Get_idx(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Storages_Get_idx(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IStorages::Get_idx")?;
         Ok(result)
     }
 
     /// Set the active Storage by index; index is 1-based: 1..count
     pub fn Set_idx(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Storages_Set_idx(self.ctx_ptr, value); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IStorages::Set_idx")
     }
 
     /// Per unit state of charge
     pub fn Get_puSOC(&self) -> Result<f64, DSSError> {
         let result = unsafe { dss_capi::ctx_Storages_Get_puSOC(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IStorages::Get_puSOC")?;
         Ok(result)
     }
 
     pub fn Set_puSOC(&self, value: f64) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Storages_Set_puSOC(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IStorages::Set_puSOC")
     }
 
     /// Get/set state: 0=Idling; 1=Discharging; -1=Charging;
@@ -10450,13 +12113,13 @@ impl<'a> IStorages<'a> {
     /// Related enumeration: StorageStates
     pub fn Get_State(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_Storages_Get_State(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IStorages::Get_State")?;
         Ok(result)
     }
 
     pub fn Set_State(&self, value: i32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_Storages_Set_State(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IStorages::Set_State")
     }
 
     /// Array of Names of all Storage energy meter registers
@@ -10464,13 +12127,26 @@ impl<'a> IStorages<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_Storages_Get_RegisterNames(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IStorages::RegisterNames")
     }
 
     /// Array of values in Storage registers.
     pub fn RegisterValues(&self) -> Result<Box::<[f64]>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IStorages::RegisterValues")?;
+        unsafe { dss_capi::ctx_Storages_Get_RegisterValues_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGR().origin("IStorages::RegisterValues")
+    }
+
+    pub fn RegisterValues_view(&self) -> Result<GRView<'a, f64>, DSSError> {
+        self.ctx.CheckGRBuffers().origin("IStorages::RegisterValues_view")?;
+        unsafe { dss_capi::ctx_Storages_Get_RegisterValues_GR(self.ctx_ptr) };
+        self.ctx.GetFloat64ArrayGRView().origin("IStorages::RegisterValues_view")
+    }
+
+    pub fn RegisterValues_into(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.ctx.CheckGRBuffers().origin("IStorages::RegisterValues_into")?;
         unsafe { dss_capi::ctx_Storages_Get_RegisterValues_GR(self.ctx_ptr) };
-        self.ctx.GetFloat64ArrayGR()
+        self.ctx.GetFloat64ArrayGRInto(out).origin("IStorages::RegisterValues_into")
     }
 }
 
@@ -10484,7 +12160,7 @@ pub struct IDSS<'a> {
     pub DSSProgress: IDSSProgress<'a>,
     pub ActiveClass: IActiveClass<'a>,
     pub Executive: IDSS_Executive<'a>,
-    // pub Events: IDSSEvents<'a>,
+    pub Events: IDSSEvents<'a>,
     pub Parser: IParser<'a>,
     // pub DSSim_Coms: IDSSimComs<'a>,
     pub YMatrix: IYMatrix<'a>,
@@ -10509,7 +12185,7 @@ impl<'a> IDSS<'a> {
             DSSProgress: IDSSProgress::new(&ctx),
             ActiveClass: IActiveClass::new(&ctx),
             Executive: IDSS_Executive::new(&ctx),
-            // Events: IDSSEvents::new(&ctx),
+            Events: IDSSEvents::new(&ctx),
             Parser: IParser::new(&ctx),
             // DSSim_Coms: IDSSimComs::new(&ctx),
             YMatrix: IYMatrix::new(&ctx),
@@ -10525,10 +12201,10 @@ impl<'a> IDSS<'a> {
     /// strings.
     ///
     /// (API Extension)
-    pub fn Command(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Command(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IDSS::Command")?;
         unsafe { dss_capi::ctx_Text_CommandBlock(self.ctx_ptr, value_c.as_ptr()); }
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSS::Command").command(&value_c.to_string_lossy())
     }
      
     /// Creates a wrapper for a new DSS engine context.
@@ -10543,44 +12219,43 @@ impl<'a> IDSS<'a> {
     pub fn NewContext(&self) -> Result<DSSContext, DSSError> {
         let newCtxPtr = unsafe { dss_capi::ctx_New() };
         if newCtxPtr.is_null() {
-            return Err(DSSError {
-                number: 0,
-                message: "Could not create a new DSS Context".to_string()
-            });
+            return Err(DSSError::wrapper(
+                DSSErrorKind::Context,
+                "Could not create a new DSS Context".to_string()
+            )).origin("IDSS::NewContext");
         }
-        let ctx = DSSContext::new(newCtxPtr);
-        Ok(ctx)    
+        DSSContext::try_new(newCtxPtr).origin("IDSS::NewContext")
     }
 
-    pub fn NewCircuit(&self, name: String) -> Result<&ICircuit, DSSError> {
-        let name_c = CString::new(name).unwrap();
+    pub fn NewCircuit(&self, name: impl AsRef<str>) -> Result<&ICircuit, DSSError> {
+        let name_c = self.ctx.PrepareString(name).origin("IDSS::NewCircuit")?;
         unsafe { dss_capi::ctx_DSS_NewCircuit(self.ctx_ptr, name_c.as_ptr()) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::NewCircuit")?;
         Ok(&self.ActiveCircuit)
     }
                        
     /// Get version string for the DSS engine.
     pub fn Version(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSS_Get_Version(self.ctx_ptr)) }.to_string_lossy().into_owned();
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::Version")?;
         Ok(result)
     }
 
     pub fn ClearAll(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSS_ClearAll(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSS::ClearAll")
     }
 
     /// This is a no-op function, does nothing. Left for compatibility.
     pub fn Reset(&self) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSS_Reset(self.ctx_ptr) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSS::Reset")
     }
 
-    pub fn SetActiveClass(&self, ClassName: String) -> Result<i32, DSSError> {
-        let ClassName_c = CString::new(ClassName).unwrap();
+    pub fn SetActiveClass(&self, ClassName: impl AsRef<str>) -> Result<i32, DSSError> {
+        let ClassName_c = self.ctx.PrepareString(ClassName).origin("IDSS::SetActiveClass")?;
         let result = unsafe { dss_capi::ctx_DSS_SetActiveClass(self.ctx_ptr, ClassName_c.as_ptr()) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::SetActiveClass")?;
         Ok(result)
     }
 
@@ -10593,7 +12268,7 @@ impl<'a> IDSS<'a> {
     /// versions.
     pub fn Start(&self, code: i32) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_DSS_Start(self.ctx_ptr, code) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::Start")?;
         Ok(result)
     }
 
@@ -10602,47 +12277,47 @@ impl<'a> IDSS<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_DSS_Get_Classes(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IDSS::Classes")
     }
 
     /// DSS Data File Path.  Default path for reports, etc. from DSS
     pub fn Get_DataPath(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSS_Get_DataPath(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::Get_DataPath")?;
         Ok(result)
     }
 
-    pub fn Set_DataPath(&self, value: String) -> Result<(), DSSError> {
-        let value_c = CString::new(value).unwrap();
+    pub fn Set_DataPath(&self, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let value_c = self.ctx.PrepareString(value).origin("IDSS::Set_DataPath")?;
         unsafe { dss_capi::ctx_DSS_Set_DataPath(self.ctx_ptr, value_c.as_ptr()) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSS::Set_DataPath")
     }
 
     /// Returns the path name for the default text editor.
     pub fn DefaultEditor(&self) -> Result<String, DSSError> {
         let result = unsafe { CStr::from_ptr(dss_capi::ctx_DSS_Get_DefaultEditor(self.ctx_ptr)).to_string_lossy().into_owned() };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::DefaultEditor")?;
         Ok(result)
     }
 
     /// Number of Circuits currently defined
     pub fn NumCircuits(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_DSS_Get_NumCircuits(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::NumCircuits")?;
         Ok(result)
     }
 
     /// Number of DSS intrinsic classes
     pub fn NumClasses(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_DSS_Get_NumClasses(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::NumClasses")?;
         Ok(result)
     }
 
     /// Number of user-defined classes
     pub fn NumUserClasses(&self) -> Result<i32, DSSError> {
         let result = unsafe { dss_capi::ctx_DSS_Get_NumUserClasses(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::NumUserClasses")?;
         Ok(result)
     }
 
@@ -10651,19 +12326,19 @@ impl<'a> IDSS<'a> {
         let mut cnt: [i32; 4] = [0, 0, 0, 0];
         let mut data: *mut *mut c_char= 0 as *mut *mut c_char;
         unsafe { dss_capi::ctx_DSS_Get_UserClasses(self.ctx_ptr, &mut data, &mut cnt[0]) };
-        self.ctx.GetStringArray(data, cnt)
+        self.ctx.GetStringArray(data, cnt).origin("IDSS::UserClasses")
     }
 
     /// Gets/sets whether text output is allowed
     pub fn Get_AllowForms(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_DSS_Get_AllowForms(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::Get_AllowForms")?;
         Ok(result)
     }
 
     pub fn Set_AllowForms(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSS_Set_AllowForms(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSS::Set_AllowForms")
     }
 
     /// Gets/sets whether running the external editor for "Show" is allowed
@@ -10675,13 +12350,13 @@ impl<'a> IDSS<'a> {
     /// (API Extension)
     pub fn Get_AllowEditor(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_DSS_Get_AllowEditor(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::Get_AllowEditor")?;
         Ok(result)
     }
 
     pub fn Set_AllowEditor(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSS_Set_AllowEditor(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSS::Set_AllowEditor")
     }
 
     /// LegacyModels was a flag used to toggle legacy (pre-2019) models for PVSystem, InvControl, Storage and
@@ -10694,13 +12369,13 @@ impl<'a> IDSS<'a> {
     /// (API Extension)
     pub fn Get_LegacyModels(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_DSS_Get_LegacyModels(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::Get_LegacyModels")?;
         Ok(result)
     }
 
     pub fn Set_LegacyModels(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSS_Set_LegacyModels(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSS::Set_LegacyModels")
     }
 
     /// If disabled, the engine will not change the active working directory during execution. E.g. a "compile"
@@ -10717,13 +12392,13 @@ impl<'a> IDSS<'a> {
     /// (API Extension)
     pub fn Get_AllowChangeDir(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_DSS_Get_AllowChangeDir(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::Get_AllowChangeDir")?;
         Ok(result)
     }
 
     pub fn Set_AllowChangeDir(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSS_Set_AllowChangeDir(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSS::Set_AllowChangeDir")
     }
 
     /// If enabled, the `DOScmd` command is allowed. Otherwise, an error is reported if the user tries to use it.
@@ -10736,13 +12411,13 @@ impl<'a> IDSS<'a> {
     /// (API Extension)
     pub fn Get_AllowDOScmd(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_DSS_Get_AllowDOScmd(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::Get_AllowDOScmd")?;
         Ok(result)
     }
 
     pub fn Set_AllowDOScmd(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSS_Set_AllowDOScmd(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSS::Set_AllowDOScmd")
     }
 
     /// If enabled, in case of errors or empty arrays, the API returns arrays with values compatible with the
@@ -10761,13 +12436,13 @@ impl<'a> IDSS<'a> {
     /// (API Extension)
     pub fn Get_COMErrorResults(&self) -> Result<bool, DSSError> {
         let result = unsafe { (dss_capi::ctx_DSS_Get_COMErrorResults(self.ctx_ptr) != 0) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::Get_COMErrorResults")?;
         Ok(result)
     }
 
     pub fn Set_COMErrorResults(&self, value: bool) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSS_Set_COMErrorResults(self.ctx_ptr, bool_to_u16(value)) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSS::Set_COMErrorResults")
     }
 
     /// Controls some compatibility flags introduced to toggle some behavior from the official OpenDSS.
@@ -10800,12 +12475,12 @@ impl<'a> IDSS<'a> {
     /// (API Extension)
     pub fn Get_CompatFlags(&self) -> Result<u32, DSSError> {
         let result = unsafe { dss_capi::ctx_DSS_Get_CompatFlags(self.ctx_ptr) };
-        self.ctx.DSSError()?;
+        self.ctx.DSSError().origin("IDSS::Get_CompatFlags")?;
         Ok(result)
     }
 
     pub fn Set_CompatFlags(&self, value: u32) -> Result<(), DSSError> {
         unsafe { dss_capi::ctx_DSS_Set_CompatFlags(self.ctx_ptr, value) };
-        self.ctx.DSSError()
+        self.ctx.DSSError().origin("IDSS::Set_CompatFlags")
     }
 }
@@ -0,0 +1,315 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rust iterators over the collection interfaces of the classic API (`ILoads`,
+//! `ILines`, `ITransformers`...), replacing the `First`/`Next` loops:
+//!
+//! ```no_run
+//! use altdss::common::DSSContext;
+//! use altdss::classic::IDSS;
+//!
+//! let ctx = DSSContext::prime();
+//! let dss = IDSS::new(&ctx);
+//! for load in dss.ActiveCircuit.Loads.iter() {
+//!     load.unwrap().With(|load| {
+//!         println!("{}: {} kW", load.Get_Name()?, load.Get_kW()?);
+//!         Ok(())
+//!     }).unwrap();
+//! }
+//! ```
+//!
+//! Besides the collections of a single class, `IPDElements`, `IActiveClass` and the
+//! power conversion elements (`ICircuit::PCElements`) can be iterated in the same way.
+//!
+//! The element that was active before the iteration is activated again when the
+//! iterator is dropped, including when the loop exits early.
+
+#![allow(non_snake_case)]
+
+use crate::classic::*;
+use crate::common::{DSSError, DSSResultExt};
+
+/// A collection interface of the classic API, with the COM-style `First`/`Next` protocol.
+pub trait DSSCollection {
+    /// Identifies an element, to activate it again later: the 1-based index, or the
+    /// name for the collections without indices
+    type Key: Clone;
+
+    fn First(&self) -> Result<i32, DSSError>;
+    fn Next(&self) -> Result<i32, DSSError>;
+
+    /// Key of the active element, if any
+    fn ActiveKey(&self) -> Result<Option<Self::Key>, DSSError>;
+
+    /// Activates an element by key
+    fn ActivateKey(&self, key: &Self::Key) -> Result<(), DSSError>;
+}
+
+/// A collection with the elements of a single DSS class, e.g. `ILoads`
+pub trait DSSClassCollection: DSSCollection<Key = i32> {
+    /// Name of the DSS class of the elements
+    const CLASS_NAME: &'static str;
+}
+
+/// Iterator over the elements of a collection; see `DSSCollectionIter::new`.
+pub struct DSSCollectionIter<'c, T: DSSCollection> {
+    collection: &'c T,
+    previous: Option<T::Key>,
+    started: bool,
+    finished: bool,
+}
+
+/// An element of a collection, yielded by `DSSCollectionIter`.
+///
+/// Any other element can be activated in between calls, so the collection interface
+/// is only reachable through `Activate` or `With`, which activate the element first.
+pub struct DSSCollectionItem<'c, T: DSSCollection> {
+    collection: &'c T,
+    key: T::Key,
+}
+
+impl<'c, T: DSSCollection> DSSCollectionIter<'c, T> {
+    /// Creates an iterator over the collection. The active element is recorded here and
+    /// restored when the iterator is dropped.
+    pub fn new(collection: &'c T) -> Self {
+        Self {
+            collection: collection,
+            // Nothing to restore if the active element cannot be read
+            previous: collection.ActiveKey().ok().flatten(),
+            started: false,
+            finished: false,
+        }
+    }
+}
+
+impl<'c, T: DSSCollection> Iterator for DSSCollectionIter<'c, T> {
+    type Item = Result<DSSCollectionItem<'c, T>, DSSError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let result = if self.started { self.collection.Next() } else { self.collection.First() };
+        self.started = true;
+        let key = match result.and_then(|found| if found == 0 { Ok(None) } else { self.collection.ActiveKey() }) {
+            Ok(None) => {
+                self.finished = true;
+                return None;
+            },
+            Ok(Some(key)) => key,
+            Err(err) => {
+                self.finished = true;
+                return Some(Err(err).origin("DSSCollectionIter::next"));
+            },
+        };
+        Some(Ok(DSSCollectionItem {
+            collection: self.collection,
+            key: key,
+        }))
+    }
+}
+
+impl<'c, T: DSSCollection> Drop for DSSCollectionIter<'c, T> {
+    fn drop(&mut self) {
+        if let (true, Some(previous)) = (self.started, &self.previous) {
+            let _ = self.collection.ActivateKey(previous);
+        }
+    }
+}
+
+impl<'c, T: DSSCollection> DSSCollectionItem<'c, T> {
+    /// Key of the element in the collection, see `DSSCollection::Key`
+    pub fn Key(&self) -> &T::Key {
+        &self.key
+    }
+
+    /// Activates the element and returns the collection interface, which applies to
+    /// it until another element is activated
+    pub fn Activate(&self) -> Result<&'c T, DSSError> {
+        self.collection.ActivateKey(&self.key).origin("DSSCollectionItem::Activate")?;
+        Ok(self.collection)
+    }
+
+    /// Activates the element and runs a closure with the collection interface
+    pub fn With<R, F: FnOnce(&T) -> Result<R, DSSError>>(&self, f: F) -> Result<R, DSSError> {
+        f(self.Activate().origin("DSSCollectionItem::With")?)
+    }
+}
+
+impl<'c, T: DSSClassCollection> DSSCollectionItem<'c, T> {
+    /// 1-based index of the element in the collection
+    pub fn Index(&self) -> i32 {
+        self.key
+    }
+}
+
+macro_rules! dss_collections {
+    ($($name:ident => $class_name:literal),* $(,)?) => {
+        $(
+            impl<'a> DSSCollection for $name<'a> {
+                type Key = i32;
+
+                fn First(&self) -> Result<i32, DSSError> {
+                    $name::First(self)
+                }
+
+                fn Next(&self) -> Result<i32, DSSError> {
+                    $name::Next(self)
+                }
+
+                fn ActiveKey(&self) -> Result<Option<i32>, DSSError> {
+                    Ok(Some($name::Get_idx(self)?).filter(|idx| *idx > 0))
+                }
+
+                fn ActivateKey(&self, key: &i32) -> Result<(), DSSError> {
+                    $name::Set_idx(self, *key)
+                }
+            }
+
+            impl<'a> DSSClassCollection for $name<'a> {
+                const CLASS_NAME: &'static str = $class_name;
+            }
+
+            impl<'a> $name<'a> {
+                /// Iterates over the elements, activating each one in turn. The previously
+                /// active element is restored when the iterator is dropped.
+                pub fn iter(&self) -> DSSCollectionIter<'_, Self> {
+                    DSSCollectionIter::new(self)
+                }
+            }
+        )*
+    };
+}
+
+dss_collections!(
+    ICNData => "CNData",
+    ICapacitors => "Capacitor",
+    ICapControls => "CapControl",
+    IFuses => "Fuse",
+    IGenerators => "Generator",
+    IGICSources => "GICSource",
+    IISources => "Isource",
+    ILineCodes => "LineCode",
+    ILineGeometries => "LineGeometry",
+    ILines => "Line",
+    ILineSpacings => "LineSpacing",
+    ILoads => "Load",
+    ILoadShapes => "LoadShape",
+    IMeters => "EnergyMeter",
+    IMonitors => "Monitor",
+    IPVSystems => "PVSystem",
+    IReactors => "Reactor",
+    IReclosers => "Recloser",
+    IRegControls => "RegControl",
+    IRelays => "Relay",
+    ISensors => "Sensor",
+    IStorages => "Storage",
+    ISwtControls => "SwtControl",
+    ITransformers => "Transformer",
+    ITSData => "TSData",
+    IVsources => "Vsource",
+    IWireData => "WireData",
+    IXYCurves => "XYcurve",
+);
+
+// The collections without indices use the names, which are the full names for the
+// circuit elements
+macro_rules! dss_named_collections {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl<'a> DSSCollection for $name<'a> {
+                type Key = String;
+
+                fn First(&self) -> Result<i32, DSSError> {
+                    $name::First(self)
+                }
+
+                fn Next(&self) -> Result<i32, DSSError> {
+                    $name::Next(self)
+                }
+
+                fn ActiveKey(&self) -> Result<Option<String>, DSSError> {
+                    Ok(Some($name::Get_Name(self)?).filter(|name| !name.is_empty()))
+                }
+
+                fn ActivateKey(&self, key: &String) -> Result<(), DSSError> {
+                    $name::Set_Name(self, key)
+                }
+            }
+
+            impl<'a> $name<'a> {
+                /// Iterates over the elements, activating each one in turn. The previously
+                /// active element is restored when the iterator is dropped.
+                pub fn iter(&self) -> DSSCollectionIter<'_, Self> {
+                    DSSCollectionIter::new(self)
+                }
+            }
+        )*
+    };
+}
+
+dss_named_collections!(
+    IPDElements,
+    IActiveClass,
+);
+
+/// The power conversion elements of the circuit (loads, generators, PV systems...),
+/// see `ICircuit::PCElements`. Each element is activated as `ICircuit::ActiveCktElement`.
+pub struct IPCElements<'c, 'a> {
+    circuit: &'c ICircuit<'a>,
+}
+
+impl<'c, 'a> IPCElements<'c, 'a> {
+    /// Interface for the active element
+    pub fn CktElement(&self) -> &'c ICktElement<'a> {
+        &self.circuit.ActiveCktElement
+    }
+
+    /// Iterates over the elements, activating each one in turn. The previously
+    /// active circuit element is restored when the iterator is dropped.
+    pub fn iter(&self) -> DSSCollectionIter<'_, Self> {
+        DSSCollectionIter::new(self)
+    }
+}
+
+impl<'c, 'a> DSSCollection for IPCElements<'c, 'a> {
+    type Key = String;
+
+    fn First(&self) -> Result<i32, DSSError> {
+        self.circuit.FirstPCElement()
+    }
+
+    fn Next(&self) -> Result<i32, DSSError> {
+        self.circuit.NextPCElement()
+    }
+
+    fn ActiveKey(&self) -> Result<Option<String>, DSSError> {
+        Ok(Some(self.circuit.ActiveCktElement.Name()?).filter(|name| !name.is_empty()))
+    }
+
+    fn ActivateKey(&self, key: &String) -> Result<(), DSSError> {
+        self.circuit.SetActiveElement(key).map(|_| ())
+    }
+}
+
+impl<'a> ICircuit<'a> {
+    /// The power conversion elements of the circuit, for iteration
+    pub fn PCElements(&self) -> IPCElements<'_, 'a> {
+        IPCElements {
+            circuit: self,
+        }
+    }
+}
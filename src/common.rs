@@ -17,30 +17,319 @@
 #![allow(nonstandard_style)]
 
 use crate::dss_capi;
-use std::{fmt, error::Error, ffi::{c_char, c_void, CStr, CString}, slice::{from_raw_parts, from_raw_parts_mut}};
+use crate::callbacks;
+use std::{fmt, error::Error, ffi::{c_char, c_void, CStr, CString, NulError}, marker::PhantomData, ops::Deref, slice::{from_raw_parts, from_raw_parts_mut}};
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "serde")]
+use std::sync::{Arc, OnceLock};
 use num_complex::Complex;
 
+/// Categories for the errors reported by the DSS engine.
+///
+/// The engine reports errors as a number and a message. Since most numbers are specific
+/// to each part of the engine, the category is derived from the well-known numbers
+/// and, for the rest, from whole words or phrases of the message.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DSSErrorCategory {
+    /// Unknown command, or a command or value that could not be parsed
+    Parse,
+    /// Unknown or invalid property for a DSS object
+    Property,
+    /// The solution did not converge, including reaching the max control iterations
+    Convergence,
+    /// There is no active circuit, or its solution was not initialized
+    NoActiveCircuit,
+    /// There is no active element or object for the interface
+    NoActiveElement,
+    /// Errors reading or writing files
+    File,
+    /// Errors not classified in any of the other categories
+    Other,
+}
+
+/// Kinds of errors, separating errors reported by the DSS engine from the errors
+/// raised by the Rust wrappers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DSSErrorKind {
+    /// Error reported by the DSS engine
+    Engine(DSSErrorCategory),
+    /// A string passed to the API contains an interior NUL character
+    InteriorNul,
+    /// The engine returned a value that is not valid for the expected enum
+    InvalidEnumValue,
+    /// The DSS C-API library could not be loaded, or some of its functions are missing
+    Library,
+    /// A DSS context could not be created
+    Context,
+    /// The GR buffers could not be used since a `GRView` over them is still alive
+    GRBufferInUse,
+    /// A callback could not be registered in the engine
+    Callback,
+    /// A DSS class, object, bus or property was not found, either by the wrappers or by
+    /// the engine (which keeps its error number in `DSSError::number`)
+    NotFound,
+    /// A value passed to the API has an invalid size
+    InvalidArgument,
+    /// JSON data could not be converted to or from the Rust types
+    JSON,
+    /// An option that affects all DSS contexts in the process was changed without an explicit request
+    GlobalOption,
+    /// An element reference or object handle is no longer valid, since the circuit was cleared
+    /// or the element was removed
+    StaleReference,
+    /// The engine does not support the operation for the given object
+    Unsupported,
+    /// The operation was cancelled before it ran
+    Cancelled,
+    /// A user-provided closure panicked
+    Panic,
+}
+
+/// Error numbers used by the engine for the same condition across its interfaces
+const ENGINE_ERROR_NUMBERS: &[(i32, DSSErrorCategory)] = &[
+    (200, DSSErrorCategory::Parse), // Unknown command
+    (485, DSSErrorCategory::Convergence), // Max control iterations exceeded
+    (8888, DSSErrorCategory::NoActiveCircuit),
+    (8899, DSSErrorCategory::NoActiveCircuit), // Solution not initialized
+    (8989, DSSErrorCategory::NoActiveElement),
+];
+
+/// Returns whether the words of `phrase` appear in sequence in `words`
+fn has_phrase(words: &[&str], phrase: &str) -> bool {
+    let phrase: Vec<&str> = phrase.split(' ').collect();
+    words.windows(phrase.len()).any(|window| window == phrase.as_slice())
+}
+
+impl DSSErrorKind {
+    /// Classifies an error reported by the DSS engine.
+    ///
+    /// Errors about missing objects, classes or buses are classified as `NotFound`,
+    /// the same kind used by the wrappers; the rest as `Engine` with a category.
+    pub fn from_engine(number: i32, message: &str) -> Self {
+        if let Some((_, category)) = ENGINE_ERROR_NUMBERS.iter().find(|(known, _)| *known == number) {
+            return DSSErrorKind::Engine(*category);
+        }
+        let message = message.to_lowercase();
+        let words: Vec<&str> = message.split(|c: char| !c.is_alphanumeric()).filter(|word| !word.is_empty()).collect();
+        let any_phrase = |phrases: &[&str]| phrases.iter().any(|phrase| has_phrase(&words, phrase));
+        let category = if any_phrase(&["converge", "converged", "convergence", "max control iterations"]) {
+            DSSErrorCategory::Convergence
+        } else if any_phrase(&["no active circuit"]) {
+            DSSErrorCategory::NoActiveCircuit
+        } else if any_phrase(&["no active"]) {
+            DSSErrorCategory::NoActiveElement
+        } else if any_phrase(&["file"]) {
+            DSSErrorCategory::File
+        } else if any_phrase(&["not found"]) {
+            return DSSErrorKind::NotFound;
+        } else if any_phrase(&["unknown parameter", "unknown property", "invalid property"]) {
+            DSSErrorCategory::Property
+        } else if any_phrase(&["unknown command", "parse", "parsing", "syntax"]) {
+            DSSErrorCategory::Parse
+        } else {
+            DSSErrorCategory::Other
+        };
+        DSSErrorKind::Engine(category)
+    }
+}
+
 /// Wrapper for OpenDSS errors
 pub struct DSSError {
     pub number: i32,
     pub message: String,
+    pub kind: DSSErrorKind,
+
+    /// Name of the wrapper method that raised the error, e.g. `"ILoads::Set_kW"`
+    pub origin: Option<&'static str>,
+
+    /// Command text related to the error, for the methods that run DSS commands
+    pub command: Option<String>,
+
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl DSSError {
+    pub fn new(kind: DSSErrorKind, number: i32, message: String) -> Self {
+        Self {
+            number: number,
+            message: message,
+            kind: kind,
+            origin: None,
+            command: None,
+            source: None,
+        }
+    }
+
+    /// Creates an error as reported by the DSS engine, classified through
+    /// `DSSErrorKind::from_engine`.
+    pub fn engine(number: i32, message: String) -> Self {
+        let kind = DSSErrorKind::from_engine(number, &message);
+        DSSError::new(kind, number, message)
+    }
+
+    /// Creates an error raised by the wrappers. These use zero as the error number.
+    pub fn wrapper(kind: DSSErrorKind, message: String) -> Self {
+        DSSError::new(kind, 0, message)
+    }
+
+    /// Returns the category if the error was reported by the DSS engine. Missing objects
+    /// reported by the engine use `DSSErrorKind::NotFound` instead, without a category.
+    pub fn category(&self) -> Option<DSSErrorCategory> {
+        match self.kind {
+            DSSErrorKind::Engine(category) => Some(category),
+            _ => None,
+        }
+    }
+
+    pub fn with_source<E: Error + Send + Sync + 'static>(mut self, source: E) -> Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+}
+
+impl From<NulError> for DSSError {
+    fn from(err: NulError) -> Self {
+        DSSError::wrapper(
+            DSSErrorKind::InteriorNul,
+            format!("String contains an interior NUL character at position {}", err.nul_position())
+        ).with_source(err)
+    }
 }
 
 impl Error for DSSError {
     fn description(&self,) -> &str {
         &self.message
     }
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_ref().map(|err| &**err as &(dyn Error + 'static))
+    }
 }
 
 impl fmt::Display for DSSError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "(#{}) {}", self.number, self.message)
+        write!(f, "(#{}) {}", self.number, self.message)?;
+        if let Some(origin) = self.origin {
+            write!(f, " [{}]", origin)?;
+        }
+        Ok(())
     }
 }
 
 impl fmt::Debug for DSSError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "(#{}) {}", self.number, self.message)
+        fmt::Display::fmt(self, f)?;
+        if let Some(command) = &self.command {
+            write!(f, "\nCommand: {}", command)?;
+        }
+        Ok(())
+    }
+}
+
+/// Adds context to the errors returned by the API wrappers
+pub trait DSSResultExt {
+    /// Records the name of the wrapper method that raised the error.
+    /// If already recorded, the original name is kept.
+    fn origin(self, origin: &'static str) -> Self;
+
+    /// Records the command text related to the error.
+    fn command(self, command: &str) -> Self;
+}
+
+impl<T> DSSResultExt for Result<T, DSSError> {
+    fn origin(self, origin: &'static str) -> Self {
+        self.map_err(|mut err| {
+            err.origin.get_or_insert(origin);
+            err
+        })
+    }
+
+    fn command(self, command: &str) -> Self {
+        self.map_err(|mut err| {
+            err.command = Some(command.to_string());
+            err
+        })
+    }
+}
+
+/// A set of flags from one of the flag enums, e.g. `DSSJSONOptions` for `DSSJSONFlags`
+/// or `DSSSaveOptions` for `DSSSaveFlags`.
+pub struct DSSFlags<F> {
+    bits: i32,
+    flag: PhantomData<F>,
+}
+
+impl<F: Copy + Into<i32>> DSSFlags<F> {
+    /// Options with no flags set, i.e. the engine defaults
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn from_flags(flags: &[F]) -> Self {
+        flags.iter().fold(Self::new(), |options, flag| options.With(*flag))
+    }
+
+    pub fn With(self, flag: F) -> Self {
+        Self {
+            bits: self.bits | flag.into(),
+            flag: PhantomData,
+        }
+    }
+
+    pub fn Without(self, flag: F) -> Self {
+        Self {
+            bits: self.bits & !flag.into(),
+            flag: PhantomData,
+        }
+    }
+
+    pub fn Contains(&self, flag: F) -> bool {
+        (self.bits & flag.into()) != 0
+    }
+
+    /// Value for the options argument of the respective API function
+    pub fn Bits(&self) -> i32 {
+        self.bits
+    }
+}
+
+// Implemented by hand to avoid requiring the traits for the flag enum
+impl<F> Default for DSSFlags<F> {
+    fn default() -> Self {
+        Self {
+            bits: 0,
+            flag: PhantomData,
+        }
+    }
+}
+
+impl<F> Clone for DSSFlags<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for DSSFlags<F> {
+}
+
+impl<F> PartialEq for DSSFlags<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<F> Eq for DSSFlags<F> {
+}
+
+impl<F> fmt::Debug for DSSFlags<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DSSFlags({:#x})", self.bits)
+    }
+}
+
+impl<F> From<DSSFlags<F>> for i32 {
+    fn from(options: DSSFlags<F>) -> i32 {
+        options.bits
     }
 }
 
@@ -62,12 +351,55 @@ pub struct DSSContext {
     DataPtr_PDouble: *mut *mut f64,
     DataPtr_PInteger: *mut *mut i32,
     DataPtr_PByte: *mut *mut i8,
+
+    // Number of live views over the GR buffers
+    GRViewCount: AtomicUsize,
+
+    // Property metadata, extracted on first use; see `meta`
+    #[cfg(feature = "serde")]
+    pub(crate) schema: OnceLock<Arc<crate::meta::DSSSchema>>,
+}
+
+/// Borrowed view over one of the global result (GR) buffers of a DSS context,
+/// returned by the `*_view` variants of the functions that use the GR buffers.
+///
+/// A view avoids copying the data, but it is only valid until the next call that
+/// fills the GR buffers. To keep that safe, while a view is alive, the functions
+/// that would overwrite the buffers return an error (`DSSErrorKind::GRBufferInUse`).
+/// Drop the view (or copy its data) before calling them.
+///
+/// For reusing an allocation across calls instead, use the `*_into` variants.
+pub struct GRView<'a, T> {
+    ctx: &'a DSSContext,
+    data: &'a [T],
+}
+
+impl<'a, T> Deref for GRView<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.data
+    }
+}
+
+impl<'a, T> Drop for GRView<'a, T> {
+    fn drop(&mut self) {
+        self.ctx.GRViewCount.fetch_sub(1, Ordering::Release);
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for GRView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.data, f)
+    }
 }
 
 impl Drop for DSSContext {
     fn drop(&mut self) {
+        // The Prime context outlives its wrappers, so its callbacks are kept
         unsafe {
             if self.ctx_ptr != dss_capi::ctx_Get_Prime() {
+                callbacks::remove_context(self.ctx_ptr);
                 dss_capi::ctx_Dispose(self.ctx_ptr);
             }
         }
@@ -78,11 +410,31 @@ impl DSSContext {
     /// Currently, the Prime context is the default OpenDSS 
     /// instance created (automatically) when the DSS C-API 
     /// library is loaded.
+    ///
+    /// With the `runtime-loading` feature, the library is loaded here if that was not
+    /// done yet (see `dss_capi::load_library_from_env`), panicking if it fails; use
+    /// `try_prime` to get the error instead.
     pub fn prime() -> Self {
-        DSSContext::new(unsafe { dss_capi::ctx_Get_Prime() })
+        Self::try_prime().unwrap_or_else(|err| panic!("{}", err))
     }
 
+    /// Same as `prime`, returning an error if the library cannot be loaded
+    /// (only possible with the `runtime-loading` feature).
+    pub fn try_prime() -> Result<Self, DSSError> {
+        dss_capi::ensure_library()?;
+        DSSContext::try_new(unsafe { dss_capi::ctx_Get_Prime() })
+    }
+
+    /// Wraps an existing DSS context. With the `runtime-loading` feature, panics if the
+    /// library cannot be loaded; use `try_new` to get the error instead.
     pub fn new(ctx_ptr: *const c_void) -> Self {
+        Self::try_new(ctx_ptr).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Same as `new`, returning an error if the library cannot be loaded
+    /// (only possible with the `runtime-loading` feature).
+    pub fn try_new(ctx_ptr: *const c_void) -> Result<Self, DSSError> {
+        dss_capi::ensure_library()?;
         unsafe {
             dss_capi::ctx_DSS_Start(ctx_ptr, 0);
             
@@ -106,7 +458,7 @@ impl DSSContext {
                 &mut CountPtr_PInteger,
                 &mut CountPtr_PByte,
             );
-            Self {
+            Ok(Self {
                 ctx_ptr: ctx_ptr,
                 errorNumberPtr: dss_capi::ctx_Error_Get_NumberPtr(ctx_ptr),
                 CountPtr_PDouble: CountPtr_PDouble,
@@ -115,7 +467,10 @@ impl DSSContext {
                 DataPtr_PDouble: DataPtr_PDouble,
                 DataPtr_PInteger: DataPtr_PInteger,
                 DataPtr_PByte: DataPtr_PByte,
-            }
+                GRViewCount: AtomicUsize::new(0),
+                #[cfg(feature = "serde")]
+                schema: OnceLock::new(),
+            })
         }
     }
 
@@ -124,19 +479,27 @@ impl DSSContext {
             let num = unsafe { *self.errorNumberPtr };
             let msg_ptr = unsafe { dss_capi::ctx_Error_Get_Description(self.ctx_ptr) };
             unsafe { *self.errorNumberPtr = 0 };
-            Err(DSSError {
-                number: num,
-                message: unsafe { CStr::from_ptr(msg_ptr) }.to_string_lossy().into_owned(),
-            })
+            Err(DSSError::engine(
+                num,
+                unsafe { CStr::from_ptr(msg_ptr) }.to_string_lossy().into_owned(),
+            ))
         } else {
             Ok(())
         }
     }
 
-    pub fn PrepareStringArray(&self, value: &[String]) -> (Box::<[CString]>, Box::<[*mut c_char]>) {
-        let mut c_strs: Box::<[CString]> = value.iter().map(|s| CString::new(s.as_str()).unwrap()).collect();
+    /// Converts a string for the DSS C-API, returning an error if it contains interior NULs.
+    pub fn PrepareString<S: AsRef<str>>(&self, value: S) -> Result<CString, DSSError> {
+        Ok(CString::new(value.as_ref())?)
+    }
+
+    /// Converts a list of strings for the DSS C-API, returning an error if any of them
+    /// contains interior NULs. The pointers in the second element refer to the strings
+    /// in the first, so both must be kept alive while the pointers are in use.
+    pub fn PrepareStringArray<S: AsRef<str>>(&self, value: &[S]) -> Result<(Box::<[CString]>, Box::<[*mut c_char]>), DSSError> {
+        let mut c_strs: Box::<[CString]> = value.iter().map(|s| CString::new(s.as_ref())).collect::<Result<_, _>>()?;
         let c_ptrs = c_strs.iter_mut().map(|cs| cs.as_ptr() as *mut c_char).collect();
-        (c_strs, c_ptrs)
+        Ok((c_strs, c_ptrs))
     }
 
     pub fn GetStringArray(&self, mut data: *mut *mut c_char, cnt: [i32; 4]) -> Result<Box::<[String]>, DSSError> {
@@ -148,6 +511,107 @@ impl DSSContext {
         Ok(res)
     }
 
+    /// Checks that the GR buffers can be overwritten, i.e. no `GRView` is alive.
+    /// This must be called before the functions that fill the GR buffers.
+    pub fn CheckGRBuffers(&self) -> Result<(), DSSError> {
+        if self.GRViewCount.load(Ordering::Acquire) != 0 {
+            return Err(DSSError::wrapper(
+                DSSErrorKind::GRBufferInUse,
+                "The GR buffers are still borrowed by a view; drop it before calling this function".to_string()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the result pointers of the float64 GR buffer, for the functions that fill
+    /// arrays through output arguments. `CheckGRBuffers` must be called before using them.
+    pub(crate) fn Float64ArrayGRPointers(&self) -> (*mut *mut f64, *mut i32) {
+        (self.DataPtr_PDouble, self.CountPtr_PDouble)
+    }
+
+    /// Same as `Float64ArrayGRPointers`, for the int32 GR buffer.
+    pub(crate) fn Int32ArrayGRPointers(&self) -> (*mut *mut i32, *mut i32) {
+        (self.DataPtr_PInteger, self.CountPtr_PInteger)
+    }
+
+    fn GRSlice<T>(&self, data: *const T, cnt: usize) -> &[T] {
+        if cnt == 0 || data.is_null() {
+            return &[];
+        }
+        unsafe { from_raw_parts(data, cnt) }
+    }
+
+    fn GRView<'a, T>(&'a self, data: &'a [T]) -> GRView<'a, T> {
+        self.GRViewCount.fetch_add(1, Ordering::Acquire);
+        GRView {
+            ctx: self,
+            data: data,
+        }
+    }
+
+    fn GetComplexSliceGR(&self) -> &[Complex<f64>] {
+        let mut res_cnt = unsafe { *self.CountPtr_PDouble } as usize;
+        if res_cnt == 1 {
+            res_cnt = 0
+        }
+        res_cnt /= 2;
+        self.GRSlice(unsafe { *self.DataPtr_PDouble } as *const Complex<f64>, res_cnt)
+    }
+
+    pub fn GetFloat64ArrayGRView(&self) -> Result<GRView<'_, f64>, DSSError> {
+        self.DSSError()?;
+        let res_cnt = unsafe { *self.CountPtr_PDouble } as usize;
+        Ok(self.GRView(self.GRSlice(unsafe { *self.DataPtr_PDouble }, res_cnt)))
+    }
+
+    pub fn GetFloat64ArrayGRInto(&self, out: &mut Vec<f64>) -> Result<(), DSSError> {
+        self.DSSError()?;
+        let res_cnt = unsafe { *self.CountPtr_PDouble } as usize;
+        out.clear();
+        out.extend_from_slice(self.GRSlice(unsafe { *self.DataPtr_PDouble }, res_cnt));
+        Ok(())
+    }
+
+    pub fn GetComplexArrayGRView(&self) -> Result<GRView<'_, Complex<f64>>, DSSError> {
+        self.DSSError()?;
+        Ok(self.GRView(self.GetComplexSliceGR()))
+    }
+
+    pub fn GetComplexArrayGRInto(&self, out: &mut Vec<Complex<f64>>) -> Result<(), DSSError> {
+        self.DSSError()?;
+        out.clear();
+        out.extend_from_slice(self.GetComplexSliceGR());
+        Ok(())
+    }
+
+    pub fn GetInt32ArrayGRView(&self) -> Result<GRView<'_, i32>, DSSError> {
+        self.DSSError()?;
+        let res_cnt = unsafe { *self.CountPtr_PInteger } as usize;
+        Ok(self.GRView(self.GRSlice(unsafe { *self.DataPtr_PInteger }, res_cnt)))
+    }
+
+    pub fn GetInt32ArrayGRInto(&self, out: &mut Vec<i32>) -> Result<(), DSSError> {
+        self.DSSError()?;
+        let res_cnt = unsafe { *self.CountPtr_PInteger } as usize;
+        out.clear();
+        out.extend_from_slice(self.GRSlice(unsafe { *self.DataPtr_PInteger }, res_cnt));
+        Ok(())
+    }
+
+    pub fn GetInt8ArrayGRView(&self) -> Result<GRView<'_, i8>, DSSError> {
+        self.DSSError()?;
+        let res_cnt = unsafe { *self.CountPtr_PByte } as usize;
+        Ok(self.GRView(self.GRSlice(unsafe { *self.DataPtr_PByte }, res_cnt)))
+    }
+
+    pub fn GetInt8ArrayGRInto(&self, out: &mut Vec<i8>) -> Result<(), DSSError> {
+        self.DSSError()?;
+        let res_cnt = unsafe { *self.CountPtr_PByte } as usize;
+        out.clear();
+        out.extend_from_slice(self.GRSlice(unsafe { *self.DataPtr_PByte }, res_cnt));
+        Ok(())
+    }
+
     pub fn GetFloat64ArrayGR(&self) -> Result<Box::<[f64]>, DSSError> {
         self.DSSError()?;
         let res_cnt = unsafe { *self.CountPtr_PDouble } as usize;
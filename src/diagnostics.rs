@@ -0,0 +1,282 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Diagnostics for solutions that do not converge.
+//!
+//! ```no_run
+//! use altdss::common::DSSContext;
+//! use altdss::classic::IDSS;
+//! use altdss::diagnostics::ConvergenceReport;
+//!
+//! let ctx = DSSContext::prime();
+//! let dss = IDSS::new(&ctx);
+//! dss.Command("redirect master.dss").unwrap();
+//! let _ = dss.ActiveCircuit.Solution.Solve();
+//! if !dss.ActiveCircuit.Solution.Get_Converged().unwrap() {
+//!     let report = ConvergenceReport::FromCircuit(&dss).unwrap().WithVoltageProbe(&dss).unwrap();
+//!     println!("{}", report);
+//! }
+//! ```
+
+#![allow(non_snake_case)]
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use crate::classic::IDSS;
+use crate::common::{DSSError, DSSResultExt};
+
+/// Number of entries kept in `ConvergenceReport::voltage_changes`
+const MAX_VOLTAGE_CHANGES: usize = 10;
+
+/// Likely cause of a convergence problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvergenceCause {
+    /// The solution converged and the controls settled
+    None,
+    /// The controls did not settle within `MaxControlIterations`, e.g. regulators or
+    /// capacitors acting against each other
+    ControlHunting,
+    /// The power flow did not converge within `MaxIterations`
+    PowerFlowDivergence,
+    /// The power flow did not converge and parts of the circuit are isolated from the sources
+    Islanding,
+}
+
+/// Change in the voltage magnitude of a node during an extra power-flow iteration
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeVoltageChange {
+    pub node: String,
+    /// Voltage magnitude (pu) before the iteration
+    pub before: f64,
+    /// Voltage magnitude (pu) after the iteration
+    pub after: f64,
+}
+
+/// A node with a voltage outside of the normal range (`ISettings::Get_NormVminpu` to
+/// `ISettings::Get_NormVmaxpu`), with the circuit elements connected to its bus
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbnormalVoltage {
+    pub node: String,
+    pub voltage_pu: f64,
+    pub elements: Vec<String>,
+}
+
+/// Data collected from the engine about the latest solution
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvergenceReport {
+    pub converged: bool,
+    pub iterations: i32,
+    pub max_iterations: i32,
+    pub most_iterations_done: i32,
+    pub control_iterations: i32,
+    pub max_control_iterations: i32,
+    /// Entries of the solution event log (`ISolution::EventLog`)
+    pub event_log: Vec<String>,
+    /// Number of control actions per element in the event log, most active first
+    pub control_actions: Vec<(String, usize)>,
+    /// Largest voltage changes in an extra iteration; only filled by
+    /// `ConvergenceReport::WithVoltageProbe`, for solutions that did not converge
+    pub voltage_changes: Vec<NodeVoltageChange>,
+    pub isolated_branches: Vec<String>,
+    pub isolated_loads: Vec<String>,
+    /// Buses connected to the isolated branches
+    pub isolated_buses: Vec<String>,
+    pub abnormal_voltages: Vec<AbnormalVoltage>,
+    pub cause: ConvergenceCause,
+}
+
+impl ConvergenceReport {
+    /// Collects the diagnostics for the latest solution of the active circuit, without
+    /// solving it again. The active circuit element is not preserved.
+    pub fn FromCircuit(dss: &IDSS) -> Result<Self, DSSError> {
+        let circuit = &dss.ActiveCircuit;
+        let solution = &circuit.Solution;
+        let mut report = Self {
+            converged: solution.Get_Converged().origin("ConvergenceReport::FromCircuit")?,
+            iterations: solution.Iterations().origin("ConvergenceReport::FromCircuit")?,
+            max_iterations: solution.Get_MaxIterations().origin("ConvergenceReport::FromCircuit")?,
+            most_iterations_done: solution.MostIterationsDone().origin("ConvergenceReport::FromCircuit")?,
+            control_iterations: solution.Get_ControlIterations().origin("ConvergenceReport::FromCircuit")?,
+            max_control_iterations: solution.Get_MaxControlIterations().origin("ConvergenceReport::FromCircuit")?,
+            event_log: solution.EventLog().origin("ConvergenceReport::FromCircuit")?.into_vec(),
+            control_actions: Vec::new(),
+            voltage_changes: Vec::new(),
+            isolated_branches: circuit.Topology.AllIsolatedBranches().origin("ConvergenceReport::FromCircuit")?.into_vec(),
+            isolated_loads: circuit.Topology.AllIsolatedLoads().origin("ConvergenceReport::FromCircuit")?.into_vec(),
+            isolated_buses: Vec::new(),
+            abnormal_voltages: Vec::new(),
+            cause: ConvergenceCause::None,
+        };
+        // The engine returns a single "NONE" entry when the lists are empty
+        report.isolated_branches.retain(|name| !name.is_empty() && !name.eq_ignore_ascii_case("NONE"));
+        report.isolated_loads.retain(|name| !name.is_empty() && !name.eq_ignore_ascii_case("NONE"));
+        report.control_actions = count_control_actions(&report.event_log);
+
+        // Buses of the isolated branches and elements connected to each bus
+        let mut isolated_buses = HashSet::new();
+        let mut bus_elements: HashMap<String, Vec<String>> = HashMap::new();
+        for idx in 0..circuit.NumCktElements().origin("ConvergenceReport::FromCircuit")? {
+            let element = circuit.get_CktElementsi(idx).origin("ConvergenceReport::FromCircuit")?;
+            let name = element.Name().origin("ConvergenceReport::FromCircuit")?;
+            let isolated = report.isolated_branches.iter().any(|branch| branch.eq_ignore_ascii_case(&name));
+            for bus in element.Get_BusNames().origin("ConvergenceReport::FromCircuit")?.iter() {
+                let bus = bus.split('.').next().unwrap_or_default().to_ascii_lowercase();
+                if isolated && isolated_buses.insert(bus.clone()) {
+                    report.isolated_buses.push(bus.clone());
+                }
+                bus_elements.entry(bus).or_default().push(name.clone());
+            }
+        }
+
+        let node_names = circuit.AllNodeNames().origin("ConvergenceReport::FromCircuit")?;
+        let volts_pu = circuit.AllBusVmagPu().origin("ConvergenceReport::FromCircuit")?;
+        let vmin = circuit.Settings.Get_NormVminpu().origin("ConvergenceReport::FromCircuit")?;
+        let vmax = circuit.Settings.Get_NormVmaxpu().origin("ConvergenceReport::FromCircuit")?;
+        for (node, voltage_pu) in node_names.iter().zip(volts_pu.iter()) {
+            // Nodes without a base voltage report zero
+            if *voltage_pu == 0.0 || (vmin..=vmax).contains(voltage_pu) {
+                continue;
+            }
+            let bus = node.split('.').next().unwrap_or_default().to_ascii_lowercase();
+            report.abnormal_voltages.push(AbnormalVoltage {
+                node: node.clone(),
+                voltage_pu: *voltage_pu,
+                elements: bus_elements.get(&bus).cloned().unwrap_or_default(),
+            });
+        }
+
+        report.cause = if !report.converged && (!report.isolated_branches.is_empty() || !report.isolated_loads.is_empty()) {
+            ConvergenceCause::Islanding
+        } else if report.control_iterations >= report.max_control_iterations {
+            ConvergenceCause::ControlHunting
+        } else if !report.converged {
+            ConvergenceCause::PowerFlowDivergence
+        } else {
+            ConvergenceCause::None
+        };
+        Ok(report)
+    }
+
+    /// For a solution that did not converge, runs one extra power-flow iteration
+    /// (`ISolution::SolveNoControl` with `MaxIterations` set to 1) to fill
+    /// `voltage_changes` with the nodes with the largest voltage changes. This changes
+    /// the state of the solution; `MaxIterations` is restored afterwards.
+    pub fn WithVoltageProbe(mut self, dss: &IDSS) -> Result<Self, DSSError> {
+        if self.converged {
+            return Ok(self);
+        }
+        let circuit = &dss.ActiveCircuit;
+        let solution = &circuit.Solution;
+        let node_names = circuit.AllNodeNames().origin("ConvergenceReport::WithVoltageProbe")?;
+        let before = circuit.AllBusVmagPu().origin("ConvergenceReport::WithVoltageProbe")?.to_vec();
+        let max_iterations = solution.Get_MaxIterations().origin("ConvergenceReport::WithVoltageProbe")?;
+        solution.Set_MaxIterations(1).origin("ConvergenceReport::WithVoltageProbe")?;
+        // Not converging in a single iteration is expected here, so errors are ignored
+        let _ = solution.SolveNoControl();
+        solution.Set_MaxIterations(max_iterations).origin("ConvergenceReport::WithVoltageProbe")?;
+        let after = circuit.AllBusVmagPu().origin("ConvergenceReport::WithVoltageProbe")?;
+        let mut changes: Vec<NodeVoltageChange> = node_names.iter().zip(before.iter()).zip(after.iter())
+            .map(|((node, before), after)| NodeVoltageChange {
+                node: node.clone(),
+                before: *before,
+                after: *after,
+            })
+            .collect();
+        changes.sort_by(|a, b| (b.after - b.before).abs().total_cmp(&(a.after - a.before).abs()));
+        changes.truncate(MAX_VOLTAGE_CHANGES);
+        self.voltage_changes = changes;
+        Ok(self)
+    }
+
+    /// Short explanation of the likely cause
+    pub fn Explanation(&self) -> String {
+        match self.cause {
+            ConvergenceCause::None => "The solution converged".to_string(),
+            ConvergenceCause::ControlHunting => {
+                let elements: Vec<&str> = self.control_actions.iter().take(3).map(|(name, _)| name.as_str()).collect();
+                format!(
+                    "The controls did not settle after {} control iterations (max. {}); most active: {}",
+                    self.control_iterations,
+                    self.max_control_iterations,
+                    if elements.is_empty() { "(no events logged)".to_string() } else { elements.join(", ") }
+                )
+            },
+            ConvergenceCause::PowerFlowDivergence => format!(
+                "The power flow did not converge in {} iterations (max. {}); check the largest voltage changes",
+                self.most_iterations_done,
+                self.max_iterations
+            ),
+            ConvergenceCause::Islanding => format!(
+                "The power flow did not converge with {} isolated branches and {} isolated loads",
+                self.isolated_branches.len(),
+                self.isolated_loads.len()
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ConvergenceReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:?}: {}", self.cause, self.Explanation())?;
+        writeln!(f, "Converged: {}, iterations: {} (most: {}, max. {}), control iterations: {} (max. {})",
+            self.converged, self.iterations, self.most_iterations_done, self.max_iterations,
+            self.control_iterations, self.max_control_iterations
+        )?;
+        if !self.isolated_branches.is_empty() || !self.isolated_loads.is_empty() {
+            writeln!(f, "Isolated branches: {}", self.isolated_branches.join(", "))?;
+            writeln!(f, "Isolated loads: {}", self.isolated_loads.join(", "))?;
+            writeln!(f, "Isolated buses: {}", self.isolated_buses.join(", "))?;
+        }
+        if !self.control_actions.is_empty() {
+            writeln!(f, "Control actions:")?;
+            for (element, count) in &self.control_actions {
+                writeln!(f, "  {}: {}", element, count)?;
+            }
+        }
+        if !self.voltage_changes.is_empty() {
+            writeln!(f, "Largest voltage changes:")?;
+            for change in &self.voltage_changes {
+                writeln!(f, "  {}: {:.5} -> {:.5} pu", change.node, change.before, change.after)?;
+            }
+        }
+        if !self.abnormal_voltages.is_empty() {
+            writeln!(f, "Abnormal voltages:")?;
+            for node in &self.abnormal_voltages {
+                writeln!(f, "  {}: {:.5} pu ({})", node.node, node.voltage_pu, node.elements.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Counts the entries of the event log per element, from the `Element=` fields
+fn count_control_actions(event_log: &[String]) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for entry in event_log {
+        let Some(element) = entry.split(',').find_map(|field| {
+            let (key, value) = field.split_once('=')?;
+            key.trim().eq_ignore_ascii_case("Element").then(|| value.trim())
+        }) else {
+            continue;
+        };
+        match counts.iter_mut().find(|(name, _)| name.eq_ignore_ascii_case(element)) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((element.to_string(), 1)),
+        }
+    }
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    counts
+}
@@ -0,0 +1,445 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Differences between two circuits, from live DSS contexts or JSON snapshots
+//! (`json::CircuitModel`). Requires the `serde` feature.
+//!
+//! ```no_run
+//! use altdss::classic::IDSS;
+//! use altdss::builder::DSSContextBuilder;
+//! use altdss::diff::{CircuitDiff, DiffOptions};
+//!
+//! let ctx1 = DSSContextBuilder::modern().Build().unwrap();
+//! let ctx2 = DSSContextBuilder::modern().Build().unwrap();
+//! let (dss1, dss2) = (IDSS::new(&ctx1), IDSS::new(&ctx2));
+//! dss1.Command("redirect feeder_v1/master.dss").unwrap();
+//! dss2.Command("redirect feeder_v2/master.dss").unwrap();
+//! let diff = CircuitDiff::FromContexts(&dss1, &dss2, &DiffOptions::new()).unwrap();
+//! println!("{}", diff);
+//! ```
+//!
+//! Element and bus names are compared without case, as in the engine. Numbers are
+//! considered equal when `|a - b| <= tolerance * max(1, |a|, |b|)`.
+
+#![allow(non_snake_case)]
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use serde_json::{Map, Value};
+use crate::classic::{DSSJSONFlags, IDSS};
+use crate::common::{DSSError, DSSResultExt};
+use crate::json::{json_error, CircuitModel, DSSJSONOptions, DSSValueText, ObjectModel};
+
+/// Options for the diffs from live contexts
+#[derive(Debug, Clone)]
+pub struct DiffOptions {
+    tolerance: f64,
+    json_options: DSSJSONOptions,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-9,
+            json_options: DSSJSONOptions::from_flags(&[DSSJSONFlags::Full, DSSJSONFlags::SkipRedundant]),
+        }
+    }
+}
+
+impl DiffOptions {
+    /// Relative tolerance of 1e-9; all the properties are exported, except the redundant ones
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn Tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Options for the JSON export of each circuit
+    pub fn JSONOptions(mut self, options: DSSJSONOptions) -> Self {
+        self.json_options = options;
+        self
+    }
+}
+
+/// A property value that differs; `None` when the property is absent on that side
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyChange {
+    pub property: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementChange {
+    pub name: String,
+    pub properties: Vec<PropertyChange>,
+}
+
+/// Differences in the elements of a DSS class
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassDiff {
+    pub class_name: String,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<ElementChange>,
+}
+
+/// An element connected to a different set of buses
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopologyChange {
+    /// Full name of the element, e.g. `"Line.l1"`
+    pub element: String,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusBaseChange {
+    pub bus: String,
+    pub before: Option<f64>,
+    pub after: Option<f64>,
+}
+
+/// Differences between two circuits
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitDiff {
+    /// Only the classes with differences are listed
+    pub classes: Vec<ClassDiff>,
+    /// Changes in the buses connected to each element present in both circuits
+    pub topology: Vec<TopologyChange>,
+    pub buses_added: Vec<String>,
+    pub buses_removed: Vec<String>,
+    /// Changes in the base voltages (kV, line-to-neutral) of the buses present in both circuits
+    pub bus_bases: Vec<BusBaseChange>,
+    /// Voltage bases (`Set VoltageBases`), if different; only available from live contexts
+    pub voltage_bases: Option<(Vec<f64>, Vec<f64>)>,
+}
+
+impl ClassDiff {
+    /// Compares two lists of objects of a class
+    pub fn new(class_name: impl Into<String>, before: &[ObjectModel], after: &[ObjectModel], tolerance: f64) -> Self {
+        Self::from_indexed(class_name.into(), before, after, &objects_by_name(before), &objects_by_name(after), tolerance)
+    }
+
+    /// Same as `new`, with the objects already indexed by `objects_by_name`
+    fn from_indexed(
+        class_name: String,
+        before: &[ObjectModel],
+        after: &[ObjectModel],
+        before_by_name: &HashMap<String, &ObjectModel>,
+        after_by_name: &HashMap<String, &ObjectModel>,
+        tolerance: f64
+    ) -> Self {
+        let mut diff = Self {
+            class_name: class_name,
+            added: Vec::new(),
+            removed: before.iter()
+                .filter(|obj| !after_by_name.contains_key(&obj.name.to_ascii_lowercase()))
+                .map(|obj| obj.name.clone())
+                .collect(),
+            changed: Vec::new(),
+        };
+        for obj in after {
+            let Some(old) = before_by_name.get(&obj.name.to_ascii_lowercase()) else {
+                diff.added.push(obj.name.clone());
+                continue;
+            };
+            let old_properties = properties_by_name(&old.properties);
+            let new_properties = properties_by_name(&obj.properties);
+            let mut properties: Vec<PropertyChange> = Vec::new();
+            for (prop, value) in obj.properties.iter() {
+                let old_value = old_properties.get(&prop.to_ascii_lowercase()).copied();
+                if !old_value.is_some_and(|old_value| values_equal(old_value, value, tolerance)) {
+                    properties.push(PropertyChange {
+                        property: prop.clone(),
+                        before: old_value.cloned(),
+                        after: Some(value.clone()),
+                    });
+                }
+            }
+            for (prop, value) in old.properties.iter() {
+                if !new_properties.contains_key(&prop.to_ascii_lowercase()) {
+                    properties.push(PropertyChange {
+                        property: prop.clone(),
+                        before: Some(value.clone()),
+                        after: None,
+                    });
+                }
+            }
+            if !properties.is_empty() {
+                diff.changed.push(ElementChange {
+                    name: obj.name.clone(),
+                    properties: properties,
+                });
+            }
+        }
+        diff
+    }
+
+    /// Compares the objects of a class in two live contexts, through `IActiveClass::ToJSON`
+    pub fn FromContexts(before: &IDSS, after: &IDSS, class_name: &str, options: &DiffOptions) -> Result<Self, DSSError> {
+        let before_objs = class_objects(before, class_name, options).origin("ClassDiff::FromContexts")?;
+        let after_objs = class_objects(after, class_name, options).origin("ClassDiff::FromContexts")?;
+        Ok(Self::new(class_name, &before_objs, &after_objs, options.tolerance))
+    }
+
+    pub fn IsEmpty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl CircuitDiff {
+    /// Compares two circuit snapshots
+    pub fn new(before: &CircuitModel, after: &CircuitModel, tolerance: f64) -> Result<Self, DSSError> {
+        let mut class_names: Vec<&str> = after.Classes();
+        let mut seen_classes: HashSet<String> = class_names.iter().map(|name| name.to_ascii_lowercase()).collect();
+        for class_name in before.Classes() {
+            if seen_classes.insert(class_name.to_ascii_lowercase()) {
+                class_names.push(class_name);
+            }
+        }
+
+        let mut classes = Vec::new();
+        let mut topology = Vec::new();
+        let mut buses_before: Vec<String> = Vec::new();
+        let mut buses_after: Vec<String> = Vec::new();
+        for class_name in class_names {
+            let before_objs = before.Objects(class_name).origin("CircuitDiff::new")?;
+            let after_objs = after.Objects(class_name).origin("CircuitDiff::new")?;
+            let before_by_name = objects_by_name(&before_objs);
+            let after_by_name = objects_by_name(&after_objs);
+            for obj in &before_objs {
+                buses_before.extend(element_buses(obj));
+            }
+            for obj in &after_objs {
+                let connected = element_buses(obj);
+                buses_after.extend(connected.iter().cloned());
+                let Some(old) = before_by_name.get(&obj.name.to_ascii_lowercase()) else {
+                    continue;
+                };
+                let old_connected = element_buses(old);
+                if old_connected != connected {
+                    topology.push(TopologyChange {
+                        element: format!("{}.{}", class_name, obj.name),
+                        before: old_connected,
+                        after: connected,
+                    });
+                }
+            }
+            let diff = ClassDiff::from_indexed(class_name.to_string(), &before_objs, &after_objs, &before_by_name, &after_by_name, tolerance);
+            if !diff.IsEmpty() {
+                classes.push(diff);
+            }
+        }
+        buses_before.extend(before.buses.iter().map(|bus| bus.name.to_ascii_lowercase()));
+        buses_after.extend(after.buses.iter().map(|bus| bus.name.to_ascii_lowercase()));
+        let known_before: HashSet<&String> = buses_before.iter().collect();
+        let known_after: HashSet<&String> = buses_after.iter().collect();
+
+        let (before_kVBases, after_kVBases) = (bus_kVBases(before), bus_kVBases(after));
+        let mut bus_bases = Vec::new();
+        for bus in &after.buses {
+            let name = bus.name.to_ascii_lowercase();
+            let old = before_kVBases.get(&name).copied().flatten();
+            let new = after_kVBases.get(&name).copied().flatten();
+            let same = match (old, new) {
+                (Some(old), Some(new)) => numbers_equal(old, new, tolerance),
+                (old, new) => old.is_none() && new.is_none(),
+            };
+            if known_before.contains(&name) && !same {
+                bus_bases.push(BusBaseChange {
+                    bus: bus.name.clone(),
+                    before: old,
+                    after: new,
+                });
+            }
+        }
+
+        Ok(Self {
+            classes: classes,
+            topology: topology,
+            buses_added: unique(buses_after.iter().filter(|bus| !known_before.contains(bus))),
+            buses_removed: unique(buses_before.iter().filter(|bus| !known_after.contains(bus))),
+            bus_bases: bus_bases,
+            voltage_bases: None,
+        })
+    }
+
+    /// Compares the active circuits of two live contexts, through `ICircuit::ToJSON`
+    pub fn FromContexts(before: &IDSS, after: &IDSS, options: &DiffOptions) -> Result<Self, DSSError> {
+        let before_model = CircuitModel::FromCircuit(before, options.json_options).origin("CircuitDiff::FromContexts")?;
+        let after_model = CircuitModel::FromCircuit(after, options.json_options).origin("CircuitDiff::FromContexts")?;
+        let mut diff = Self::new(&before_model, &after_model, options.tolerance)?;
+        let before_bases = before.ActiveCircuit.Settings.Get_VoltageBases().origin("CircuitDiff::FromContexts")?.to_vec();
+        let after_bases = after.ActiveCircuit.Settings.Get_VoltageBases().origin("CircuitDiff::FromContexts")?.to_vec();
+        let same_bases = before_bases.len() == after_bases.len()
+            && before_bases.iter().zip(after_bases.iter()).all(|(a, b)| numbers_equal(*a, *b, options.tolerance));
+        if !same_bases {
+            diff.voltage_bases = Some((before_bases, after_bases));
+        }
+        Ok(diff)
+    }
+
+    pub fn IsEmpty(&self) -> bool {
+        self.classes.is_empty()
+            && self.topology.is_empty()
+            && self.buses_added.is_empty()
+            && self.buses_removed.is_empty()
+            && self.bus_bases.is_empty()
+            && self.voltage_bases.is_none()
+    }
+}
+
+impl fmt::Display for ClassDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}:", self.class_name)?;
+        for name in &self.added {
+            writeln!(f, "  + {}", name)?;
+        }
+        for name in &self.removed {
+            writeln!(f, "  - {}", name)?;
+        }
+        for element in &self.changed {
+            let changes: Vec<String> = element.properties.iter()
+                .map(|change| format!("{} {} -> {}", change.property, value_text(&change.before), value_text(&change.after)))
+                .collect();
+            writeln!(f, "  ~ {}: {}", element.name, changes.join("; "))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for CircuitDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.IsEmpty() {
+            return writeln!(f, "No differences");
+        }
+        for class in &self.classes {
+            write!(f, "{}", class)?;
+        }
+        if !self.topology.is_empty() {
+            writeln!(f, "Topology:")?;
+            for change in &self.topology {
+                writeln!(f, "  ~ {}: {} -> {}", change.element, change.before.join(", "), change.after.join(", "))?;
+            }
+        }
+        if !self.buses_added.is_empty() || !self.buses_removed.is_empty() || !self.bus_bases.is_empty() {
+            writeln!(f, "Buses:")?;
+            for bus in &self.buses_added {
+                writeln!(f, "  + {}", bus)?;
+            }
+            for bus in &self.buses_removed {
+                writeln!(f, "  - {}", bus)?;
+            }
+            for change in &self.bus_bases {
+                let kV = |value: Option<f64>| value.map_or("(none)".to_string(), |value| value.to_string());
+                writeln!(f, "  ~ {}: kVBase {} -> {}", change.bus, kV(change.before), kV(change.after))?;
+            }
+        }
+        if let Some((before, after)) = &self.voltage_bases {
+            writeln!(f, "Voltage bases: {:?} -> {:?}", before, after)?;
+        }
+        Ok(())
+    }
+}
+
+fn class_objects(dss: &IDSS, class_name: &str, options: &DiffOptions) -> Result<Vec<ObjectModel>, DSSError> {
+    dss.SetActiveClass(class_name)?;
+    let json = dss.ActiveClass.ToJSON(options.json_options.Bits())?;
+    serde_json::from_str(&json).map_err(json_error)
+}
+
+/// Objects by lowercase name; for repeated names, the first object is kept
+fn objects_by_name(objs: &[ObjectModel]) -> HashMap<String, &ObjectModel> {
+    let mut by_name = HashMap::with_capacity(objs.len());
+    for obj in objs {
+        by_name.entry(obj.name.to_ascii_lowercase()).or_insert(obj);
+    }
+    by_name
+}
+
+/// Property values by lowercase name
+fn properties_by_name(properties: &Map<String, Value>) -> HashMap<String, &Value> {
+    properties.iter().map(|(name, value)| (name.to_ascii_lowercase(), value)).collect()
+}
+
+/// Base voltage of each bus, by lowercase name; for repeated names, the first bus is kept
+fn bus_kVBases(model: &CircuitModel) -> HashMap<String, Option<f64>> {
+    let mut kVBases = HashMap::with_capacity(model.buses.len());
+    for bus in &model.buses {
+        let kVBase = bus.properties.iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("kVBase"))
+            .and_then(|(_, value)| value.as_f64());
+        kVBases.entry(bus.name.to_ascii_lowercase()).or_insert(kVBase);
+    }
+    kVBases
+}
+
+/// Names of the buses connected to an element, without the nodes, in lowercase
+fn element_buses(obj: &ObjectModel) -> Vec<String> {
+    let mut buses = Vec::new();
+    for (prop, value) in obj.properties.iter() {
+        if !["bus", "bus1", "bus2", "buses"].iter().any(|name| prop.eq_ignore_ascii_case(name)) {
+            continue;
+        }
+        let items = match value {
+            Value::Array(items) => items.iter().filter_map(Value::as_str).collect(),
+            Value::String(text) => vec![text.as_str()],
+            _ => Vec::new(),
+        };
+        for item in items {
+            let bus = item.split('.').next().unwrap_or_default().trim().to_ascii_lowercase();
+            if !bus.is_empty() {
+                buses.push(bus);
+            }
+        }
+    }
+    buses
+}
+
+/// Removes the repeated names, keeping the first occurrence
+fn unique<'s>(names: impl Iterator<Item = &'s String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    names.filter(|name| seen.insert(*name)).cloned().collect()
+}
+
+fn numbers_equal(a: f64, b: f64, tolerance: f64) -> bool {
+    (a - b).abs() <= tolerance * a.abs().max(b.abs()).max(1.0)
+}
+
+fn values_equal(a: &Value, b: &Value, tolerance: f64) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => match (a.as_f64(), b.as_f64()) {
+            (Some(a), Some(b)) => numbers_equal(a, b, tolerance),
+            _ => a == b,
+        },
+        (Value::String(a), Value::String(b)) => a.eq_ignore_ascii_case(b),
+        (Value::Array(a), Value::Array(b)) => a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| values_equal(a, b, tolerance)),
+        (Value::Object(a), Value::Object(b)) => a.len() == b.len() && a.iter().all(|(key, a)| b.get(key).is_some_and(|b| values_equal(a, b, tolerance))),
+        _ => a == b,
+    }
+}
+
+fn value_text(value: &Option<Value>) -> String {
+    match value {
+        // Only for the report, so values without a DSS equivalent are shown as JSON
+        Some(value) => DSSValueText(value).unwrap_or_else(|_| value.to_string()),
+        None => "(unset)".to_string(),
+    }
+}
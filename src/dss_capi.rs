@@ -18,3 +18,104 @@
 // #![allow(non_upper_case_globals)]
 #![allow(nonstandard_style)]
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+use crate::common::DSSError;
+#[cfg(feature = "runtime-loading")]
+use crate::common::DSSErrorKind;
+#[cfg(feature = "runtime-loading")]
+use std::{env, ffi::OsStr, sync::OnceLock};
+
+/// Environment variable checked for the path of the DSS C-API library when
+/// the library is loaded implicitly, i.e. if `load_library` was not called
+/// before the first use of the API.
+#[cfg(feature = "runtime-loading")]
+pub const LIBRARY_PATH_ENV_VAR: &str = "DSS_CAPI_LIBRARY";
+
+#[cfg(feature = "runtime-loading")]
+static LIBRARY: OnceLock<DSSCApiLibrary> = OnceLock::new();
+
+/// Loads the DSS C-API library from the provided path and resolves all its functions.
+///
+/// This must be called before any context is created. Only a single library can be
+/// loaded in the process; loading another one after that is reported as an error.
+///
+/// If a function expected by the bindings is missing from the library (e.g. an
+/// older DSS C-API version), the error message includes the name of the function.
+#[cfg(feature = "runtime-loading")]
+pub fn load_library<P: AsRef<OsStr>>(path: P) -> Result<(), DSSError> {
+    if LIBRARY.get().is_some() {
+        return Err(DSSError::wrapper(DSSErrorKind::Library, "The DSS C-API library was already loaded".to_string()));
+    }
+    let path = path.as_ref();
+    let library = unsafe { libloading::Library::new(path) }.map_err(|err| DSSError::wrapper(
+        DSSErrorKind::Library,
+        format!("Could not load the DSS C-API library from \"{}\": {}", path.to_string_lossy(), err)
+    ).with_source(err))?;
+    let library = unsafe { DSSCApiLibrary::load(library) }?;
+    LIBRARY.set(library).map_err(|_| DSSError::wrapper(
+        DSSErrorKind::Library,
+        "The DSS C-API library was already loaded".to_string()
+    ))
+}
+
+/// Loads the DSS C-API library from the path in the `DSS_CAPI_LIBRARY` environment
+/// variable or, if not set, using the default library file name for the platform
+/// (e.g. `libdss_capi.so`), leaving the search to the system loader.
+///
+/// Does nothing if a library was already loaded.
+#[cfg(feature = "runtime-loading")]
+pub fn load_library_from_env() -> Result<(), DSSError> {
+    if LIBRARY.get().is_some() {
+        return Ok(());
+    }
+    match env::var_os(LIBRARY_PATH_ENV_VAR) {
+        Some(path) => load_library(path),
+        None => load_library(libloading::library_filename("dss_capi")),
+    }
+}
+
+/// Returns whether the DSS C-API library was already loaded in this process.
+#[cfg(feature = "runtime-loading")]
+pub fn is_library_loaded() -> bool {
+    LIBRARY.get().is_some()
+}
+
+/// Loads the library implicitly, as in `load_library_from_env`, if it was not loaded yet.
+/// The context constructors call this first, so that a missing library is reported as
+/// an error from them instead of a panic in the first call to the API.
+#[cfg(feature = "runtime-loading")]
+pub(crate) fn ensure_library() -> Result<(), DSSError> {
+    match load_library_from_env() {
+        // Another thread could have loaded the library in the meantime
+        Err(_) if LIBRARY.get().is_some() => Ok(()),
+        result => result,
+    }
+}
+
+/// The library is linked at build time, so there is nothing to load.
+#[cfg(not(feature = "runtime-loading"))]
+#[inline]
+pub(crate) fn ensure_library() -> Result<(), DSSError> {
+    Ok(())
+}
+
+#[cfg(feature = "runtime-loading")]
+fn library() -> &'static DSSCApiLibrary {
+    if let Some(library) = LIBRARY.get() {
+        return library;
+    }
+    // Only reachable when calling the functions from this module directly, before
+    // creating any context; the safe API goes through `ensure_library` first.
+    if let Err(err) = ensure_library() {
+        panic!("{}", err);
+    }
+    LIBRARY.get().unwrap()
+}
+
+#[cfg(feature = "runtime-loading")]
+fn missing_symbol(name: &str, err: libloading::Error) -> DSSError {
+    DSSError::wrapper(
+        DSSErrorKind::Library,
+        format!("Could not find the function \"{}\" in the DSS C-API library: {}", name, err)
+    ).with_source(err)
+}
@@ -0,0 +1,163 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(non_snake_case)]
+
+use crate::callbacks::ClearEpoch;
+use crate::classic::{ICircuit, ICktElement, IDSSElement};
+use crate::collections::DSSClassCollection;
+use crate::common::{DSSContext, DSSError, DSSErrorKind, DSSResultExt};
+use crate::obj::{DSSObject, IObj};
+
+/// Stable reference to a DSS element, by class and name.
+///
+/// The classic interfaces act on the active element of the context, so code that
+/// activates other elements in between calls ends up reading the wrong one. An
+/// `ElementRef` activates its element each time one of the interfaces is used,
+/// passing it to a closure:
+///
+/// ```no_run
+/// use altdss::common::DSSContext;
+/// use altdss::classic::IDSS;
+/// use altdss::element::ElementRef;
+///
+/// let ctx = DSSContext::prime();
+/// let dss = IDSS::new(&ctx);
+/// let load = ElementRef::new(&ctx, "Load", "ld1").unwrap();
+/// let kW = load.WithInterface(&dss.ActiveCircuit.Loads, |load| load.Get_kW()).unwrap();
+/// let powers = load.WithCktElement(|element| element.Powers()).unwrap();
+/// ```
+///
+/// Activating another element inside the closure affects the rest of the closure,
+/// as usual for the classic API, but not the later calls.
+///
+/// The element is looked up by name on each access, so an error is returned if it
+/// does not exist anymore. Since the engine cannot remove single elements, a disabled
+/// element (e.g. with the `remove` command or `DSSObject::Disable`) is treated as
+/// removed, returning `DSSErrorKind::StaleReference`. Once the circuit is cleared, the
+/// reference is permanently invalid, even if an element with the same name is
+/// created later.
+pub struct ElementRef<'a> {
+    ctx: &'a DSSContext,
+    circuit: ICircuit<'a>,
+    class_name: String,
+    name: String,
+    epoch: ClearEpoch,
+}
+
+unsafe impl<'a> Send for ElementRef <'a> {
+}
+impl<'a> ElementRef<'a> {
+    /// Creates a reference to an existing element
+    pub fn new(ctx: &'a DSSContext, class_name: impl AsRef<str>, name: impl AsRef<str>) -> Result<Self, DSSError> {
+        let obj = IObj::new(ctx).Get(class_name, name).origin("ElementRef::new")?;
+        Self::from_object(ctx, &obj).origin("ElementRef::new")
+    }
+
+    /// Creates a reference to an element of a class by index, in the range `1..=Count()`.
+    /// The reference keeps the name of the element, not the index.
+    pub fn from_index(ctx: &'a DSSContext, class_name: impl AsRef<str>, idx: i32) -> Result<Self, DSSError> {
+        let obj = IObj::new(ctx).Class(class_name)?.GetByIndex(idx).origin("ElementRef::from_index")?;
+        Self::from_object(ctx, &obj).origin("ElementRef::from_index")
+    }
+
+    /// Creates a reference to the active circuit element
+    pub fn from_active(ctx: &'a DSSContext) -> Result<Self, DSSError> {
+        let full_name = ICircuit::new(ctx).ActiveCktElement.Name().origin("ElementRef::from_active")?;
+        let obj = IObj::new(ctx).GetByFullName(full_name).origin("ElementRef::from_active")?;
+        Self::from_object(ctx, &obj).origin("ElementRef::from_active")
+    }
+
+    fn from_object(ctx: &'a DSSContext, obj: &DSSObject<'a>) -> Result<Self, DSSError> {
+        Ok(Self {
+            ctx: ctx,
+            circuit: ICircuit::new(ctx),
+            class_name: obj.ClassName()?,
+            name: obj.Name()?,
+            epoch: ClearEpoch::current(ctx)?,
+        })
+    }
+
+    pub fn ClassName(&self) -> &str {
+        &self.class_name
+    }
+
+    pub fn Name(&self) -> &str {
+        &self.name
+    }
+
+    /// Full name of the element, e.g. `"Load.ld1"`
+    pub fn FullName(&self) -> String {
+        format!("{}.{}", self.class_name, self.name)
+    }
+
+    /// Returns true if the element still exists, and is enabled, in the circuit it was
+    /// created in
+    pub fn IsValid(&self) -> bool {
+        self.Object().is_ok()
+    }
+
+    /// Generic handle to the element, see `obj::DSSObject`
+    pub fn Object(&self) -> Result<DSSObject<'a>, DSSError> {
+        self.epoch.check(|| format!("reference to \"{}\"", self.FullName())).origin("ElementRef::Object")?;
+        let obj = IObj::new(self.ctx).Get(&self.class_name, &self.name).origin("ElementRef::Object")?;
+        // Only circuit elements can be disabled; resolving the property activates the object
+        let enabled = match obj.PropertyIndex("enabled") {
+            Ok(_) => self.circuit.ActiveCktElement.Get_Enabled().origin("ElementRef::Object")?,
+            Err(err) if err.kind == DSSErrorKind::NotFound => true,
+            Err(err) => return Err(err).origin("ElementRef::Object"),
+        };
+        if !enabled {
+            return Err(DSSError::wrapper(
+                DSSErrorKind::StaleReference,
+                format!("The element \"{}\" was removed (disabled)", self.FullName())
+            )).origin("ElementRef::Object");
+        }
+        Ok(obj)
+    }
+
+    /// Makes the element active, both as the active DSS object/circuit element and in the
+    /// lists of its class
+    pub fn Activate(&self) -> Result<(), DSSError> {
+        self.Object()?.ActivateAllLists().origin("ElementRef::Activate")
+    }
+
+    /// Activates the element and runs a closure with the circuit element interface
+    pub fn WithCktElement<R, F: FnOnce(&ICktElement<'a>) -> Result<R, DSSError>>(&self, f: F) -> Result<R, DSSError> {
+        self.Activate().origin("ElementRef::WithCktElement")?;
+        f(&self.circuit.ActiveCktElement)
+    }
+
+    /// Activates the element and runs a closure with the DSS element interface, which
+    /// also applies to the general DSS objects (e.g. line codes and load shapes)
+    pub fn WithDSSElement<R, F: FnOnce(&IDSSElement<'a>) -> Result<R, DSSError>>(&self, f: F) -> Result<R, DSSError> {
+        self.Activate().origin("ElementRef::WithDSSElement")?;
+        f(&self.circuit.ActiveDSSElement)
+    }
+
+    /// Activates the element and runs a closure with the collection interface of its
+    /// class, e.g. `ILoads` for a load. The interface must match the class of the element.
+    pub fn WithInterface<T: DSSClassCollection, R, F: FnOnce(&T) -> Result<R, DSSError>>(&self, interface: &T, f: F) -> Result<R, DSSError> {
+        if !self.class_name.eq_ignore_ascii_case(T::CLASS_NAME) {
+            return Err(DSSError::wrapper(
+                DSSErrorKind::InvalidArgument,
+                format!("The element \"{}\" is not a {}", self.FullName(), T::CLASS_NAME)
+            )).origin("ElementRef::WithInterface");
+        }
+        self.Activate().origin("ElementRef::WithInterface")?;
+        f(interface)
+    }
+}
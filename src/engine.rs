@@ -0,0 +1,93 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::dss_capi;
+use crate::classic::IDSS;
+use crate::common::{DSSContext, DSSError, DSSErrorKind, DSSResultExt};
+use std::sync::Arc;
+
+/// Owned DSS engine: a DSS context together with the classic API structs bound to it.
+///
+/// Since `IDSS` borrows its `DSSContext`, it cannot be stored side by side with the
+/// context in a struct, returned from a function or moved to another thread. `Engine`
+/// owns both, so it has no lifetime parameter and can be used as `'static`:
+///
+/// ```no_run
+/// use altdss::engine::Engine;
+///
+/// let engine = Engine::new().unwrap();
+/// let handle = std::thread::spawn(move || {
+///     engine.dss().Command("new circuit.test").unwrap();
+///     engine
+/// });
+/// let engine = handle.join().unwrap();
+/// println!("{}", engine.dss().ActiveCircuit.Name().unwrap());
+/// ```
+pub struct Engine {
+    // Declared before `ctx` so that it is dropped first, since it borrows from `ctx`
+    dss: IDSS<'static>,
+    ctx: Arc<DSSContext>,
+}
+
+impl Engine {
+    /// Wraps an existing context.
+    pub fn from_context(ctx: DSSContext) -> Self {
+        let ctx = Arc::new(ctx);
+        // The context is kept alive, at the same address, by the Arc for as long as `dss`
+        // exists. The 'static lifetime is never exposed, see `Engine::dss`.
+        let ctx_ref: &'static DSSContext = unsafe { &*Arc::as_ptr(&ctx) };
+        Self {
+            dss: IDSS::new(ctx_ref),
+            ctx: ctx,
+        }
+    }
+
+    /// Returns an engine for the Prime (default) DSS context.
+    /// See `DSSContext::prime`.
+    pub fn prime() -> Self {
+        Engine::from_context(DSSContext::prime())
+    }
+
+    /// Same as `prime`, returning an error if the library cannot be loaded.
+    /// See `DSSContext::try_prime`.
+    pub fn try_prime() -> Result<Self, DSSError> {
+        Ok(Engine::from_context(DSSContext::try_prime().origin("Engine::try_prime")?))
+    }
+
+    /// Creates an engine with a new, independent DSS context.
+    /// See `IDSS::NewContext` for more details on DSS contexts.
+    pub fn new() -> Result<Self, DSSError> {
+        dss_capi::ensure_library().origin("Engine::new")?;
+        let ctx_ptr = unsafe { dss_capi::ctx_New() };
+        if ctx_ptr.is_null() {
+            return Err(DSSError::wrapper(
+                DSSErrorKind::Context,
+                "Could not create a new DSS Context".to_string()
+            )).origin("Engine::new");
+        }
+        Ok(Engine::from_context(DSSContext::try_new(ctx_ptr).origin("Engine::new")?))
+    }
+
+    /// Returns the classic API structs, bound to the context owned by the engine.
+    pub fn dss(&self) -> &IDSS<'_> {
+        &self.dss
+    }
+
+    /// Returns the context owned by the engine.
+    pub fn ctx(&self) -> &DSSContext {
+        &self.ctx
+    }
+}
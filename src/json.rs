@@ -0,0 +1,240 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed access to the JSON data exported by the engine, following
+//! [AltDSS-Schema](https://github.com/dss-extensions/AltDSS-Schema).
+//! Requires the `serde` feature.
+//!
+//! `CircuitModel` holds a whole circuit, as exported by `ICircuit::ToJSON`, and can be
+//! loaded back into a DSS context:
+//!
+//! ```no_run
+//! use altdss::common::DSSContext;
+//! use altdss::classic::{DSSJSONFlags, IDSS};
+//! use altdss::json::{CircuitModel, DSSJSONOptions};
+//!
+//! let ctx = DSSContext::prime();
+//! let dss = IDSS::new(&ctx);
+//! dss.Command("redirect IEEE13Nodeckt.dss").unwrap();
+//! let options = DSSJSONOptions::new().With(DSSJSONFlags::SkipRedundant);
+//! let model = CircuitModel::FromCircuit(&dss, options).unwrap();
+//!
+//! // Load a copy of the circuit in a new context
+//! let ctx2 = model.ToNewContext().unwrap();
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::classic::{DSSJSONFlags, IDSS};
+use crate::common::{DSSContext, DSSError, DSSErrorKind, DSSFlags, DSSResultExt};
+use crate::builder::DSSContextBuilder;
+use crate::obj::IObj;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Options for the JSON export functions, as a set of `DSSJSONFlags`.
+///
+/// Note that `LowercaseKeys` changes the names of the keys; `CircuitModel` accepts
+/// both forms.
+pub type DSSJSONOptions = DSSFlags<DSSJSONFlags>;
+
+/// A bus from the circuit JSON data
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BusModel {
+    #[serde(alias = "Name")]
+    pub name: String,
+    /// Other properties, e.g. the base voltage and coordinates
+    #[serde(flatten)]
+    pub properties: Map<String, Value>,
+}
+
+/// A DSS object from the circuit JSON data
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ObjectModel {
+    #[serde(alias = "Name")]
+    pub name: String,
+    /// Property values, in the order they are set
+    #[serde(flatten)]
+    pub properties: Map<String, Value>,
+}
+
+/// A whole circuit, as exported by `ICircuit::ToJSON`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CircuitModel {
+    #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    #[serde(alias = "Name")]
+    pub name: String,
+    #[serde(rename = "DefaultBaseFreq", alias = "defaultbasefreq", default, skip_serializing_if = "Option::is_none")]
+    pub default_base_freq: Option<f64>,
+    /// Commands to run after creating the circuit, before the objects
+    #[serde(rename = "PreCommands", alias = "precommands", default, skip_serializing_if = "Vec::is_empty")]
+    pub pre_commands: Vec<String>,
+    /// Commands to run after creating all the objects
+    #[serde(rename = "PostCommands", alias = "postcommands", default, skip_serializing_if = "Vec::is_empty")]
+    pub post_commands: Vec<String>,
+    /// Bus data; not present when exported with `SkipBuses`
+    #[serde(rename = "Bus", alias = "bus", default, skip_serializing_if = "Vec::is_empty")]
+    pub buses: Vec<BusModel>,
+    /// The DSS objects, as lists per class name, in the order exported by the engine.
+    /// Other entries are kept as well, to preserve the data in the round-trips.
+    #[serde(flatten)]
+    pub classes: Map<String, Value>,
+}
+
+impl CircuitModel {
+    /// Exports the active circuit of a context
+    pub fn FromCircuit(dss: &IDSS, options: DSSJSONOptions) -> Result<Self, DSSError> {
+        let json = dss.ActiveCircuit.ToJSON(options.Bits()).origin("CircuitModel::FromCircuit")?;
+        Self::FromJSON(&json)
+    }
+
+    pub fn FromJSON(json: &str) -> Result<Self, DSSError> {
+        serde_json::from_str(json).map_err(json_error).origin("CircuitModel::FromJSON")
+    }
+
+    pub fn ToJSON(&self) -> Result<String, DSSError> {
+        serde_json::to_string(self).map_err(json_error).origin("CircuitModel::ToJSON")
+    }
+
+    /// Names of the DSS classes with objects in the model
+    pub fn Classes(&self) -> Vec<&str> {
+        self.classes.iter().filter(|(_, value)| value.is_array()).map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Objects of a DSS class (case-insensitive), in the order exported by the engine
+    pub fn Objects(&self, class_name: &str) -> Result<Vec<ObjectModel>, DSSError> {
+        let entry = self.classes.iter().find(|(name, value)| value.is_array() && name.eq_ignore_ascii_case(class_name));
+        match entry {
+            Some((_, value)) => serde_json::from_value(value.clone()).map_err(json_error).origin("CircuitModel::Objects"),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Objects of a DSS class, converted to the respective generated type
+    #[cfg(feature = "schema-types")]
+    pub fn ObjectsAs<T: crate::schema::DSSClassType>(&self) -> Result<Vec<T>, DSSError> {
+        let entry = self.classes.iter().find(|(name, value)| value.is_array() && name.eq_ignore_ascii_case(T::CLASS_NAME));
+        match entry {
+            Some((_, value)) => serde_json::from_value(value.clone()).map_err(json_error).origin("CircuitModel::ObjectsAs"),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Clears the context and creates the circuit from the model
+    pub fn Load(&self, dss: &IDSS) -> Result<(), DSSError> {
+        dss.ClearAll().origin("CircuitModel::Load")?;
+        if let Some(freq) = self.default_base_freq {
+            dss.Command(format!("set DefaultBaseFrequency={}", freq)).origin("CircuitModel::Load")?;
+        }
+        dss.NewCircuit(&self.name).origin("CircuitModel::Load")?;
+        for command in &self.pre_commands {
+            dss.Command(command).origin("CircuitModel::Load")?;
+        }
+        for class_name in self.Classes() {
+            for obj in self.Objects(class_name)? {
+                // Names are qualified when exported with `FullNames`
+                let name = obj.name.split_once('.').map_or(obj.name.as_str(), |(_, name)| name);
+                PushObject(dss.ctx, class_name, name, obj.properties.iter()).origin("CircuitModel::Load")?;
+            }
+        }
+        if !self.buses.is_empty() {
+            self.LoadBuses(dss).origin("CircuitModel::Load")?;
+        }
+        for command in &self.post_commands {
+            dss.Command(command).origin("CircuitModel::Load")?;
+        }
+        Ok(())
+    }
+
+    /// Creates a new DSS context, with the modern defaults, and loads the model into it
+    pub fn ToNewContext(&self) -> Result<DSSContext, DSSError> {
+        let ctx = DSSContextBuilder::modern().Build().origin("CircuitModel::ToNewContext")?;
+        self.Load(&IDSS::new(&ctx))?;
+        Ok(ctx)
+    }
+
+    fn LoadBuses(&self, dss: &IDSS) -> Result<(), DSSError> {
+        dss.Command("MakeBusList")?;
+        for bus in &self.buses {
+            let get = |key: &str| bus.properties.iter().find(|(name, _)| name.eq_ignore_ascii_case(key)).and_then(|(_, value)| value.as_f64());
+            if let Some(kVBase) = get("kVBase") {
+                dss.Command(format!("SetkVBase bus={} kVLN={}", bus.name, kVBase))?;
+            }
+            if let (Some(x), Some(y)) = (get("x"), get("y")) {
+                dss.ActiveCircuit.SetActiveBus(&bus.name)?;
+                dss.ActiveCircuit.ActiveBus.Set_x(x)?;
+                dss.ActiveCircuit.ActiveBus.Set_y(y)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Converts a JSON value to the text used for the same value in DSS scripts.
+///
+/// JSON objects have no equivalent in DSS scripts, so these return a
+/// `DSSErrorKind::JSON` error, also when nested in arrays.
+pub fn DSSValueText(value: &Value) -> Result<String, DSSError> {
+    match value {
+        Value::Null => Ok(String::new()),
+        Value::Bool(v) => Ok(v.to_string()),
+        Value::Number(v) => Ok(v.to_string()),
+        Value::String(v) => Ok(v.clone()),
+        Value::Array(items) => {
+            // Matrices use "|" to separate the rows
+            let separator = if items.iter().all(Value::is_array) { " | " } else { " " };
+            let items = items.iter().map(|item| match item {
+                Value::Array(row) => Ok(row.iter().map(DSSValueText).collect::<Result<Vec<_>, _>>()?.join(" ")),
+                _ => DSSValueText(item),
+            }).collect::<Result<Vec<_>, DSSError>>()?;
+            Ok(format!("[{}]", items.join(separator)))
+        },
+        Value::Object(_) => Err(DSSError::wrapper(
+            DSSErrorKind::JSON,
+            format!("JSON objects cannot be used as DSS values: {}", value)
+        )),
+    }
+}
+
+/// Writes a list of property values to an object of a DSS context, in order,
+/// creating the object if it does not exist.
+pub(crate) fn PushObject<'v>(ctx: &DSSContext, class_name: &str, name: &str, values: impl Iterator<Item = (&'v String, &'v Value)>) -> Result<(), DSSError> {
+    let class = IObj::new(ctx).Class(class_name)?;
+    let obj = match class.Get(name) {
+        Ok(obj) => obj,
+        Err(err) if err.kind == DSSErrorKind::NotFound => class.New(name)?,
+        Err(err) => return Err(err),
+    };
+    let mut num_changes = 0;
+    obj.BeginEdit()?;
+    for (prop_name, value) in values {
+        if prop_name.eq_ignore_ascii_case("name") || value.is_null() {
+            continue;
+        }
+        let result = DSSValueText(value).and_then(|text| obj.SetAsString(prop_name.as_str(), text));
+        if let Err(err) = result {
+            let _ = obj.EndEdit(num_changes);
+            return Err(err);
+        }
+        num_changes += 1;
+    }
+    obj.EndEdit(num_changes)
+}
+
+pub(crate) fn json_error(err: serde_json::Error) -> DSSError {
+    DSSError::wrapper(DSSErrorKind::JSON, format!("Invalid JSON data: {}", err)).with_source(err)
+}
@@ -19,6 +19,29 @@
 pub mod dss_capi;
 pub mod common;
 pub mod classic;
+pub mod engine;
+pub mod callbacks;
+pub mod builder;
+pub mod obj;
+pub mod batch;
+pub mod collections;
+pub mod element;
+pub mod circuit;
+pub mod script;
+pub mod timeseries;
+pub mod snapshot;
+pub mod diagnostics;
+pub mod pool;
+#[cfg(feature = "montecarlo")]
+pub mod montecarlo;
+#[cfg(feature = "serde")]
+pub mod json;
+#[cfg(feature = "serde")]
+pub mod meta;
+#[cfg(feature = "serde")]
+pub mod diff;
+#[cfg(feature = "schema-types")]
+pub mod schema;
 
 // #[cfg(test)]
 // mod tests {
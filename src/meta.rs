@@ -0,0 +1,286 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metadata for the properties of the DSS classes (types, units, defaults, enum
+//! choices...), as reported by the engine through `DSS_ExtractSchema`.
+//! Requires the `serde` feature.
+//!
+//! ```no_run
+//! use altdss::common::DSSContext;
+//! use altdss::classic::IDSS;
+//!
+//! let ctx = DSSContext::prime();
+//! let dss = IDSS::new(&ctx);
+//! let load = dss.ClassSchema("Load").unwrap();
+//! let kW = load.Property("kW").unwrap();
+//! println!("{}: {:?}, {:?}", kW.name, kW.data_type, kW.unit);
+//! ```
+//!
+//! The metadata is extracted once per `DSSContext`, on first use, and shared by
+//! `IDSS::Schema`, `IDSS::ClassSchema` and `IActiveClass::Schema`.
+//!
+//! The JSON from the engine has a list of `classes`, each with its `name` and the list
+//! of `properties` in index order, and a list of `enums`, each with its `name` and the
+//! `values` as an object mapping the choices to their integer values. Each property has
+//! its `name` and `type` (the name of the property type in the engine, e.g.
+//! `DoubleProperty`), and optionally `flags`, `units`, `default`, `enum` (the name of the
+//! enumeration), `redundantWith` and `description`.
+
+#![allow(non_snake_case)]
+
+use std::collections::HashMap;
+use std::ffi::{c_char, CStr};
+use std::sync::Arc;
+use serde_json::{Map, Value};
+use crate::dss_capi;
+use crate::classic::{IActiveClass, IDSS};
+use crate::common::{DSSContext, DSSError, DSSErrorKind, DSSResultExt};
+use crate::json::{json_error, DSSValueText};
+use crate::obj::not_found;
+
+/// Data type of a property, as seen through the `Obj_*` functions
+#[derive(Debug, Clone, PartialEq)]
+pub enum DSSPropertyType {
+    Float64,
+    Int32,
+    Boolean,
+    String,
+    /// Integer value from a set of named choices; see `PropertySchema::choices`
+    Enum,
+    /// Reference to another DSS object
+    Object,
+    Complex,
+    /// Any other type reported by the engine
+    Other(String),
+}
+
+/// Shape of the value of a property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DSSPropertyShape {
+    Scalar,
+    Array,
+    /// Square matrix, usually given as its lower triangle in the scripts
+    Matrix,
+}
+
+/// Metadata of a property
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertySchema {
+    pub name: String,
+    /// Index of the property, as used by `obj::DSSObject`, starting at 1
+    pub index: i32,
+    pub data_type: DSSPropertyType,
+    pub shape: DSSPropertyShape,
+    pub unit: Option<String>,
+    /// Default value, as script text
+    pub default: Option<String>,
+    /// Name of the enumeration, for `DSSPropertyType::Enum`
+    pub enum_name: Option<String>,
+    /// Accepted names for `DSSPropertyType::Enum`
+    pub choices: Vec<String>,
+    pub deprecated: bool,
+    /// For properties that are alternative names or forms of another property, the
+    /// name of that property
+    pub alias_of: Option<String>,
+    pub description: Option<String>,
+    /// Flags reported by the engine, as is
+    pub flags: Vec<String>,
+}
+
+/// Metadata of a DSS class
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassSchema {
+    pub name: String,
+    pub properties: Vec<PropertySchema>,
+}
+
+impl ClassSchema {
+    /// Returns the metadata of a property by name (case-insensitive)
+    pub fn Property(&self, name: &str) -> Option<&PropertySchema> {
+        self.properties.iter().find(|prop| prop.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn PropertyNames(&self) -> Vec<&str> {
+        self.properties.iter().map(|prop| prop.name.as_str()).collect()
+    }
+}
+
+/// Metadata for all the DSS classes of a context
+#[derive(Debug, Clone, PartialEq)]
+pub struct DSSSchema {
+    pub classes: Vec<ClassSchema>,
+    /// Enumerations, by name, with their choices
+    pub enums: HashMap<String, Vec<String>>,
+}
+
+impl DSSSchema {
+    /// Extracts the metadata from the engine. Prefer `IDSS::Schema`, which caches it.
+    pub fn new(ctx: &DSSContext) -> Result<Self, DSSError> {
+        let opts = ctx.PrepareString("{}").origin("DSSSchema::new")?;
+        let ptr = unsafe { dss_capi::ctx_DSS_ExtractSchema(ctx.ctx_ptr, opts.as_ptr()) };
+        ctx.DSSError().origin("DSSSchema::new")?;
+        if ptr.is_null() {
+            return Err(not_found("The engine did not return the schema".to_string())).origin("DSSSchema::new");
+        }
+        let json = unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned();
+        unsafe { dss_capi::DSS_Dispose_String(ptr as *mut c_char) };
+        Self::FromJSON(&json)
+    }
+
+    /// Parses the JSON from `DSS_ExtractSchema`; see the module docs for the format
+    pub fn FromJSON(json: &str) -> Result<Self, DSSError> {
+        let data: Value = serde_json::from_str(json).map_err(json_error).origin("DSSSchema::FromJSON")?;
+        let enums = match data.get("enums") {
+            Some(Value::Array(items)) => items.iter().map(parse_enum).collect::<Result<_, _>>()?,
+            None => HashMap::new(),
+            Some(_) => return Err(schema_error("\"enums\" is not a list")),
+        };
+        let classes = match data.get("classes") {
+            Some(Value::Array(items)) => items.iter().map(|cls| parse_class(cls, &enums)).collect::<Result<_, _>>()?,
+            _ => return Err(schema_error("\"classes\" is missing or not a list")),
+        };
+        Ok(Self {
+            classes: classes,
+            enums: enums,
+        })
+    }
+
+    /// Returns the metadata of a class by name (case-insensitive)
+    pub fn Class(&self, name: &str) -> Result<&ClassSchema, DSSError> {
+        self.classes.iter()
+            .find(|cls| cls.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| not_found(format!("Class \"{}\" not found in the schema", name)))
+            .origin("DSSSchema::Class")
+    }
+}
+
+impl DSSContext {
+    /// The schema of this context, extracted on first use
+    fn Schema(&self) -> Result<Arc<DSSSchema>, DSSError> {
+        if let Some(schema) = self.schema.get() {
+            return Ok(schema.clone());
+        }
+        let schema = Arc::new(DSSSchema::new(self)?);
+        // Another thread may have extracted it in the meantime; both are equal
+        Ok(self.schema.get_or_init(|| schema).clone())
+    }
+}
+
+impl<'a> IDSS<'a> {
+    /// Property metadata for all the DSS classes; see `meta::DSSSchema`.
+    /// Requires the `serde` feature.
+    pub fn Schema(&self) -> Result<Arc<DSSSchema>, DSSError> {
+        self.ctx.Schema().origin("IDSS::Schema")
+    }
+
+    /// Property metadata for a DSS class; see `meta::DSSSchema`.
+    /// Requires the `serde` feature.
+    pub fn ClassSchema(&self, name: impl AsRef<str>) -> Result<ClassSchema, DSSError> {
+        self.Schema()?.Class(name.as_ref()).cloned().origin("IDSS::ClassSchema")
+    }
+}
+
+impl<'a> IActiveClass<'a> {
+    /// Property metadata for the active class.
+    /// Requires the `serde` feature.
+    pub fn Schema(&self) -> Result<ClassSchema, DSSError> {
+        let name = self.ActiveClassName().origin("IActiveClass::Schema")?;
+        self.ctx.Schema().origin("IActiveClass::Schema")?.Class(&name).cloned().origin("IActiveClass::Schema")
+    }
+}
+
+fn schema_error(message: &str) -> DSSError {
+    DSSError::wrapper(DSSErrorKind::JSON, format!("Unexpected schema data from the engine: {}", message))
+}
+
+fn text(obj: &Map<String, Value>, key: &str) -> Option<String> {
+    match obj.get(key)? {
+        Value::Null => None,
+        Value::String(text) => Some(text.clone()),
+        value => Some(DSSValueText(value).unwrap_or_else(|_| value.to_string())),
+    }
+}
+
+fn parse_enum(item: &Value) -> Result<(String, Vec<String>), DSSError> {
+    let name = item.get("name").and_then(Value::as_str).ok_or_else(|| schema_error("enum without a name"))?;
+    let Some(Value::Object(values)) = item.get("values") else {
+        return Err(schema_error(&format!("no values for the enum \"{}\"", name)));
+    };
+    Ok((name.to_string(), values.keys().cloned().collect()))
+}
+
+/// Maps the property types of the engine to the data type and shape
+fn parse_type(type_name: &str) -> (DSSPropertyType, DSSPropertyShape) {
+    use DSSPropertyShape::*;
+    match type_name {
+        "DoubleProperty" => (DSSPropertyType::Float64, Scalar),
+        "DoubleArrayProperty" | "DoubleVArrayProperty" | "DoubleDArrayProperty" | "DoubleFArrayProperty"
+            | "DoubleOnArrayProperty" | "DoubleOnStructArrayProperty" => (DSSPropertyType::Float64, Array),
+        "DoubleSymMatrixProperty" | "DoubleMatrixProperty" => (DSSPropertyType::Float64, Matrix),
+        "IntegerProperty" => (DSSPropertyType::Int32, Scalar),
+        "IntegerArrayProperty" | "IntegerOnStructArrayProperty" => (DSSPropertyType::Int32, Array),
+        "StringProperty" | "BusProperty" | "PathProperty" | "StringSilentROFunctionProperty" => (DSSPropertyType::String, Scalar),
+        "StringListProperty" | "BusesOnStructArrayProperty" => (DSSPropertyType::String, Array),
+        "MappedStringEnumProperty" | "MappedIntEnumProperty" | "MappedStringEnumOrIntegerProperty" => (DSSPropertyType::Enum, Scalar),
+        "MappedStringEnumArrayProperty" | "MappedStringEnumOnStructArrayProperty" => (DSSPropertyType::Enum, Array),
+        "BooleanProperty" | "EnabledProperty" => (DSSPropertyType::Boolean, Scalar),
+        "DSSObjectReferenceProperty" | "MakeLikeProperty" => (DSSPropertyType::Object, Scalar),
+        "DSSObjectReferenceArrayProperty" => (DSSPropertyType::Object, Array),
+        "ComplexProperty" | "ComplexPartsProperty" => (DSSPropertyType::Complex, Scalar),
+        _ => (DSSPropertyType::Other(type_name.to_string()), Scalar),
+    }
+}
+
+fn parse_class(cls: &Value, enums: &HashMap<String, Vec<String>>) -> Result<ClassSchema, DSSError> {
+    let Some(cls) = cls.as_object() else {
+        return Err(schema_error("class is not an object"));
+    };
+    let name = text(cls, "name").ok_or_else(|| schema_error("class without a name"))?;
+    let Some(Value::Array(props)) = cls.get("properties") else {
+        return Err(schema_error(&format!("no properties for the class \"{}\"", name)));
+    };
+    let properties = props.iter().enumerate().map(|(pos, prop)| {
+        let Some(prop) = prop.as_object() else {
+            return Err(schema_error(&format!("property of the class \"{}\" is not an object", name)));
+        };
+        let prop_name = text(prop, "name").ok_or_else(|| schema_error(&format!("property without a name in the class \"{}\"", name)))?;
+        let flags: Vec<String> = match prop.get("flags") {
+            Some(Value::Array(flags)) => flags.iter().filter_map(Value::as_str).map(str::to_string).collect(),
+            _ => Vec::new(),
+        };
+        let (data_type, shape) = parse_type(prop.get("type").and_then(Value::as_str).unwrap_or_default());
+        let enum_name = text(prop, "enum");
+        let choices = enum_name.as_ref().and_then(|enum_name| enums.get(enum_name)).cloned().unwrap_or_default();
+        Ok(PropertySchema {
+            name: prop_name,
+            index: pos as i32 + 1,
+            data_type: data_type,
+            shape: shape,
+            unit: text(prop, "units"),
+            default: text(prop, "default"),
+            enum_name: enum_name,
+            choices: choices,
+            deprecated: flags.iter().any(|flag| flag == "Deprecated"),
+            alias_of: text(prop, "redundantWith"),
+            description: text(prop, "description"),
+            flags: flags,
+        })
+    }).collect::<Result<_, _>>()?;
+    Ok(ClassSchema {
+        name: name,
+        properties: properties,
+    })
+}
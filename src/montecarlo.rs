@@ -0,0 +1,340 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reproducible Monte Carlo sampling of load and generation multipliers, with the
+//! random numbers drawn in Rust instead of the engine (`ISolution::Set_Random` and the
+//! `Monte*` solution modes). Requires the `montecarlo` feature.
+//!
+//! ```no_run
+//! use altdss::common::DSSContext;
+//! use altdss::classic::IDSS;
+//! use altdss::montecarlo::{MonteCarlo, MultiplierDistribution};
+//!
+//! let ctx = DSSContext::prime();
+//! let dss = IDSS::new(&ctx);
+//! dss.Command("redirect master.dss").unwrap();
+//! let mc = MonteCarlo::new(42)
+//!     .Loads(MultiplierDistribution::Normal { mean: 1.0, std_dev: 0.1 })
+//!     .Generators(MultiplierDistribution::Uniform { min: 0.0, max: 1.0 });
+//! let results = mc.Run(&dss, 0..1000, |dss, _sample| {
+//!     dss.ActiveCircuit.Solution.Solve()?;
+//!     Ok(dss.ActiveCircuit.Losses()?.re)
+//! }).unwrap();
+//! // Any sample can be reproduced later from its seed
+//! let worst = results.iter().max_by(|a, b| a.output.total_cmp(&b.output)).unwrap();
+//! let again = mc.Replay(&dss, worst.sample.seed, |dss, _sample| {
+//!     dss.ActiveCircuit.Solution.Solve()?;
+//!     Ok(dss.ActiveCircuit.Losses()?.re)
+//! }).unwrap();
+//! ```
+//!
+//! Each sample has its own random stream, derived from the base seed and the index of
+//! the sample, so the samples do not depend on the order they run in. This makes it
+//! safe to split the indices across contexts, e.g. with `pool::ScenarioPool` and
+//! `MonteCarlo::RunSample`.
+
+#![allow(non_snake_case)]
+
+use std::collections::HashMap;
+use rand::{Rng, RngCore, SeedableRng};
+use rand::distributions::Uniform;
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{LogNormal, Normal};
+use serde_json::{Map, Value};
+use crate::classic::{DSSJSONFlags, IDSS};
+use crate::common::{DSSError, DSSErrorKind, DSSResultExt};
+use crate::json::{json_error, DSSJSONOptions};
+
+/// Distribution of the multipliers applied to the base values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MultiplierDistribution {
+    Fixed(f64),
+    Uniform { min: f64, max: f64 },
+    /// Normal distribution; negative samples are clamped to zero
+    Normal { mean: f64, std_dev: f64 },
+    /// Log-normal distribution, from the parameters of the underlying normal distribution
+    LogNormal { mu: f64, sigma: f64 },
+}
+
+impl MultiplierDistribution {
+    fn Sample(&self, rng: &mut ChaCha8Rng) -> Result<f64, DSSError> {
+        let invalid = |message: String| DSSError::wrapper(DSSErrorKind::InvalidArgument, message);
+        Ok(match *self {
+            MultiplierDistribution::Fixed(value) => value,
+            MultiplierDistribution::Uniform { min, max } => {
+                if min.is_nan() || max.is_nan() || min > max {
+                    return Err(invalid(format!("Invalid uniform distribution: min={}, max={}", min, max)));
+                }
+                rng.sample(Uniform::new_inclusive(min, max))
+            },
+            MultiplierDistribution::Normal { mean, std_dev } => {
+                if !std_dev.is_finite() || std_dev < 0.0 {
+                    return Err(invalid(format!("Invalid normal distribution: std_dev={}", std_dev)));
+                }
+                let normal = Normal::new(mean, std_dev).map_err(|err| invalid(format!("Invalid normal distribution: {}", err)))?;
+                rng.sample(normal).max(0.0)
+            },
+            MultiplierDistribution::LogNormal { mu, sigma } => {
+                if !sigma.is_finite() || sigma < 0.0 {
+                    return Err(invalid(format!("Invalid log-normal distribution: sigma={}", sigma)));
+                }
+                let log_normal = LogNormal::new(mu, sigma).map_err(|err| invalid(format!("Invalid log-normal distribution: {}", err)))?;
+                rng.sample(log_normal)
+            },
+        })
+    }
+}
+
+/// The multipliers of a sample, by element name
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonteCarloSample {
+    /// Index of the sample in the run; `None` for samples replayed from a seed
+    pub index: Option<u64>,
+    /// Seed of the random stream of this sample; see `MonteCarlo::Replay`
+    pub seed: u64,
+    pub loads: Vec<(String, f64)>,
+    pub generators: Vec<(String, f64)>,
+}
+
+/// Output of a sample, next to the sample
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonteCarloResult<O> {
+    pub sample: MonteCarloSample,
+    pub output: O,
+}
+
+/// How the power of a load is specified, from the last of `kW`/`kvar`/`pf`/`kVA` set
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LoadSpec {
+    PowerAndPF { kW: f64, pf: f64 },
+    PowerAndKvar { kW: f64, kvar: f64 },
+    ApparentPowerAndPF { kVA: f64, pf: f64 },
+}
+
+/// Base values of the loads and generators, restored after each sample
+struct BaseValues {
+    loads: Vec<(String, LoadSpec)>,
+    generators: Vec<(String, f64)>,
+}
+
+impl BaseValues {
+    fn FromCircuit(dss: &IDSS) -> Result<Self, DSSError> {
+        let specified = specified_load_properties(dss)?;
+        let mut loads = Vec::new();
+        for load in dss.ActiveCircuit.Loads.iter() {
+            let load = load?.Activate()?;
+            let name = load.Get_Name()?;
+            let last = specified.get(&name.to_ascii_lowercase()).and_then(|props| {
+                props.iter().rev().find(|prop| ["kvar", "pf", "kva"].contains(&prop.as_str()))
+            });
+            // Loads are defined by kW and pf by default
+            let spec = match last.map(String::as_str) {
+                Some("kvar") => LoadSpec::PowerAndKvar { kW: load.Get_kW()?, kvar: load.Get_kvar()? },
+                Some("kva") => LoadSpec::ApparentPowerAndPF { kVA: load.Get_kva()?, pf: load.Get_PF()? },
+                _ => LoadSpec::PowerAndPF { kW: load.Get_kW()?, pf: load.Get_PF()? },
+            };
+            loads.push((name, spec));
+        }
+        let mut generators = Vec::new();
+        for generator in dss.ActiveCircuit.Generators.iter() {
+            let generator = generator?.Activate()?;
+            generators.push((generator.Get_Name()?, generator.Get_kW()?));
+        }
+        Ok(Self {
+            loads: loads,
+            generators: generators,
+        })
+    }
+
+    fn Apply(&self, dss: &IDSS, load_mults: &[(String, f64)], generator_mults: &[(String, f64)]) -> Result<(), DSSError> {
+        let loads = &dss.ActiveCircuit.Loads;
+        // Each setter changes the specification of the load, so the values are set in
+        // the same way the load is specified, keeping the power factor
+        for ((name, spec), (_, mult)) in self.loads.iter().zip(load_mults.iter()) {
+            loads.Set_Name(name)?;
+            match *spec {
+                LoadSpec::PowerAndPF { kW, pf } => {
+                    loads.Set_kW(kW * mult)?;
+                    loads.Set_PF(pf)?;
+                },
+                LoadSpec::PowerAndKvar { kW, kvar } => {
+                    loads.Set_kW(kW * mult)?;
+                    loads.Set_kvar(kvar * mult)?;
+                },
+                LoadSpec::ApparentPowerAndPF { kVA, pf } => {
+                    loads.Set_kva(kVA * mult)?;
+                    loads.Set_PF(pf)?;
+                },
+            }
+        }
+        let generators = &dss.ActiveCircuit.Generators;
+        for ((name, kW), (_, mult)) in self.generators.iter().zip(generator_mults.iter()) {
+            generators.Set_Name(name)?;
+            generators.Set_kW(kW * mult)?;
+        }
+        Ok(())
+    }
+
+    fn Restore(&self, dss: &IDSS) -> Result<(), DSSError> {
+        let ones = |count: usize| vec![(String::new(), 1.0); count];
+        self.Apply(dss, &ones(self.loads.len()), &ones(self.generators.len()))
+    }
+}
+
+/// Names of the properties set for each load, by lowercase load name, in the order
+/// they were set, as exported by the engine
+fn specified_load_properties(dss: &IDSS) -> Result<HashMap<String, Vec<String>>, DSSError> {
+    dss.SetActiveClass("Load")?;
+    let json = dss.ActiveClass.ToJSON(DSSJSONOptions::from_flags(&[DSSJSONFlags::LowercaseKeys]).Bits())?;
+    let objs: Vec<Map<String, Value>> = serde_json::from_str(&json).map_err(json_error)?;
+    Ok(objs.into_iter().filter_map(|obj| {
+        let name = obj.get("name")?.as_str()?.to_ascii_lowercase();
+        Some((name, obj.keys().cloned().collect()))
+    }).collect())
+}
+
+/// Configuration of a Monte Carlo run, see the module documentation.
+///
+/// The load multipliers scale the power of each load, keeping its power factor and the
+/// way it is specified (kW and pf, kW and kvar, or kVA and pf); the generation
+/// multipliers scale the kW of each generator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonteCarlo {
+    seed: u64,
+    loads: MultiplierDistribution,
+    generators: MultiplierDistribution,
+    independent: bool,
+}
+
+impl MonteCarlo {
+    /// New configuration with the base seed; the multipliers are fixed at 1 until the
+    /// distributions are set
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed: seed,
+            loads: MultiplierDistribution::Fixed(1.0),
+            generators: MultiplierDistribution::Fixed(1.0),
+            independent: true,
+        }
+    }
+
+    pub fn Loads(mut self, distribution: MultiplierDistribution) -> Self {
+        self.loads = distribution;
+        self
+    }
+
+    pub fn Generators(mut self, distribution: MultiplierDistribution) -> Self {
+        self.generators = distribution;
+        self
+    }
+
+    /// If true (the default), each element gets its own multiplier; otherwise, a single
+    /// multiplier is drawn for all the loads and another for all the generators
+    pub fn Independent(mut self, value: bool) -> Self {
+        self.independent = value;
+        self
+    }
+
+    /// Seed of the random stream of the sample `index`
+    pub fn SampleSeed(&self, index: u64) -> u64 {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        rng.set_stream(index);
+        rng.next_u64()
+    }
+
+    /// Draws the multipliers for the given elements from the stream of a sample seed,
+    /// without touching the engine
+    pub fn Draw(&self, seed: u64, load_names: &[String], generator_names: &[String]) -> Result<MonteCarloSample, DSSError> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let loads = self.DrawMultipliers(&mut rng, &self.loads, load_names).origin("MonteCarlo::Draw")?;
+        let generators = self.DrawMultipliers(&mut rng, &self.generators, generator_names).origin("MonteCarlo::Draw")?;
+        Ok(MonteCarloSample {
+            index: None,
+            seed: seed,
+            loads: loads,
+            generators: generators,
+        })
+    }
+
+    fn DrawMultipliers(&self, rng: &mut ChaCha8Rng, distribution: &MultiplierDistribution, names: &[String]) -> Result<Vec<(String, f64)>, DSSError> {
+        let shared = if self.independent { None } else { Some(distribution.Sample(rng)?) };
+        names.iter().map(|name| {
+            let mult = match shared {
+                Some(mult) => mult,
+                None => distribution.Sample(rng)?,
+            };
+            Ok((name.clone(), mult))
+        }).collect()
+    }
+
+    /// Runs the sample `index` on the active circuit: applies the multipliers, calls
+    /// `func` and restores the base values, even if `func` fails
+    pub fn RunSample<O, F>(&self, dss: &IDSS, index: u64, func: F) -> Result<MonteCarloResult<O>, DSSError>
+    where
+        F: FnOnce(&IDSS, &MonteCarloSample) -> Result<O, DSSError>
+    {
+        let base = BaseValues::FromCircuit(dss).origin("MonteCarlo::RunSample")?;
+        self.RunSampleFrom(dss, &base, index, func)
+    }
+
+    fn RunSampleFrom<O, F>(&self, dss: &IDSS, base: &BaseValues, index: u64, func: F) -> Result<MonteCarloResult<O>, DSSError>
+    where
+        F: FnOnce(&IDSS, &MonteCarloSample) -> Result<O, DSSError>
+    {
+        let mut result = self.ReplayFrom(dss, base, self.SampleSeed(index), func).origin("MonteCarlo::RunSample")?;
+        result.sample.index = Some(index);
+        Ok(result)
+    }
+
+    /// Runs a sample from its seed (`MonteCarloSample::seed`), as in `RunSample`
+    pub fn Replay<O, F>(&self, dss: &IDSS, seed: u64, func: F) -> Result<MonteCarloResult<O>, DSSError>
+    where
+        F: FnOnce(&IDSS, &MonteCarloSample) -> Result<O, DSSError>
+    {
+        let base = BaseValues::FromCircuit(dss).origin("MonteCarlo::Replay")?;
+        self.ReplayFrom(dss, &base, seed, func)
+    }
+
+    /// Applies the sample from `seed` over `base`, which is always restored afterwards
+    fn ReplayFrom<O, F>(&self, dss: &IDSS, base: &BaseValues, seed: u64, func: F) -> Result<MonteCarloResult<O>, DSSError>
+    where
+        F: FnOnce(&IDSS, &MonteCarloSample) -> Result<O, DSSError>
+    {
+        let load_names: Vec<String> = base.loads.iter().map(|(name, _)| name.clone()).collect();
+        let generator_names: Vec<String> = base.generators.iter().map(|(name, _)| name.clone()).collect();
+        let sample = self.Draw(seed, &load_names, &generator_names)?;
+        let output = base.Apply(dss, &sample.loads, &sample.generators).and_then(|_| func(dss, &sample));
+        let restored = base.Restore(dss).origin("MonteCarlo::Replay");
+        // The error from the closure, if any, is the one to report
+        let output = output?;
+        restored?;
+        Ok(MonteCarloResult {
+            sample: sample,
+            output: output,
+        })
+    }
+
+    /// Runs the samples in `indices` in sequence; stops at the first error.
+    /// The base values are captured once, before the first sample, so every
+    /// sample starts from the same circuit state.
+    pub fn Run<O, F>(&self, dss: &IDSS, indices: impl IntoIterator<Item = u64>, mut func: F) -> Result<Vec<MonteCarloResult<O>>, DSSError>
+    where
+        F: FnMut(&IDSS, &MonteCarloSample) -> Result<O, DSSError>
+    {
+        let base = BaseValues::FromCircuit(dss).origin("MonteCarlo::Run")?;
+        indices.into_iter().map(|index| self.RunSampleFrom(dss, &base, index, &mut func)).collect()
+    }
+}
@@ -0,0 +1,585 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generic access to the objects of every DSS class, through the `Obj_*` functions of
+//! DSS C-API.
+//!
+//! Unlike the classic API, which only covers the classes exposed in the COM interfaces,
+//! this works for every class of the engine, including e.g. `InvControl`, `XfmrCode`
+//! or `TCC_Curve`. The objects are addressed directly through handles, so the active
+//! element of the engine is not changed, except to resolve the property names.
+//!
+//! ```no_run
+//! use altdss::common::DSSContext;
+//! use altdss::classic::IDSS;
+//! use altdss::obj::IObj;
+//!
+//! let ctx = DSSContext::prime();
+//! let dss = IDSS::new(&ctx);
+//! dss.Command("new circuit.test").unwrap();
+//! dss.Command("new load.ld1 bus1=sourcebus kv=115 kw=1000").unwrap();
+//!
+//! let obj = IObj::new(&ctx);
+//! let load = obj.Get("Load", "ld1").unwrap();
+//! load.SetFloat64("kW", 1200.0).unwrap();
+//! assert_eq!(load.GetFloat64("kW").unwrap(), 1200.0);
+//! let spectrum = load.GetObject("spectrum").unwrap().unwrap();
+//! println!("{}", spectrum.FullName().unwrap());
+//! ```
+//!
+//! Clearing the circuit (e.g. with the `clear` command or `IDSS::ClearAll`) frees all
+//! the DSS objects; the handles created before that return `DSSErrorKind::StaleReference`
+//! instead of touching the freed memory.
+//!
+//! The engine does not support removing single DSS objects. Circuit elements can be
+//! disabled with `DSSObject::Disable`, as the `remove` command does; they are then
+//! ignored by the solution, but still listed in their class.
+
+#![allow(non_snake_case)]
+
+use crate::dss_capi;
+use crate::callbacks::ClearEpoch;
+use crate::common::{DSSContext, DSSError, DSSErrorKind, DSSResultExt};
+use std::cell::OnceCell;
+use std::ffi::{c_char, c_void, CStr};
+use std::fmt;
+use std::slice::from_raw_parts;
+
+fn bool_to_u16(v: bool) -> u16 {
+    if v {
+        1
+    } else {
+        0
+    }
+}
+
+pub(crate) fn not_found(message: String) -> DSSError {
+    DSSError::wrapper(DSSErrorKind::NotFound, message)
+}
+
+/// Entry point for the generic object API of a DSS context
+pub struct IObj<'a> {
+    ctx: &'a DSSContext,
+}
+
+unsafe impl<'a> Send for IObj <'a> {
+}
+impl<'a> IObj<'a> {
+    pub fn new(ctx: &'a DSSContext) -> Self {
+        Self {
+            ctx: ctx,
+        }
+    }
+
+    /// Names of all the DSS classes of the engine
+    pub fn Classes(&self) -> Result<Box::<[String]>, DSSError> {
+        let mut cnt: [i32; 4] = [0, 0, 0, 0];
+        let mut data: *mut *mut c_char = 0 as *mut *mut c_char;
+        unsafe { dss_capi::ctx_DSS_Get_Classes(self.ctx.ctx_ptr, &mut data, &mut cnt[0]) };
+        self.ctx.GetStringArray(data, cnt).origin("IObj::Classes")
+    }
+
+    /// Returns a DSS class by name (case-insensitive). Note that this changes the active class.
+    pub fn Class(&self, name: impl AsRef<str>) -> Result<DSSClass<'a>, DSSError> {
+        let name = name.as_ref();
+        let name_c = self.ctx.PrepareString(name).origin("IObj::Class")?;
+        let idx = unsafe { dss_capi::ctx_DSS_SetActiveClass(self.ctx.ctx_ptr, name_c.as_ptr()) };
+        if idx <= 0 {
+            // Clear the error from the engine, if any, reporting a clearer one
+            let _ = self.ctx.DSSError();
+            return Err(not_found(format!("DSS class \"{}\" not found", name))).origin("IObj::Class");
+        }
+        self.ctx.DSSError().origin("IObj::Class")?;
+        let class_name = unsafe { CStr::from_ptr(dss_capi::ctx_ActiveClass_Get_ActiveClassName(self.ctx.ctx_ptr)).to_string_lossy().into_owned() };
+        self.ctx.DSSError().origin("IObj::Class")?;
+        let epoch = ClearEpoch::current(self.ctx).origin("IObj::Class")?;
+        Ok(DSSClass {
+            ctx: self.ctx,
+            idx: idx,
+            name: class_name,
+            epoch: epoch,
+        })
+    }
+
+    /// Returns a DSS object by class and name
+    pub fn Get(&self, class_name: impl AsRef<str>, name: impl AsRef<str>) -> Result<DSSObject<'a>, DSSError> {
+        self.Class(class_name)?.Get(name)
+    }
+
+    /// Returns a DSS object from a full name, e.g. `"Load.ld1"`
+    pub fn GetByFullName(&self, full_name: impl AsRef<str>) -> Result<DSSObject<'a>, DSSError> {
+        let full_name = full_name.as_ref();
+        let Some((class_name, name)) = full_name.split_once('.') else {
+            return Err(not_found(format!("Invalid full name for a DSS object: \"{}\"", full_name))).origin("IObj::GetByFullName");
+        };
+        self.Get(class_name, name)
+    }
+
+    /// Creates a new DSS object. See `DSSClass::New`.
+    pub fn New(&self, class_name: impl AsRef<str>, name: impl AsRef<str>) -> Result<DSSObject<'a>, DSSError> {
+        self.Class(class_name)?.New(name)
+    }
+}
+
+/// A DSS class, e.g. `Load` or `InvControl`
+pub struct DSSClass<'a> {
+    pub(crate) ctx: &'a DSSContext,
+    idx: i32,
+    name: String,
+    pub(crate) epoch: ClearEpoch,
+}
+
+unsafe impl<'a> Send for DSSClass <'a> {
+}
+impl<'a> DSSClass<'a> {
+    /// Name of the class, as used in the engine
+    pub fn Name(&self) -> &str {
+        &self.name
+    }
+
+    /// Index of the class in the engine
+    pub fn Index(&self) -> i32 {
+        self.idx
+    }
+
+    /// Number of objects of this class
+    pub fn Count(&self) -> Result<i32, DSSError> {
+        let result = unsafe { dss_capi::Obj_GetCount(self.ctx.ctx_ptr as *mut c_void, self.idx) };
+        self.ctx.DSSError().origin("DSSClass::Count")?;
+        Ok(result)
+    }
+
+    /// Returns an object of this class by name (case-insensitive)
+    pub fn Get(&self, name: impl AsRef<str>) -> Result<DSSObject<'a>, DSSError> {
+        let name = name.as_ref();
+        let name_c = self.ctx.PrepareString(name).origin("DSSClass::Get")?;
+        let ptr = unsafe { dss_capi::Obj_GetHandleByName(self.ctx.ctx_ptr as *mut c_void, self.idx, name_c.as_ptr()) };
+        self.ctx.DSSError().origin("DSSClass::Get")?;
+        DSSObject::from_handle(self.ctx, self.epoch.renew(), ptr)
+            .ok_or_else(|| not_found(format!("{}.{} not found", self.name, name)))
+            .origin("DSSClass::Get")
+    }
+
+    /// Returns an object of this class by index, in the range `1..=Count()`
+    pub fn GetByIndex(&self, idx: i32) -> Result<DSSObject<'a>, DSSError> {
+        let ptr = unsafe { dss_capi::Obj_GetHandleByIdx(self.ctx.ctx_ptr as *mut c_void, self.idx, idx) };
+        self.ctx.DSSError().origin("DSSClass::GetByIndex")?;
+        DSSObject::from_handle(self.ctx, self.epoch.renew(), ptr)
+            .ok_or_else(|| not_found(format!("Invalid index {} for the class {}", idx, self.name)))
+            .origin("DSSClass::GetByIndex")
+    }
+
+    /// All the objects of this class
+    pub fn Objects(&self) -> Result<Vec<DSSObject<'a>>, DSSError> {
+        (1..=self.Count()?).map(|idx| self.GetByIndex(idx)).collect()
+    }
+
+    /// Names of all the objects of this class
+    pub fn Names(&self) -> Result<Vec<String>, DSSError> {
+        Ok(self.Batch().origin("DSSClass::Names")?.Names().origin("DSSClass::Names")?.into_vec())
+    }
+
+    /// Creates a new object of this class. The new object is not activated, and it
+    /// uses the default values of the class for all properties.
+    pub fn New(&self, name: impl AsRef<str>) -> Result<DSSObject<'a>, DSSError> {
+        let name_c = self.ctx.PrepareString(name).origin("DSSClass::New")?;
+        let ptr = unsafe { dss_capi::Obj_New(self.ctx.ctx_ptr as *mut c_void, self.idx, name_c.as_ptr(), bool_to_u16(false), bool_to_u16(false)) };
+        self.ctx.DSSError().origin("DSSClass::New")?;
+        DSSObject::from_handle(self.ctx, self.epoch.renew(), ptr)
+            .ok_or_else(|| DSSError::wrapper(DSSErrorKind::Context, format!("Could not create the {} object", self.name)))
+            .origin("DSSClass::New")
+    }
+}
+
+/// Identifies a property of a DSS object, either by its index (starting at 1) or by
+/// its name (case-insensitive).
+pub trait DSSPropertyKey {
+    fn PropertyIndex(&self, obj: &DSSObject) -> Result<i32, DSSError>;
+}
+
+impl DSSPropertyKey for i32 {
+    fn PropertyIndex(&self, _obj: &DSSObject) -> Result<i32, DSSError> {
+        Ok(*self)
+    }
+}
+
+impl DSSPropertyKey for str {
+    fn PropertyIndex(&self, obj: &DSSObject) -> Result<i32, DSSError> {
+        obj.PropertyIndex(self)
+    }
+}
+
+impl DSSPropertyKey for String {
+    fn PropertyIndex(&self, obj: &DSSObject) -> Result<i32, DSSError> {
+        obj.PropertyIndex(self)
+    }
+}
+
+impl<T: DSSPropertyKey + ?Sized> DSSPropertyKey for &T {
+    fn PropertyIndex(&self, obj: &DSSObject) -> Result<i32, DSSError> {
+        (**self).PropertyIndex(obj)
+    }
+}
+
+/// Handle to a DSS object of any class.
+///
+/// The property getters and setters use the native type of each property; for
+/// object references, the referenced objects are returned as handles. Any property
+/// can also be read and written as text, with `GetAsString`/`SetAsString`.
+///
+/// Resolving the property names requires activating the object, so prefer the
+/// property indices in tight loops; the names are cached in each handle.
+pub struct DSSObject<'a> {
+    ctx: &'a DSSContext,
+    ptr: *mut c_void,
+    epoch: ClearEpoch,
+    property_names: OnceCell<Box<[String]>>,
+}
+
+unsafe impl<'a> Send for DSSObject <'a> {
+}
+impl<'a> DSSObject<'a> {
+    pub(crate) fn from_handle(ctx: &'a DSSContext, epoch: ClearEpoch, ptr: *mut c_void) -> Option<Self> {
+        if ptr.is_null() {
+            return None;
+        }
+        Some(Self {
+            ctx: ctx,
+            ptr: ptr,
+            epoch: epoch,
+            property_names: OnceCell::new(),
+        })
+    }
+
+    /// Raw handle of the object in the engine. Returns an error if the circuit was
+    /// cleared after this handle was created, since the object does not exist anymore.
+    pub fn Handle(&self) -> Result<*mut c_void, DSSError> {
+        self.checked_ptr().origin("DSSObject::Handle")
+    }
+
+    pub(crate) fn checked_ptr(&self) -> Result<*mut c_void, DSSError> {
+        self.epoch.check(|| "handle to the DSS object".to_string())?;
+        Ok(self.ptr)
+    }
+
+    /// Name of the object
+    pub fn Name(&self) -> Result<String, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::Name")?;
+        let result = unsafe { CStr::from_ptr(dss_capi::Obj_GetName(ptr)).to_string_lossy().into_owned() };
+        self.ctx.DSSError().origin("DSSObject::Name")?;
+        Ok(result)
+    }
+
+    /// Name of the class of the object
+    pub fn ClassName(&self) -> Result<String, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::ClassName")?;
+        let result = unsafe { CStr::from_ptr(dss_capi::Obj_GetClassName(ptr)).to_string_lossy().into_owned() };
+        self.ctx.DSSError().origin("DSSObject::ClassName")?;
+        Ok(result)
+    }
+
+    /// Index of the class of the object, see `DSSClass::Index`
+    pub fn ClassIndex(&self) -> Result<i32, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::ClassIndex")?;
+        let result = unsafe { dss_capi::Obj_GetClassIdx(ptr) };
+        self.ctx.DSSError().origin("DSSObject::ClassIndex")?;
+        Ok(result)
+    }
+
+    /// Full name of the object, e.g. `"Load.ld1"`
+    pub fn FullName(&self) -> Result<String, DSSError> {
+        Ok(format!("{}.{}", self.ClassName()?, self.Name()?))
+    }
+
+    /// Number of properties of the object
+    pub fn NumProperties(&self) -> Result<i32, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::NumProperties")?;
+        let result = unsafe { dss_capi::Obj_GetNumProperties(ptr) };
+        self.ctx.DSSError().origin("DSSObject::NumProperties")?;
+        Ok(result)
+    }
+
+    /// Makes this the active DSS object (and active circuit element, when applicable)
+    pub fn Activate(&self) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::Activate")?;
+        unsafe { dss_capi::Obj_Activate(ptr, bool_to_u16(false)) };
+        self.ctx.DSSError().origin("DSSObject::Activate")
+    }
+
+    /// Like `Activate`, but also makes the object active in the class-specific lists,
+    /// which are used by the classic interfaces such as `ILoads`
+    pub(crate) fn ActivateAllLists(&self) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::ActivateAllLists")?;
+        unsafe { dss_capi::Obj_Activate(ptr, bool_to_u16(true)) };
+        self.ctx.DSSError().origin("DSSObject::ActivateAllLists")
+    }
+
+    /// Names of the properties, in order, i.e. the name for property index `i` is at `i - 1`.
+    /// This activates the object the first time it is called.
+    pub fn PropertyNames(&self) -> Result<&[String], DSSError> {
+        if let Some(names) = self.property_names.get() {
+            return Ok(names);
+        }
+        self.Activate().origin("DSSObject::PropertyNames")?;
+        let mut cnt: [i32; 4] = [0, 0, 0, 0];
+        let mut data: *mut *mut c_char = 0 as *mut *mut c_char;
+        unsafe { dss_capi::ctx_DSSElement_Get_AllPropertyNames(self.ctx.ctx_ptr, &mut data, &mut cnt[0]) };
+        let names = self.ctx.GetStringArray(data, cnt).origin("DSSObject::PropertyNames")?;
+        Ok(self.property_names.get_or_init(|| names))
+    }
+
+    /// Index (starting at 1) of a property, by name (case-insensitive)
+    pub fn PropertyIndex(&self, name: &str) -> Result<i32, DSSError> {
+        let names = self.PropertyNames()?;
+        match names.iter().position(|prop| prop.eq_ignore_ascii_case(name)) {
+            Some(pos) => Ok(pos as i32 + 1),
+            None => Err(not_found(format!("Property \"{}\" not found", name))).origin("DSSObject::PropertyIndex"),
+        }
+    }
+
+    /// Starts editing the object. The changes are only processed by the engine in
+    /// `EndEdit`, which is useful when several properties depend on each other.
+    pub fn BeginEdit(&self) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::BeginEdit")?;
+        unsafe { dss_capi::Obj_BeginEdit(ptr) };
+        self.ctx.DSSError().origin("DSSObject::BeginEdit")
+    }
+
+    /// Finishes editing the object, see `BeginEdit`
+    pub fn EndEdit(&self, num_changes: i32) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::EndEdit")?;
+        unsafe { dss_capi::Obj_EndEdit(ptr, num_changes) };
+        self.ctx.DSSError().origin("DSSObject::EndEdit")
+    }
+
+    /// Exports the object as JSON. See the `DSSJSONFlags` enum in DSS C-API for the options.
+    pub fn ToJSON(&self, options: i32) -> Result<String, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::ToJSON")?;
+        let data = unsafe { dss_capi::Obj_ToJSON(ptr, options) };
+        self.ctx.DSSError().origin("DSSObject::ToJSON")?;
+        if data.is_null() {
+            return Ok(String::new());
+        }
+        let result = unsafe { CStr::from_ptr(data).to_string_lossy().into_owned() };
+        unsafe { dss_capi::DSS_Dispose_String(data) };
+        Ok(result)
+    }
+
+    pub fn GetFloat64(&self, prop: impl DSSPropertyKey) -> Result<f64, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::GetFloat64")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::GetFloat64")?;
+        let result = unsafe { dss_capi::Obj_GetFloat64(ptr, idx) };
+        self.ctx.DSSError().origin("DSSObject::GetFloat64")?;
+        Ok(result)
+    }
+
+    pub fn GetInt32(&self, prop: impl DSSPropertyKey) -> Result<i32, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::GetInt32")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::GetInt32")?;
+        let result = unsafe { dss_capi::Obj_GetInt32(ptr, idx) };
+        self.ctx.DSSError().origin("DSSObject::GetInt32")?;
+        Ok(result)
+    }
+
+    pub fn GetString(&self, prop: impl DSSPropertyKey) -> Result<String, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::GetString")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::GetString")?;
+        let data = unsafe { dss_capi::Obj_GetString(ptr, idx) };
+        self.ctx.DSSError().origin("DSSObject::GetString")?;
+        if data.is_null() {
+            return Ok(String::new());
+        }
+        let result = unsafe { CStr::from_ptr(data).to_string_lossy().into_owned() };
+        unsafe { dss_capi::DSS_Dispose_String(data as *mut c_char) };
+        Ok(result)
+    }
+
+    /// Returns the value of any property as text, as it would be used in a DSS script
+    pub fn GetAsString(&self, prop: impl DSSPropertyKey) -> Result<String, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::GetAsString")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::GetAsString")?;
+        let data = unsafe { dss_capi::Obj_GetAsString(ptr, idx) };
+        self.ctx.DSSError().origin("DSSObject::GetAsString")?;
+        if data.is_null() {
+            return Ok(String::new());
+        }
+        let result = unsafe { CStr::from_ptr(data).to_string_lossy().into_owned() };
+        unsafe { dss_capi::DSS_Dispose_String(data as *mut c_char) };
+        Ok(result)
+    }
+
+    /// Returns the object referenced by a property, if any
+    pub fn GetObject(&self, prop: impl DSSPropertyKey) -> Result<Option<DSSObject<'a>>, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::GetObject")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::GetObject")?;
+        let ptr = unsafe { dss_capi::Obj_GetObject(ptr, idx) };
+        self.ctx.DSSError().origin("DSSObject::GetObject")?;
+        Ok(DSSObject::from_handle(self.ctx, self.epoch.clone(), ptr))
+    }
+
+    /// Float64 array property, read through the GR buffers
+    pub fn GetFloat64Array(&self, prop: impl DSSPropertyKey) -> Result<Box::<[f64]>, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::GetFloat64Array")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::GetFloat64Array")?;
+        self.ctx.CheckGRBuffers().origin("DSSObject::GetFloat64Array")?;
+        let (data, cnt) = self.ctx.Float64ArrayGRPointers();
+        unsafe { dss_capi::Obj_GetFloat64Array(data, cnt, ptr, idx) };
+        self.ctx.GetFloat64ArrayGR().origin("DSSObject::GetFloat64Array")
+    }
+
+    /// Int32 array property, read through the GR buffers
+    pub fn GetInt32Array(&self, prop: impl DSSPropertyKey) -> Result<Box::<[i32]>, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::GetInt32Array")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::GetInt32Array")?;
+        self.ctx.CheckGRBuffers().origin("DSSObject::GetInt32Array")?;
+        let (data, cnt) = self.ctx.Int32ArrayGRPointers();
+        unsafe { dss_capi::Obj_GetInt32Array(data, cnt, ptr, idx) };
+        self.ctx.GetInt32ArrayGR().origin("DSSObject::GetInt32Array")
+    }
+
+    pub fn GetStringArray(&self, prop: impl DSSPropertyKey) -> Result<Box::<[String]>, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::GetStringArray")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::GetStringArray")?;
+        let mut cnt: [i32; 4] = [0, 0, 0, 0];
+        let mut data: *mut *mut c_char = 0 as *mut *mut c_char;
+        unsafe { dss_capi::Obj_GetStringArray(&mut data, &mut cnt[0], ptr, idx) };
+        self.ctx.GetStringArray(data, cnt).origin("DSSObject::GetStringArray")
+    }
+
+    /// Returns the objects referenced by an array property
+    pub fn GetObjectArray(&self, prop: impl DSSPropertyKey) -> Result<Vec<Option<DSSObject<'a>>>, DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::GetObjectArray")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::GetObjectArray")?;
+        let mut cnt: [i32; 4] = [0, 0, 0, 0];
+        let mut data: *mut *mut c_void = 0 as *mut *mut c_void;
+        unsafe { dss_capi::Obj_GetObjectArray(&mut data, &mut cnt[0], ptr, idx) };
+        let result = self.ctx.DSSError().origin("DSSObject::GetObjectArray").map(|_| {
+            if data.is_null() || cnt[0] <= 0 {
+                return Vec::new();
+            }
+            let ptrs = unsafe { from_raw_parts(data, cnt[0] as usize) };
+            ptrs.iter().map(|ptr| DSSObject::from_handle(self.ctx, self.epoch.clone(), *ptr)).collect()
+        });
+        unsafe { dss_capi::DSS_Dispose_PPointer(&mut data) };
+        result
+    }
+
+    pub fn SetFloat64(&self, prop: impl DSSPropertyKey, value: f64) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::SetFloat64")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::SetFloat64")?;
+        unsafe { dss_capi::Obj_SetFloat64(ptr, idx, value, 0) };
+        self.ctx.DSSError().origin("DSSObject::SetFloat64")
+    }
+
+    pub fn SetInt32(&self, prop: impl DSSPropertyKey, value: i32) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::SetInt32")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::SetInt32")?;
+        unsafe { dss_capi::Obj_SetInt32(ptr, idx, value, 0) };
+        self.ctx.DSSError().origin("DSSObject::SetInt32")
+    }
+
+    pub fn SetString(&self, prop: impl DSSPropertyKey, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::SetString")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::SetString")?;
+        let value_c = self.ctx.PrepareString(value).origin("DSSObject::SetString")?;
+        unsafe { dss_capi::Obj_SetString(ptr, idx, value_c.as_ptr(), 0) };
+        self.ctx.DSSError().origin("DSSObject::SetString")
+    }
+
+    /// Sets any property from text, as it would be used in a DSS script
+    pub fn SetAsString(&self, prop: impl DSSPropertyKey, value: impl AsRef<str>) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::SetAsString")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::SetAsString")?;
+        let value_c = self.ctx.PrepareString(value).origin("DSSObject::SetAsString")?;
+        unsafe { dss_capi::Obj_SetAsString(ptr, idx, value_c.as_ptr()) };
+        self.ctx.DSSError().origin("DSSObject::SetAsString")
+    }
+
+    /// Sets an object reference property; `None` clears the reference
+    pub fn SetObject(&self, prop: impl DSSPropertyKey, value: Option<&DSSObject>) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::SetObject")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::SetObject")?;
+        let value_ptr = value.map_or(Ok(0 as *mut c_void), |obj| obj.checked_ptr()).origin("DSSObject::SetObject")?;
+        unsafe { dss_capi::Obj_SetObject(ptr, idx, value_ptr, 0) };
+        self.ctx.DSSError().origin("DSSObject::SetObject")
+    }
+
+    pub fn SetFloat64Array(&self, prop: impl DSSPropertyKey, value: &[f64]) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::SetFloat64Array")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::SetFloat64Array")?;
+        unsafe { dss_capi::Obj_SetFloat64Array(ptr, idx, value.as_ptr(), value.len() as i32, 0) };
+        self.ctx.DSSError().origin("DSSObject::SetFloat64Array")
+    }
+
+    pub fn SetInt32Array(&self, prop: impl DSSPropertyKey, value: &[i32]) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::SetInt32Array")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::SetInt32Array")?;
+        unsafe { dss_capi::Obj_SetInt32Array(ptr, idx, value.as_ptr(), value.len() as i32, 0) };
+        self.ctx.DSSError().origin("DSSObject::SetInt32Array")
+    }
+
+    pub fn SetStringArray(&self, prop: impl DSSPropertyKey, value: &[impl AsRef<str>]) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::SetStringArray")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::SetStringArray")?;
+        let (_value_cstrs, value_c) = self.ctx.PrepareStringArray(value).origin("DSSObject::SetStringArray")?;
+        unsafe { dss_capi::Obj_SetStringArray(ptr, idx, value_c.as_ptr() as *mut *const c_char, value.len() as i32, 0) };
+        self.ctx.DSSError().origin("DSSObject::SetStringArray")
+    }
+
+    pub fn SetObjectArray(&self, prop: impl DSSPropertyKey, value: &[&DSSObject]) -> Result<(), DSSError> {
+        let ptr = self.checked_ptr().origin("DSSObject::SetObjectArray")?;
+        let idx = prop.PropertyIndex(self).origin("DSSObject::SetObjectArray")?;
+        let value_ptrs: Box::<[*mut c_void]> = value.iter().map(|obj| obj.checked_ptr()).collect::<Result<_, _>>().origin("DSSObject::SetObjectArray")?;
+        unsafe { dss_capi::Obj_SetObjectArray(ptr, idx, value_ptrs.as_ptr() as *mut *const c_void, value.len() as i32, 0) };
+        self.ctx.DSSError().origin("DSSObject::SetObjectArray")
+    }
+
+    /// Disables a circuit element, as the `remove` command does. The element is ignored
+    /// by the solution, but it is not removed: it still resolves by name and is still
+    /// counted and listed in its class, e.g. in the JSON and batch exports.
+    ///
+    /// Other objects, e.g. `LoadShape` or `XYcurve`, cannot be disabled and return
+    /// `DSSErrorKind::Unsupported`.
+    pub fn Disable(&self) -> Result<(), DSSError> {
+        let enabled = match self.PropertyIndex("enabled") {
+            Ok(idx) => idx,
+            Err(err) if err.kind == DSSErrorKind::NotFound => {
+                let full_name = self.FullName().origin("DSSObject::Disable")?;
+                return Err(DSSError::wrapper(
+                    DSSErrorKind::Unsupported,
+                    format!("Cannot disable \"{}\": only circuit elements can be disabled", full_name)
+                )).origin("DSSObject::Disable");
+            },
+            Err(err) => return Err(err).origin("DSSObject::Disable"),
+        };
+        self.SetAsString(enabled, "false").origin("DSSObject::Disable")
+    }
+}
+
+impl<'a> PartialEq for DSSObject<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr
+    }
+}
+
+impl<'a> fmt::Debug for DSSObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.FullName() {
+            Ok(full_name) => write!(f, "DSSObject({})", full_name),
+            Err(_) => write!(f, "DSSObject({:p})", self.ptr),
+        }
+    }
+}
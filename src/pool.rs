@@ -0,0 +1,196 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs many scenarios of the same circuit in parallel, with a DSS context per
+//! worker thread.
+//!
+//! ```no_run
+//! use altdss::pool::ScenarioPool;
+//!
+//! let mut pool = ScenarioPool::new(4, "redirect master.dss").unwrap();
+//! let loadmults: Vec<f64> = (0..90).map(|i| 0.5 + 0.01 * i as f64).collect();
+//! let results = pool.Run(loadmults, |dss, loadmult| {
+//!     dss.ActiveCircuit.Solution.Set_LoadMult(loadmult)?;
+//!     dss.ActiveCircuit.Solution.Solve()?;
+//!     Ok(dss.ActiveCircuit.Losses()?.re)
+//! });
+//! for result in results {
+//!     match result {
+//!         Ok(losses) => println!("{}", losses),
+//!         Err(err) => println!("Error: {}", err.message),
+//!     }
+//! }
+//! ```
+//!
+//! The circuit is compiled once per worker, when the pool is created. Each scenario
+//! runs on a context where previous scenarios already ran, so the closures must set
+//! everything they depend on (or restore what they change).
+
+#![allow(non_snake_case)]
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use crate::builder::DSSContextBuilder;
+use crate::classic::IDSS;
+use crate::common::{DSSContext, DSSError, DSSErrorKind, DSSResultExt};
+
+/// Cancels the scenarios of a `ScenarioPool` that did not start yet. It can be cloned
+/// and shared with other threads, e.g. a UI or a signal handler.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn Cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn IsCancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// A set of DSS contexts with the same base circuit, to run scenarios in parallel
+pub struct ScenarioPool {
+    contexts: Vec<DSSContext>,
+    cancel: CancelToken,
+}
+
+impl ScenarioPool {
+    /// Creates `workers` contexts, with the options from `DSSContextBuilder::modern`,
+    /// and runs the `setup` commands (usually a `redirect` to the base circuit) in each
+    /// of them, in parallel
+    pub fn new(workers: usize, setup: impl AsRef<str>) -> Result<Self, DSSError> {
+        Self::with_builder(&DSSContextBuilder::modern(), workers, setup)
+    }
+
+    /// Same as `new`, with the options for the contexts from `builder`. Note that the
+    /// engine must not change the working directory in the workers (see
+    /// `DSSContextBuilder::AllowChangeDir`), since it is shared by the whole process.
+    pub fn with_builder(builder: &DSSContextBuilder, workers: usize, setup: impl AsRef<str>) -> Result<Self, DSSError> {
+        if workers == 0 {
+            return Err(DSSError::wrapper(
+                DSSErrorKind::InvalidArgument,
+                "A scenario pool requires at least one worker".to_string()
+            )).origin("ScenarioPool::new");
+        }
+        let contexts = (0..workers).map(|_| builder.Build()).collect::<Result<Vec<_>, _>>().origin("ScenarioPool::new")?;
+        let setup = setup.as_ref();
+        thread::scope(|scope| {
+            let handles: Vec<_> = contexts.iter()
+                .map(|ctx| scope.spawn(move || IDSS::new(ctx).Command(setup)))
+                .collect();
+            handles.into_iter().map(|handle| handle.join().unwrap_or_else(|err| Err(panic_error(err)))).collect::<Result<Vec<_>, _>>()
+        }).origin("ScenarioPool::new")?;
+        Ok(Self {
+            contexts: contexts,
+            cancel: CancelToken::new(),
+        })
+    }
+
+    pub fn Workers(&self) -> usize {
+        self.contexts.len()
+    }
+
+    /// Token to cancel the current run. The token is reset when a new run starts.
+    pub fn CancelToken(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+
+    /// Runs `func` for each input and returns the results in the order of the inputs.
+    ///
+    /// Errors (and panics) in a scenario are returned as the result of that scenario,
+    /// without affecting the others. Scenarios that did not start before a cancellation
+    /// return `DSSErrorKind::Cancelled`.
+    pub fn Run<I, O, F>(&mut self, inputs: impl IntoIterator<Item = I>, func: F) -> Vec<Result<O, DSSError>>
+    where
+        I: Send,
+        O: Send,
+        F: Fn(&IDSS, I) -> Result<O, DSSError> + Sync
+    {
+        self.RunWithProgress(inputs, func, |_done, _total| {})
+    }
+
+    /// Same as `Run`, calling `progress` with the number of finished scenarios and the
+    /// total after each scenario. `progress` is called from the worker threads.
+    pub fn RunWithProgress<I, O, F, P>(&mut self, inputs: impl IntoIterator<Item = I>, func: F, progress: P) -> Vec<Result<O, DSSError>>
+    where
+        I: Send,
+        O: Send,
+        F: Fn(&IDSS, I) -> Result<O, DSSError> + Sync,
+        P: Fn(usize, usize) + Sync
+    {
+        self.cancel.cancelled.store(false, Ordering::SeqCst);
+        let queue: Mutex<VecDeque<(usize, I)>> = Mutex::new(inputs.into_iter().enumerate().collect());
+        let total = queue.lock().unwrap().len();
+        let done = AtomicUsize::new(0);
+        let cancel = &self.cancel;
+        let (queue, func, progress, done) = (&queue, &func, &progress, &done);
+
+        let mut results: Vec<Option<Result<O, DSSError>>> = (0..total).map(|_| None).collect();
+        thread::scope(|scope| {
+            let handles: Vec<_> = self.contexts.iter().map(|ctx| scope.spawn(move || {
+                let dss = IDSS::new(ctx);
+                let mut worker_results = Vec::new();
+                loop {
+                    let Some((idx, input)) = queue.lock().unwrap().pop_front() else {
+                        break;
+                    };
+                    let result = if cancel.IsCancelled() {
+                        Err(DSSError::wrapper(DSSErrorKind::Cancelled, "The scenario was cancelled".to_string()))
+                    } else {
+                        catch_unwind(AssertUnwindSafe(|| func(&dss, input))).unwrap_or_else(|err| Err(panic_error(err)))
+                    };
+                    worker_results.push((idx, result.origin("ScenarioPool::Run")));
+                    progress(done.fetch_add(1, Ordering::SeqCst) + 1, total);
+                }
+                worker_results
+            })).collect();
+            for handle in handles {
+                // Panics in the scenarios are caught in the workers; this would only
+                // fail for a panic in the progress callback
+                if let Ok(worker_results) = handle.join() {
+                    for (idx, result) in worker_results {
+                        results[idx] = Some(result);
+                    }
+                }
+            }
+        });
+        results.into_iter()
+            .map(|result| result.unwrap_or_else(|| Err(panic_error(Box::new("The worker thread panicked")))))
+            .collect()
+    }
+}
+
+fn panic_error(err: Box<dyn Any + Send>) -> DSSError {
+    let message = if let Some(message) = err.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = err.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "(no message)".to_string()
+    };
+    DSSError::wrapper(DSSErrorKind::Panic, format!("Panic in a scenario: {}", message))
+}
@@ -0,0 +1,93 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed structs for the DSS classes, generated at build time from
+//! [AltDSS-Schema](https://github.com/dss-extensions/AltDSS-Schema).
+//!
+//! Each DSS class (`Load`, `Line`, `InvControl`...) has a struct with one optional field
+//! per property, using the property names from the schema, and each enumeration has a
+//! Rust enum. The structs follow the JSON produced by the engine through
+//! `IDSSElement::ToJSON` (with the default options, i.e. enums as strings) and can be
+//! pushed back to a context:
+//!
+//! ```ignore
+//! use altdss::schema::{DSSClassType, Load};
+//!
+//! let mut load = Load::Get(&ctx, "ld1")?;
+//! load.kW = Some(1200.0);
+//! load.Push(&ctx, "ld1")?;
+//! ```
+//!
+//! Whole circuits can be converted with `json::CircuitModel::ObjectsAs`.
+//!
+//! The schema file is not shipped with the crate; see `schema/README.md`.
+
+#![allow(non_snake_case)]
+#![allow(non_camel_case_types)]
+
+use crate::common::{DSSContext, DSSError, DSSResultExt};
+use crate::json::{json_error, PushObject};
+use crate::obj::IObj;
+use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Common interface for the generated types of the DSS classes
+pub trait DSSClassType: Serialize + DeserializeOwned {
+    /// Name of the DSS class
+    const CLASS_NAME: &'static str;
+
+    /// Names of the properties covered by the type, in the order they are set in `Push`
+    const PROPERTIES: &'static [&'static str];
+
+    /// Units of the properties, for the properties that have unit metadata in the schema
+    const UNITS: &'static [(&'static str, &'static str)];
+
+    /// Returns the unit of a property, if known
+    fn Unit(property: &str) -> Option<&'static str> {
+        Self::UNITS.iter().find(|(name, _)| name.eq_ignore_ascii_case(property)).map(|(_, unit)| *unit)
+    }
+
+    /// Parses the JSON for an object of this class, as returned by the engine
+    fn FromJSON(json: &str) -> Result<Self, DSSError> {
+        serde_json::from_str(json).map_err(json_error).origin("DSSClassType::FromJSON")
+    }
+
+    fn ToJSON(&self) -> Result<String, DSSError> {
+        serde_json::to_string(self).map_err(json_error).origin("DSSClassType::ToJSON")
+    }
+
+    /// Reads an object of this class from a DSS context
+    fn Get(ctx: &DSSContext, name: &str) -> Result<Self, DSSError> {
+        let obj = IObj::new(ctx).Get(Self::CLASS_NAME, name).origin("DSSClassType::Get")?;
+        Self::FromJSON(&obj.ToJSON(0)?)
+    }
+
+    /// Writes the properties that are set in this instance to an object of a DSS context,
+    /// creating the object if it does not exist.
+    fn Push(&self, ctx: &DSSContext, name: &str) -> Result<(), DSSError> {
+        let Value::Object(values) = serde_json::to_value(self).map_err(json_error).origin("DSSClassType::Push")? else {
+            return Ok(());
+        };
+        // Properties from the schema go first, in order, since some depend on the
+        // previous ones (e.g. the number of phases or points)
+        let ordered = Self::PROPERTIES.iter().filter_map(|name| values.get_key_value(*name));
+        let extra = values.iter().filter(|(name, _)| !Self::PROPERTIES.contains(&name.as_str()));
+        PushObject(ctx, Self::CLASS_NAME, name, ordered.chain(extra)).origin("DSSClassType::Push")
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/schema_types.rs"));
@@ -0,0 +1,194 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Export of the compiled circuit as DSS script text, in memory.
+//!
+//! This uses `ICircuit::Save` with the `ToString` flag, so nothing is written to disk.
+//! The script can be used as a single text, or split in files per class like the
+//! folder tree written by the `save circuit` command:
+//!
+//! ```no_run
+//! use altdss::common::DSSContext;
+//! use altdss::classic::{DSSSaveFlags, IDSS};
+//! use altdss::script::{CircuitScript, DSSSaveOptions};
+//!
+//! let ctx = DSSContext::prime();
+//! let dss = IDSS::new(&ctx);
+//! dss.Command("redirect IEEE13Nodeckt.dss").unwrap();
+//! let options = DSSSaveOptions::new().With(DSSSaveFlags::SetVoltageBases);
+//! let script = CircuitScript::FromCircuit(&dss, options).unwrap();
+//! for file in script.Files() {
+//!     println!("{}: {} lines", file.name, file.text.lines().count());
+//! }
+//! ```
+
+#![allow(non_snake_case)]
+
+use std::fmt;
+use crate::classic::{DSSSaveFlags, IDSS};
+use crate::common::{DSSError, DSSFlags, DSSResultExt};
+
+/// Options for `CircuitScript::FromCircuit`, as a set of `DSSSaveFlags`.
+///
+/// `ToString` and `SingleFile` are always added by `CircuitScript`.
+pub type DSSSaveOptions = DSSFlags<DSSSaveFlags>;
+
+/// A named part of a circuit script, see `CircuitScript::Files`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptFile {
+    pub name: String,
+    pub text: String,
+}
+
+/// The full definition of a circuit as DSS script text
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitScript {
+    text: String,
+}
+
+/// Name of the file for the bus coordinates, as in the `save circuit` output
+const BUSCOORDS_FILE: &str = "BusCoords.dss";
+
+impl CircuitScript {
+    /// Exports the active circuit of a context. The bus coordinates are included as
+    /// `SetBusXY` commands when the engine does not include them.
+    pub fn FromCircuit(dss: &IDSS, options: DSSSaveOptions) -> Result<Self, DSSError> {
+        let options = options.With(DSSSaveFlags::ToString).With(DSSSaveFlags::SingleFile);
+        let mut text = dss.ActiveCircuit.Save("", options.Bits() as u32).origin("CircuitScript::FromCircuit")?;
+        let has_coords = text.lines().any(|line| is_buscoords(line.trim_start()));
+        if !has_coords {
+            let coords = Self::BusCoords(dss).origin("CircuitScript::FromCircuit")?;
+            if !coords.is_empty() {
+                if !text.is_empty() && !text.ends_with('\n') {
+                    text.push('\n');
+                }
+                text.push_str(&coords);
+            }
+        }
+        Ok(Self { text: text })
+    }
+
+    /// Wraps script text, e.g. a script stored previously
+    pub fn from_text(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+
+    /// The whole script, as a single text
+    pub fn Text(&self) -> &str {
+        &self.text
+    }
+
+    /// Splits the script in files, one per DSS class, plus `BusCoords.dss` and `Master.dss`.
+    ///
+    /// `Master.dss` keeps the commands that are not object definitions (the circuit itself,
+    /// options, voltage bases...), with a `Redirect` to each of the other files where the
+    /// respective class first appears, so the original order is preserved.
+    pub fn Files(&self) -> Vec<ScriptFile> {
+        let mut master = String::new();
+        let mut files: Vec<ScriptFile> = Vec::new();
+        // Continuation lines and comments go with the previous command
+        let mut current: Option<usize> = None;
+        for line in self.text.lines() {
+            let trimmed = line.trim_start();
+            let file_name = if trimmed.starts_with('~') || trimmed.starts_with('!') || trimmed.is_empty() {
+                None
+            } else if is_buscoords(trimmed) {
+                Some(BUSCOORDS_FILE.to_string())
+            } else {
+                match new_class(trimmed) {
+                    Some(class_name) if !class_name.eq_ignore_ascii_case("circuit") => Some(format!("{}.dss", class_name)),
+                    _ => {
+                        current = None;
+                        None
+                    },
+                }
+            };
+            if let Some(file_name) = file_name {
+                current = match files.iter().position(|file| file.name.eq_ignore_ascii_case(&file_name)) {
+                    Some(idx) => Some(idx),
+                    None => {
+                        master.push_str(&format!("Redirect {}\n", file_name));
+                        files.push(ScriptFile { name: file_name, text: String::new() });
+                        Some(files.len() - 1)
+                    },
+                };
+            }
+            let target = match current {
+                Some(idx) => &mut files[idx].text,
+                None => &mut master,
+            };
+            target.push_str(line);
+            target.push('\n');
+        }
+        let mut result = Vec::with_capacity(files.len() + 1);
+        result.push(ScriptFile { name: "Master.dss".to_string(), text: master });
+        result.extend(files);
+        result
+    }
+
+    /// Returns a file from `Files` by name (case-insensitive)
+    pub fn File(&self, name: &str) -> Option<ScriptFile> {
+        self.Files().into_iter().find(|file| file.name.eq_ignore_ascii_case(name))
+    }
+
+    fn BusCoords(dss: &IDSS) -> Result<String, DSSError> {
+        let circuit = &dss.ActiveCircuit;
+        // The active bus is activated again afterwards, as in the collection iterators;
+        // nothing to restore if it cannot be read
+        let previous = circuit.ActiveBus.Name().ok().filter(|name| !name.is_empty());
+        let walk = || -> Result<String, DSSError> {
+            let mut coords = String::new();
+            for idx in 0..circuit.NumBuses()? {
+                circuit.SetActiveBusi(idx)?;
+                let bus = &circuit.ActiveBus;
+                if bus.Coorddefined()? {
+                    coords.push_str(&format!("SetBusXY bus={} x={} y={}\n", bus.Name()?, bus.Get_x()?, bus.Get_y()?));
+                }
+            }
+            Ok(coords)
+        };
+        let coords = walk();
+        if let Some(previous) = previous {
+            let _ = circuit.SetActiveBus(previous);
+        }
+        coords
+    }
+}
+
+impl fmt::Display for CircuitScript {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+fn is_buscoords(line: &str) -> bool {
+    let lower = line.to_ascii_lowercase();
+    lower.starts_with("setbusxy") || lower.starts_with("buscoords")
+}
+
+/// Class name from a `New Class.name ...` command
+fn new_class(line: &str) -> Option<&str> {
+    let (command, rest) = line.split_once(char::is_whitespace)?;
+    if !command.eq_ignore_ascii_case("new") {
+        return None;
+    }
+    let rest = rest.trim_start();
+    let rest = match rest.get(..7) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("object=") => &rest[7..],
+        _ => rest,
+    };
+    rest.split_once('.').map(|(class_name, _)| class_name)
+}
@@ -0,0 +1,354 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Owned copies of the power-flow results, to compare scenarios without keeping
+//! the engine around.
+//!
+//! ```no_run
+//! use altdss::common::DSSContext;
+//! use altdss::classic::IDSS;
+//! use altdss::snapshot::SolutionSnapshot;
+//!
+//! let ctx = DSSContext::prime();
+//! let dss = IDSS::new(&ctx);
+//! dss.Command("redirect master.dss").unwrap();
+//! dss.ActiveCircuit.Solution.Solve().unwrap();
+//! let base = SolutionSnapshot::FromCircuit(&dss).unwrap();
+//! dss.ActiveCircuit.Solution.Set_LoadMult(1.2).unwrap();
+//! dss.ActiveCircuit.Solution.Solve().unwrap();
+//! let heavy = SolutionSnapshot::FromCircuit(&dss).unwrap();
+//! println!("{:?}", heavy.Bus("675").unwrap().nodes);
+//! println!("{}", base.Diff(&heavy, 1e-4));
+//! ```
+
+#![allow(non_snake_case)]
+
+use std::collections::HashMap;
+use std::fmt;
+use num_complex::Complex;
+use crate::classic::IDSS;
+use crate::common::{DSSError, DSSResultExt};
+
+/// Voltage at a node
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeResult {
+    /// Full name of the node, e.g. `"b1.2"`
+    pub name: String,
+    /// Voltage (V)
+    pub voltage: Complex<f64>,
+    /// Voltage magnitude (pu), zero if the bus has no base voltage
+    pub voltage_pu: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusResult {
+    pub name: String,
+    pub nodes: Vec<NodeResult>,
+}
+
+/// Results for a circuit element
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementResult {
+    /// Full name of the element, e.g. `"Line.l1"`
+    pub name: String,
+    pub enabled: bool,
+    /// Total losses, as in `ICircuit::AllElementLosses` (kW, kvar)
+    pub losses: Complex<f64>,
+    pub num_terminals: usize,
+    pub num_conductors: usize,
+    /// Currents (A) for each conductor of each terminal; empty for disabled elements
+    pub currents: Vec<Complex<f64>>,
+    /// Powers (kW, kvar) for each conductor of each terminal; empty for disabled elements
+    pub powers: Vec<Complex<f64>>,
+}
+
+impl ElementResult {
+    /// Total power (kW, kvar) flowing into a terminal, starting at 1
+    pub fn TerminalPower(&self, terminal: usize) -> Option<Complex<f64>> {
+        if terminal < 1 || terminal > self.num_terminals || self.powers.len() < terminal * self.num_conductors {
+            return None;
+        }
+        Some(self.powers[(terminal - 1) * self.num_conductors..terminal * self.num_conductors].iter().sum())
+    }
+}
+
+/// The power-flow state of a circuit after a solution
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolutionSnapshot {
+    pub circuit_name: String,
+    pub converged: bool,
+    pub iterations: i32,
+    /// Time of the latest solution (`ISolution::Process_Time`)
+    pub process_time: f64,
+    /// Total power (kW, kvar) supplied to the circuit
+    pub total_power: Complex<f64>,
+    /// Total losses (W, var)
+    pub losses: Complex<f64>,
+    /// Losses in the lines (kW, kvar)
+    pub line_losses: Complex<f64>,
+    /// Losses in the substation transformers (kW, kvar)
+    pub substation_losses: Complex<f64>,
+    /// Buses in the order of `ICircuit::AllBusNames`
+    pub buses: Vec<BusResult>,
+    /// Circuit elements in the order of `ICircuit::AllElementNames`
+    pub elements: Vec<ElementResult>,
+    bus_index: HashMap<String, usize>,
+    node_index: HashMap<String, (usize, usize)>,
+    element_index: HashMap<String, usize>,
+}
+
+impl SolutionSnapshot {
+    /// Captures the results of the latest solution of the active circuit.
+    ///
+    /// This activates each circuit element in turn, so the active element is not
+    /// preserved.
+    pub fn FromCircuit(dss: &IDSS) -> Result<Self, DSSError> {
+        let circuit = &dss.ActiveCircuit;
+        let solution = &circuit.Solution;
+        let mut snapshot = Self {
+            circuit_name: circuit.Name().origin("SolutionSnapshot::FromCircuit")?,
+            converged: solution.Get_Converged().origin("SolutionSnapshot::FromCircuit")?,
+            iterations: solution.Iterations().origin("SolutionSnapshot::FromCircuit")?,
+            process_time: solution.Process_Time().origin("SolutionSnapshot::FromCircuit")?,
+            total_power: circuit.TotalPower().origin("SolutionSnapshot::FromCircuit")?,
+            losses: circuit.Losses().origin("SolutionSnapshot::FromCircuit")?,
+            line_losses: circuit.LineLosses().origin("SolutionSnapshot::FromCircuit")?,
+            substation_losses: circuit.SubstationLosses().origin("SolutionSnapshot::FromCircuit")?,
+            buses: Vec::new(),
+            elements: Vec::new(),
+            bus_index: HashMap::new(),
+            node_index: HashMap::new(),
+            element_index: HashMap::new(),
+        };
+
+        for name in circuit.AllBusNames().origin("SolutionSnapshot::FromCircuit")?.iter() {
+            snapshot.bus_index.insert(name.to_ascii_lowercase(), snapshot.buses.len());
+            snapshot.buses.push(BusResult {
+                name: name.clone(),
+                nodes: Vec::new(),
+            });
+        }
+        let node_names = circuit.AllNodeNames().origin("SolutionSnapshot::FromCircuit")?;
+        let volts = circuit.AllBusVolts().origin("SolutionSnapshot::FromCircuit")?;
+        let volts_pu = circuit.AllBusVmagPu().origin("SolutionSnapshot::FromCircuit")?;
+        for ((name, voltage), voltage_pu) in node_names.iter().zip(volts.iter()).zip(volts_pu.iter()) {
+            let bus_name = name.split_once('.').map_or(name.as_str(), |(bus, _)| bus).to_ascii_lowercase();
+            let Some(&bus_idx) = snapshot.bus_index.get(&bus_name) else {
+                continue;
+            };
+            let bus = &mut snapshot.buses[bus_idx];
+            snapshot.node_index.insert(name.to_ascii_lowercase(), (bus_idx, bus.nodes.len()));
+            bus.nodes.push(NodeResult {
+                name: name.clone(),
+                voltage: *voltage,
+                voltage_pu: *voltage_pu,
+            });
+        }
+
+        let element_names = circuit.AllElementNames().origin("SolutionSnapshot::FromCircuit")?;
+        let losses = circuit.AllElementLosses().origin("SolutionSnapshot::FromCircuit")?;
+        for (idx, (name, losses)) in element_names.iter().zip(losses.iter()).enumerate() {
+            let element = circuit.get_CktElementsi(idx as i32).origin("SolutionSnapshot::FromCircuit")?;
+            let enabled = element.Get_Enabled().origin("SolutionSnapshot::FromCircuit")?;
+            let (currents, powers) = if enabled {
+                (
+                    element.Currents().origin("SolutionSnapshot::FromCircuit")?.into_vec(),
+                    element.Powers().origin("SolutionSnapshot::FromCircuit")?.into_vec(),
+                )
+            } else {
+                (Vec::new(), Vec::new())
+            };
+            snapshot.element_index.insert(name.to_ascii_lowercase(), snapshot.elements.len());
+            snapshot.elements.push(ElementResult {
+                name: name.clone(),
+                enabled: enabled,
+                losses: *losses,
+                num_terminals: element.NumTerminals().origin("SolutionSnapshot::FromCircuit")? as usize,
+                num_conductors: element.NumConductors().origin("SolutionSnapshot::FromCircuit")? as usize,
+                currents: currents,
+                powers: powers,
+            });
+        }
+        Ok(snapshot)
+    }
+
+    /// Returns a bus by name (case-insensitive)
+    pub fn Bus(&self, name: &str) -> Option<&BusResult> {
+        self.bus_index.get(&name.to_ascii_lowercase()).map(|idx| &self.buses[*idx])
+    }
+
+    /// Returns a node by full name (case-insensitive), e.g. `"b1.2"`
+    pub fn Node(&self, name: &str) -> Option<&NodeResult> {
+        self.node_index.get(&name.to_ascii_lowercase()).map(|(bus, node)| &self.buses[*bus].nodes[*node])
+    }
+
+    /// Returns a circuit element by full name (case-insensitive), e.g. `"Line.l1"`
+    pub fn Element(&self, name: &str) -> Option<&ElementResult> {
+        self.element_index.get(&name.to_ascii_lowercase()).map(|idx| &self.elements[*idx])
+    }
+
+    /// All the nodes, in the order of `ICircuit::AllNodeNames`
+    pub fn Nodes(&self) -> impl Iterator<Item = &NodeResult> {
+        self.buses.iter().flat_map(|bus| bus.nodes.iter())
+    }
+
+    /// Compares the results with another snapshot, usually another scenario of the
+    /// same circuit.
+    ///
+    /// Nodes are reported when the voltage magnitudes differ by more than `tolerance`
+    /// (pu). Elements are reported when the losses or the power at the first terminal
+    /// differ by more than `tolerance`, relative to the largest magnitude (at least 1 kVA).
+    pub fn Diff(&self, other: &SolutionSnapshot, tolerance: f64) -> SnapshotDiff {
+        let mut nodes = Vec::new();
+        for node in self.Nodes() {
+            let after = other.Node(&node.name).map(|other| other.voltage_pu);
+            let changed = match after {
+                Some(after) => (after - node.voltage_pu).abs() > tolerance,
+                None => true,
+            };
+            if changed {
+                nodes.push(NodeChange {
+                    name: node.name.clone(),
+                    before: Some(node.voltage_pu),
+                    after: after,
+                });
+            }
+        }
+        for node in other.Nodes().filter(|node| self.Node(&node.name).is_none()) {
+            nodes.push(NodeChange {
+                name: node.name.clone(),
+                before: None,
+                after: Some(node.voltage_pu),
+            });
+        }
+
+        let differs = |a: Complex<f64>, b: Complex<f64>| (a - b).norm() > tolerance * a.norm().max(b.norm()).max(1.0);
+        let mut elements = Vec::new();
+        for element in &self.elements {
+            let after = other.Element(&element.name);
+            let changed = match after {
+                Some(after) => differs(element.losses, after.losses)
+                    || differs(element.TerminalPower(1).unwrap_or_default(), after.TerminalPower(1).unwrap_or_default()),
+                None => true,
+            };
+            if changed {
+                elements.push(ElementChange {
+                    name: element.name.clone(),
+                    before: Some(ElementState::new(element)),
+                    after: after.map(ElementState::new),
+                });
+            }
+        }
+        for element in other.elements.iter().filter(|element| self.Element(&element.name).is_none()) {
+            elements.push(ElementChange {
+                name: element.name.clone(),
+                before: None,
+                after: Some(ElementState::new(element)),
+            });
+        }
+
+        SnapshotDiff {
+            total_power: (self.total_power, other.total_power),
+            losses: (self.losses, other.losses),
+            converged: (self.converged, other.converged),
+            nodes: nodes,
+            elements: elements,
+        }
+    }
+}
+
+/// Voltage magnitude (pu) of a node in two snapshots; `None` if the node is missing
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeChange {
+    pub name: String,
+    pub before: Option<f64>,
+    pub after: Option<f64>,
+}
+
+/// Summary of the results of an element, for `ElementChange`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElementState {
+    /// Losses (kW, kvar)
+    pub losses: Complex<f64>,
+    /// Power (kW, kvar) at the first terminal
+    pub power: Complex<f64>,
+}
+
+impl ElementState {
+    fn new(element: &ElementResult) -> Self {
+        Self {
+            losses: element.losses,
+            power: element.TerminalPower(1).unwrap_or_default(),
+        }
+    }
+}
+
+/// Results of an element in two snapshots; `None` if the element is missing
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementChange {
+    pub name: String,
+    pub before: Option<ElementState>,
+    pub after: Option<ElementState>,
+}
+
+/// Differences between two snapshots, from `SolutionSnapshot::Diff`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotDiff {
+    /// Total power (kW, kvar), before and after
+    pub total_power: (Complex<f64>, Complex<f64>),
+    /// Total losses (W, var), before and after
+    pub losses: (Complex<f64>, Complex<f64>),
+    pub converged: (bool, bool),
+    pub nodes: Vec<NodeChange>,
+    pub elements: Vec<ElementChange>,
+}
+
+impl SnapshotDiff {
+    /// Returns true if no nodes or elements changed beyond the tolerance
+    pub fn IsEmpty(&self) -> bool {
+        self.nodes.is_empty() && self.elements.is_empty()
+    }
+
+    /// Largest change in the voltage magnitudes (pu) among the nodes present in both snapshots
+    pub fn MaxVoltageChange(&self) -> f64 {
+        self.nodes.iter()
+            .filter_map(|node| Some((node.after? - node.before?).abs()))
+            .fold(0.0, f64::max)
+    }
+}
+
+impl fmt::Display for SnapshotDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (before, after) = self.total_power;
+        writeln!(f, "Total power: {:.3} + j{:.3} -> {:.3} + j{:.3} kVA", before.re, before.im, after.re, after.im)?;
+        let (before, after) = self.losses;
+        writeln!(f, "Losses: {:.3} + j{:.3} -> {:.3} + j{:.3} kVA", before.re / 1000.0, before.im / 1000.0, after.re / 1000.0, after.im / 1000.0)?;
+        if self.converged.0 != self.converged.1 {
+            writeln!(f, "Converged: {} -> {}", self.converged.0, self.converged.1)?;
+        }
+        let pu = |value: Option<f64>| value.map_or("(none)".to_string(), |value| format!("{:.5}", value));
+        for node in &self.nodes {
+            writeln!(f, "  {}: {} -> {} pu", node.name, pu(node.before), pu(node.after))?;
+        }
+        let state = |state: Option<ElementState>| state.map_or("(none)".to_string(), |state| format!(
+            "P={:.3} Q={:.3} losses={:.3}", state.power.re, state.power.im, state.losses.re
+        ));
+        for element in &self.elements {
+            writeln!(f, "  {}: {} -> {}", element.name, state(element.before), state(element.after))?;
+        }
+        Ok(())
+    }
+}
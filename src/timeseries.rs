@@ -0,0 +1,373 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Driver for time-series (QSTS) simulations, which solves one time step at a time
+//! and yields the requested quantities for each step as an iterator.
+//!
+//! ```no_run
+//! use altdss::common::DSSContext;
+//! use altdss::classic::{IDSS, SolveModes};
+//! use altdss::timeseries::TimeSeriesRun;
+//!
+//! let ctx = DSSContext::prime();
+//! let dss = IDSS::new(&ctx);
+//! dss.Command("redirect master.dss").unwrap();
+//! let run = TimeSeriesRun::new(&dss)
+//!     .Mode(SolveModes::Daily)
+//!     .StepSizeMin(15.0)
+//!     .Steps(96)
+//!     .BusVoltagesPu("675")
+//!     .ElementPowers("Line.650632")
+//!     .MeterRegisters("feeder")
+//!     .Custom("loss_kW", |dss| Ok(vec![dss.ActiveCircuit.Losses()?.re / 1000.0]))
+//!     .StopWhen(|record| !record.converged);
+//! for record in run.Run().unwrap() {
+//!     let record = record.unwrap();
+//!     println!("{:.2} h: {:?}", record.hour, record.Get("loss_kW"));
+//! }
+//! ```
+//!
+//! Each call to `Iterator::next` solves a single step (`ISolution::Solve` with
+//! `Number` set to 1); dropping the iterator stops the simulation, and
+//! `TimeSeriesRun::Resume` continues it later from a given hour.
+
+#![allow(non_snake_case)]
+
+use std::sync::Arc;
+use num_complex::Complex;
+use crate::classic::{ControlModes, IDSS, SolveModes};
+use crate::common::{DSSError, DSSResultExt};
+use crate::obj::not_found;
+
+type CustomQuantity<'a, 'd> = Box<dyn FnMut(&IDSS<'a>) -> Result<Vec<f64>, DSSError> + 'd>;
+type StopCondition<'d> = Box<dyn FnMut(&StepRecord) -> bool + 'd>;
+
+/// A quantity to collect at each time step
+enum Quantity<'a, 'd> {
+    BusVoltages(String),
+    BusVoltagesPu(String),
+    ElementPowers(String),
+    MeterRegisters(String),
+    Custom(CustomQuantity<'a, 'd>),
+}
+
+/// Values of a quantity at a time step
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepValue {
+    Real(Vec<f64>),
+    Complex(Vec<Complex<f64>>),
+}
+
+impl StepValue {
+    pub fn AsReal(&self) -> Option<&[f64]> {
+        match self {
+            StepValue::Real(values) => Some(values),
+            StepValue::Complex(_) => None,
+        }
+    }
+
+    pub fn AsComplex(&self) -> Option<&[Complex<f64>]> {
+        match self {
+            StepValue::Complex(values) => Some(values),
+            StepValue::Real(_) => None,
+        }
+    }
+}
+
+/// Results of a time step
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepRecord {
+    /// Number of the step in this run, starting at 1
+    pub step: usize,
+    /// Time of the solution, in hours (`ISolution::Get_dblHour`)
+    pub hour: f64,
+    pub converged: bool,
+    pub iterations: i32,
+    /// Values of the quantities, in the order they were declared
+    pub values: Vec<StepValue>,
+    names: Arc<[String]>,
+}
+
+impl StepRecord {
+    /// Values of a quantity by name; see `TimeSeriesRun` for the names of the built-in quantities
+    pub fn Get(&self, name: &str) -> Option<&StepValue> {
+        self.names.iter().position(|n| n == name).map(|idx| &self.values[idx])
+    }
+
+    pub fn Names(&self) -> &[String] {
+        &self.names
+    }
+}
+
+/// Configuration of a time-series simulation, see the module documentation.
+///
+/// The defaults match a daily simulation with 15-minute steps: `SolveModes::Daily`,
+/// 96 steps, starting at hour 0, with the energy meters reset before the first step.
+///
+/// The built-in quantities are named after the respective classic API functions:
+/// `"Voltages(<bus>)"`, `"puVoltages(<bus>)"`, `"Powers(<element>)"` and
+/// `"Registers(<meter>)"`.
+pub struct TimeSeriesRun<'a, 'd> {
+    dss: &'d IDSS<'a>,
+    mode: SolveModes,
+    step_size: f64,
+    start_hour: f64,
+    steps: usize,
+    control_mode: Option<ControlModes>,
+    reset_meters: bool,
+    names: Vec<String>,
+    quantities: Vec<Quantity<'a, 'd>>,
+    stop_when: Option<StopCondition<'d>>,
+}
+
+impl<'a, 'd> TimeSeriesRun<'a, 'd> {
+    pub fn new(dss: &'d IDSS<'a>) -> Self {
+        Self {
+            dss: dss,
+            mode: SolveModes::Daily,
+            step_size: 900.0,
+            start_hour: 0.0,
+            steps: 96,
+            control_mode: None,
+            reset_meters: true,
+            names: Vec::new(),
+            quantities: Vec::new(),
+            stop_when: None,
+        }
+    }
+
+    /// Solution mode, e.g. `SolveModes::Daily`, `SolveModes::Yearly` or `SolveModes::Time`
+    pub fn Mode(mut self, mode: SolveModes) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Step size, in seconds
+    pub fn StepSize(mut self, seconds: f64) -> Self {
+        self.step_size = seconds;
+        self
+    }
+
+    /// Step size, in minutes
+    pub fn StepSizeMin(self, minutes: f64) -> Self {
+        self.StepSize(minutes * 60.0)
+    }
+
+    /// Time before the first step, in hours. As in the engine, the time is incremented
+    /// before each solution, so the first step is solved at `hour` plus the step size.
+    pub fn StartHour(mut self, hour: f64) -> Self {
+        self.start_hour = hour;
+        self
+    }
+
+    pub fn Steps(mut self, steps: usize) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    /// Control mode for the run; by default, the current control mode is kept
+    pub fn ControlMode(mut self, mode: ControlModes) -> Self {
+        self.control_mode = Some(mode);
+        self
+    }
+
+    /// Continues a previous run from the given hour (usually the `hour` of its last
+    /// record), without resetting the energy meters
+    pub fn Resume(mut self, hour: f64) -> Self {
+        self.start_hour = hour;
+        self.reset_meters = false;
+        self
+    }
+
+    /// Complex voltages (V) at each node of a bus
+    pub fn BusVoltages(mut self, bus: impl Into<String>) -> Self {
+        let bus = bus.into();
+        self.names.push(format!("Voltages({})", bus));
+        self.quantities.push(Quantity::BusVoltages(bus));
+        self
+    }
+
+    /// Complex voltages (pu) at each node of a bus
+    pub fn BusVoltagesPu(mut self, bus: impl Into<String>) -> Self {
+        let bus = bus.into();
+        self.names.push(format!("puVoltages({})", bus));
+        self.quantities.push(Quantity::BusVoltagesPu(bus));
+        self
+    }
+
+    /// Complex powers (kVA) at each conductor of a circuit element, by full name
+    pub fn ElementPowers(mut self, element: impl Into<String>) -> Self {
+        let element = element.into();
+        self.names.push(format!("Powers({})", element));
+        self.quantities.push(Quantity::ElementPowers(element));
+        self
+    }
+
+    /// Register values of an energy meter, in the order of `IMeters::RegisterNames`
+    pub fn MeterRegisters(mut self, meter: impl Into<String>) -> Self {
+        let meter = meter.into();
+        self.names.push(format!("Registers({})", meter));
+        self.quantities.push(Quantity::MeterRegisters(meter));
+        self
+    }
+
+    /// Collects the values returned by a closure, called after each solution
+    pub fn Custom<F>(mut self, name: impl Into<String>, func: F) -> Self
+    where
+        F: FnMut(&IDSS<'a>) -> Result<Vec<f64>, DSSError> + 'd
+    {
+        self.names.push(name.into());
+        self.quantities.push(Quantity::Custom(Box::new(func)));
+        self
+    }
+
+    /// Ends the run after the first record for which `func` returns true; the record
+    /// itself is still yielded
+    pub fn StopWhen<F>(mut self, func: F) -> Self
+    where
+        F: FnMut(&StepRecord) -> bool + 'd
+    {
+        self.stop_when = Some(Box::new(func));
+        self
+    }
+
+    /// Prepares the solution and returns the iterator over the steps
+    pub fn Run(self) -> Result<TimeSeriesSteps<'a, 'd>, DSSError> {
+        let solution = &self.dss.ActiveCircuit.Solution;
+        // Setting the mode resets the step size and the time, so it goes first
+        solution.Set_Mode(self.mode).origin("TimeSeriesRun::Run")?;
+        if let Some(control_mode) = self.control_mode {
+            solution.Set_ControlMode(control_mode).origin("TimeSeriesRun::Run")?;
+        }
+        solution.Set_StepSize(self.step_size).origin("TimeSeriesRun::Run")?;
+        solution.Set_Number(1).origin("TimeSeriesRun::Run")?;
+        solution.Set_dblHour(self.start_hour).origin("TimeSeriesRun::Run")?;
+        if self.reset_meters {
+            self.dss.ActiveCircuit.Meters.ResetAll().origin("TimeSeriesRun::Run")?;
+        }
+        Ok(TimeSeriesSteps {
+            dss: self.dss,
+            names: self.names.into(),
+            quantities: self.quantities,
+            stop_when: self.stop_when,
+            steps: self.steps,
+            step: 0,
+            hour: self.start_hour,
+            done: false,
+        })
+    }
+}
+
+/// Iterator over the steps of a time-series simulation, from `TimeSeriesRun::Run`.
+///
+/// Steps that do not converge are not errors: they are yielded with `converged` set
+/// to false. The iteration ends after the first error.
+pub struct TimeSeriesSteps<'a, 'd> {
+    dss: &'d IDSS<'a>,
+    names: Arc<[String]>,
+    quantities: Vec<Quantity<'a, 'd>>,
+    stop_when: Option<StopCondition<'d>>,
+    steps: usize,
+    step: usize,
+    hour: f64,
+    done: bool,
+}
+
+impl<'a, 'd> TimeSeriesSteps<'a, 'd> {
+    /// Number of steps solved so far
+    pub fn StepsDone(&self) -> usize {
+        self.step
+    }
+
+    /// Time of the last solved step (or the start time), in hours; pass it to
+    /// `TimeSeriesRun::Resume` to continue the simulation later
+    pub fn Hour(&self) -> f64 {
+        self.hour
+    }
+
+    fn SolveStep(&mut self) -> Result<StepRecord, DSSError> {
+        let circuit = &self.dss.ActiveCircuit;
+        let solution = &circuit.Solution;
+        let converged = match solution.Solve() {
+            Ok(()) => solution.Get_Converged()?,
+            // The engine reports some convergence failures as errors too
+            Err(err) => if solution.Get_Converged()? { return Err(err) } else { false },
+        };
+        self.step += 1;
+        self.hour = solution.Get_dblHour()?;
+        let iterations = solution.Iterations()?;
+        let mut values = Vec::with_capacity(self.quantities.len());
+        for quantity in self.quantities.iter_mut() {
+            values.push(match quantity {
+                Quantity::BusVoltages(bus) => {
+                    activate_bus(self.dss, bus)?;
+                    StepValue::Complex(circuit.ActiveBus.Voltages()?.into_vec())
+                },
+                Quantity::BusVoltagesPu(bus) => {
+                    activate_bus(self.dss, bus)?;
+                    StepValue::Complex(circuit.ActiveBus.puVoltages()?.into_vec())
+                },
+                Quantity::ElementPowers(element) => {
+                    if circuit.SetActiveElement(element.as_str())? < 0 {
+                        return Err(not_found(format!("Element \"{}\" not found", element)));
+                    }
+                    StepValue::Complex(circuit.ActiveCktElement.Powers()?.into_vec())
+                },
+                Quantity::MeterRegisters(meter) => {
+                    circuit.Meters.Set_Name(meter.as_str())?;
+                    if !circuit.Meters.Get_Name()?.eq_ignore_ascii_case(meter) {
+                        return Err(not_found(format!("EnergyMeter \"{}\" not found", meter)));
+                    }
+                    StepValue::Real(circuit.Meters.RegisterValues()?.into_vec())
+                },
+                Quantity::Custom(func) => StepValue::Real(func(self.dss)?),
+            });
+        }
+        Ok(StepRecord {
+            step: self.step,
+            hour: self.hour,
+            converged: converged,
+            iterations: iterations,
+            values: values,
+            names: self.names.clone(),
+        })
+    }
+}
+
+impl<'a, 'd> Iterator for TimeSeriesSteps<'a, 'd> {
+    type Item = Result<StepRecord, DSSError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.step >= self.steps {
+            return None;
+        }
+        let result = self.SolveStep().origin("TimeSeriesSteps::next");
+        match &result {
+            Ok(record) => if let Some(stop_when) = self.stop_when.as_mut() {
+                self.done = stop_when(record);
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+fn activate_bus(dss: &IDSS, bus: &str) -> Result<(), DSSError> {
+    if dss.ActiveCircuit.SetActiveBus(bus)? < 0 {
+        return Err(not_found(format!("Bus \"{}\" not found", bus)));
+    }
+    Ok(())
+}
@@ -0,0 +1,79 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example reads and writes the loads of a circuit in bulk with the batch API.
+
+extern crate altdss;
+
+use altdss::batch::{BatchOperation, DSSBatch};
+use altdss::common::{DSSError, DSSErrorKind, DSSContext};
+use altdss::classic::IDSS;
+use altdss::obj::IObj;
+
+fn edit_loads(ctx: &DSSContext, dss: &IDSS, obj: &IObj) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.test basekv=12.47
+        new line.l1 bus1=sourcebus bus2=b1 length=1 units=km
+        set voltagebases=[12.47]
+    ")?;
+    for i in 0..20 {
+        dss.Command(format!("new load.ld{} bus1=b1 kv=12.47 kw=10 kvar=3 model={}", i, 1 + i % 2))?;
+    }
+    dss.Command("calcv")?;
+
+    let loads = obj.Class("Load")?.Batch()?;
+    assert_eq!(loads.Count(), 20);
+    loads.Float64Operation("kW", BatchOperation::Multiply, 2.0)?;
+    assert!(loads.GetFloat64("kW")?.iter().all(|kW| *kW == 20.0));
+
+    let kW: Vec<f64> = (0..20).map(|i| i as f64).collect();
+    loads.SetFloat64Array("kW", &kW)?;
+    let names = loads.Names()?;
+    assert_eq!(&loads.GetFloat64("kW")?[..], &kW[..]);
+    assert_eq!(names[3], "ld3");
+
+    let err = loads.SetFloat64Array("kW", &[1.0]).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::InvalidArgument);
+
+    let model2 = loads.FilterByInt32Property("model", 2)?;
+    assert_eq!(model2.Count(), 10);
+    assert!(model2.GetInt32("model")?.iter().all(|model| *model == 2));
+
+    let some = obj.Class("Load")?.BatchByNames(&["ld5", "ld1"])?;
+    assert_eq!(&some.Names()?[..], &["ld5", "ld1"]);
+    some.SetString("status", "fixed")?;
+    assert!(some.GetAsString("status")?.iter().all(|status| status.eq_ignore_ascii_case("fixed")));
+
+    // Batches from a list of objects must use a single class
+    let objs = vec![obj.Get("Load", "ld7")?, obj.Get("Load", "ld2")?];
+    let from_objs = DSSBatch::from_objects(ctx, &objs)?;
+    assert_eq!(&from_objs.Names()?[..], &["ld7", "ld2"]);
+    let mixed = vec![obj.Get("Load", "ld7")?, obj.Get("Line", "l1")?];
+    let err = DSSBatch::from_objects(ctx, &mixed).err().unwrap();
+    assert_eq!(err.kind, DSSErrorKind::InvalidArgument);
+
+    dss.ActiveCircuit.Solution.Solve()?;
+    Ok(())
+}
+
+#[test]
+fn batch() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    let obj = IObj::new(&ctx);
+    edit_loads(&ctx, &dss, &obj).unwrap();
+}
@@ -0,0 +1,67 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example creates DSS contexts with the builder, using the modern
+/// defaults, and checks that global options require an explicit opt-in.
+
+extern crate altdss;
+
+use altdss::builder::DSSContextBuilder;
+use altdss::common::{DSSError, DSSErrorKind};
+use altdss::classic::IDSS;
+
+fn check_builder() -> Result<(), DSSError> {
+    let ctx = DSSContextBuilder::modern().AllowForms(true).Build()?;
+    let dss = IDSS::new(&ctx);
+    assert!(!dss.Get_AllowChangeDir()?);
+    assert!(!dss.Get_AllowEditor()?);
+    assert!(dss.Get_AllowForms()?);
+    assert!(!dss.Get_COMErrorResults()?);
+    assert!(dss.Error.Get_ExtendedErrors()?);
+
+    // The compatibility flags affect all contexts, so they are refused by default
+    let err = DSSContextBuilder::new().CompatFlags(0).Apply(&ctx).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::GlobalOption);
+
+    // Nothing was applied from the refused builder
+    DSSContextBuilder::new().AllowForms(false).CompatFlags(0).Apply(&ctx).unwrap_err();
+    assert!(dss.Get_AllowForms()?);
+
+    // An option that fails in the engine, after others were applied: the engine
+    // does not create the parents of the data path
+    let data_path = dss.Get_DataPath()?;
+    let err = DSSContextBuilder::new()
+        .AllowForms(false)
+        .ExtendedErrors(false)
+        .EarlyAbort(false)
+        .DataPath("/nonexistent-altdss-folder/data")
+        .Apply(&ctx)
+        .unwrap_err();
+    assert!(matches!(err.kind, DSSErrorKind::Engine(_)));
+    assert!(dss.Get_AllowForms()?);
+    assert!(dss.Error.Get_ExtendedErrors()?);
+    assert!(dss.Error.Get_EarlyAbort()?);
+    assert_eq!(dss.Get_DataPath()?, data_path);
+
+    let flags = dss.Get_CompatFlags()?;
+    DSSContextBuilder::new().CompatFlags(flags).AllowGlobalOptions(true).Apply(&ctx)?;
+    Ok(())
+}
+
+#[test]
+fn builder() {
+    check_builder().unwrap();
+}
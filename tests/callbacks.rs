@@ -0,0 +1,194 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example registers message and progress callbacks on two contexts, checking
+/// that each closure only receives the updates of its own context, and that nothing
+/// is delivered after removing the callbacks.
+
+extern crate altdss;
+
+use std::sync::{Arc, Mutex};
+use altdss::callbacks::{DSSMessage, DSSMessageSeverity, DSSProgress};
+use altdss::common::{DSSError, DSSContext};
+use altdss::classic::IDSS;
+
+#[derive(Default)]
+struct Received {
+    messages: Mutex<Vec<DSSMessage>>,
+    progress: Mutex<Vec<DSSProgress>>,
+}
+
+fn register(ctx: &DSSContext) -> Arc<Received> {
+    let received = Arc::new(Received::default());
+    let target = received.clone();
+    ctx.SetMessageCallback(move |msg| target.messages.lock().unwrap().push(msg.clone()));
+    let target = received.clone();
+    ctx.SetProgressCallback(move |progress| target.progress.lock().unwrap().push(progress.clone()));
+    received
+}
+
+/// Emits an error message and a progress update in the context
+fn emit(dss: &IDSS, caption: &str) -> Result<(), DSSError> {
+    assert!(dss.Command("foo bar").is_err());
+    dss.DSSProgress.Set_Caption(caption)
+}
+
+fn counts(received: &Received) -> (usize, usize) {
+    (received.messages.lock().unwrap().len(), received.progress.lock().unwrap().len())
+}
+
+fn route_messages(ctx1: &DSSContext, ctx2: &DSSContext) -> Result<(), DSSError> {
+    let (dss1, dss2) = (IDSS::new(ctx1), IDSS::new(ctx2));
+    // The engine only emits the messages when forms are allowed
+    dss1.Set_AllowForms(true)?;
+    dss2.Set_AllowForms(true)?;
+    let received1 = register(ctx1);
+    let received2 = register(ctx2);
+
+    emit(&dss1, "first")?;
+    let (messages1, progress1) = counts(&received1);
+    assert!(messages1 > 0 && progress1 > 0);
+    assert_eq!(counts(&received2), (0, 0));
+    assert!(received1.messages.lock().unwrap().iter().any(|msg| msg.severity == DSSMessageSeverity::Error));
+    assert!(received1.progress.lock().unwrap().iter().any(|progress| matches!(progress, DSSProgress::Caption(text) if text == "first")));
+
+    emit(&dss2, "second")?;
+    assert_eq!(counts(&received1), (messages1, progress1));
+    let (messages2, progress2) = counts(&received2);
+    assert!(messages2 > 0 && progress2 > 0);
+    assert!(received2.progress.lock().unwrap().iter().all(|progress| !matches!(progress, DSSProgress::Caption(text) if text == "first")));
+
+    // Nothing is delivered after removing the callbacks
+    ctx1.ClearCallbacks();
+    emit(&dss1, "third")?;
+    assert_eq!(counts(&received1), (messages1, progress1));
+    emit(&dss2, "fourth")?;
+    assert!(counts(&received2).1 > progress2);
+    ctx2.ClearCallbacks();
+    let after = counts(&received2);
+    emit(&dss2, "fifth")?;
+    assert_eq!(counts(&received2), after);
+
+    dss1.Set_AllowForms(false)?;
+    Ok(())
+}
+
+#[test]
+fn message_callbacks() {
+    let ctx1 = DSSContext::prime();
+    let ctx2 = IDSS::new(&ctx1).NewContext().unwrap();
+    route_messages(&ctx1, &ctx2).unwrap();
+}
+
+#[cfg(feature = "log")]
+mod log_adapter {
+    use std::sync::Mutex;
+    use altdss::callbacks::forward_to_log;
+    use altdss::common::DSSContext;
+    use altdss::classic::IDSS;
+
+    static RECORDS: Mutex<Vec<(log::Level, String)>> = Mutex::new(Vec::new());
+
+    struct TestLogger;
+
+    impl log::Log for TestLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.target() == "altdss"
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                RECORDS.lock().unwrap().push((record.level(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {
+        }
+    }
+
+    #[test]
+    fn forward_messages_to_log() {
+        log::set_logger(&TestLogger).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+        let ctx = IDSS::new(&DSSContext::prime()).NewContext().unwrap();
+        let dss = IDSS::new(&ctx);
+        dss.Set_AllowForms(true).unwrap();
+        forward_to_log(&ctx);
+        assert!(dss.Command("foo bar").is_err());
+        dss.DSSProgress.Set_Caption("logged").unwrap();
+        let records = RECORDS.lock().unwrap();
+        assert!(records.iter().any(|(level, _)| *level == log::Level::Error));
+        assert!(records.iter().any(|(level, text)| *level == log::Level::Trace && text.contains("logged")));
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod tracing_adapter {
+    use std::sync::{Arc, Mutex};
+    use tracing::{Event, Level, Metadata, Subscriber};
+    use tracing::span::{Attributes, Id, Record};
+    use altdss::callbacks::forward_to_tracing;
+    use altdss::common::DSSContext;
+    use altdss::classic::IDSS;
+
+    /// Minimal subscriber that keeps the level of each event for the `altdss` target
+    struct TestSubscriber {
+        levels: Arc<Mutex<Vec<Level>>>,
+    }
+
+    impl Subscriber for TestSubscriber {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == "altdss"
+        }
+
+        fn new_span(&self, _span: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {
+        }
+
+        fn event(&self, event: &Event<'_>) {
+            self.levels.lock().unwrap().push(*event.metadata().level());
+        }
+
+        fn enter(&self, _span: &Id) {
+        }
+
+        fn exit(&self, _span: &Id) {
+        }
+    }
+
+    #[test]
+    fn forward_messages_to_tracing() {
+        let levels = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = TestSubscriber { levels: levels.clone() };
+        tracing::subscriber::with_default(subscriber, || {
+            let ctx = IDSS::new(&DSSContext::prime()).NewContext().unwrap();
+            let dss = IDSS::new(&ctx);
+            dss.Set_AllowForms(true).unwrap();
+            forward_to_tracing(&ctx);
+            assert!(dss.Command("foo bar").is_err());
+            dss.DSSProgress.Set_Caption("traced").unwrap();
+        });
+        let levels = levels.lock().unwrap();
+        assert!(levels.contains(&Level::ERROR));
+        assert!(levels.contains(&Level::TRACE));
+    }
+}
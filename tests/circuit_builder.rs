@@ -0,0 +1,94 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example builds part of the IEEE13 feeder with the typed circuit builder,
+/// through both the DSS commands and the object API, and checks the validation.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSErrorKind, DSSContext};
+use altdss::classic::{IDSS, LineUnits, LoadModels};
+use altdss::circuit::*;
+
+fn feeder() -> Circuit {
+    Circuit::new("ieee13_part", 115.0)
+        .BaseFrequency(60.0)
+        .pu(1.0001)
+        .Angle(30.0)
+        .ShortCircuit(20000.0, 21000.0)
+        .Transformer(TransformerDef::new("sub")
+            .XHL(0.008)
+            .Winding(WindingDef::new("sourcebus", 115.0, 5000.0).Conn(Connection::Delta).pctR(0.0005))
+            .Winding(WindingDef::new("650", 4.16, 5000.0).pctR(0.0005))
+        )
+        .LineCode(LineCodeDef::new("mtx601", 3, LineUnits::Miles)
+            .Matrix(&[0.3465, 0.1560, 0.3375, 0.1580, 0.1535, 0.3414], &[1.0179, 0.5017, 1.0478, 0.4236, 0.3849, 1.0348])
+        )
+        .LineCode(LineCodeDef::new("mtx603", 2, LineUnits::Miles).Matrix(&[1.3238, 0.2066, 1.3294], &[1.3569, 0.4591, 1.3471]))
+        .Line(LineDef::new("650632", "650.1.2.3", "632.1.2.3").LineCode("mtx601").Length(2000.0, LineUnits::ft))
+        .Line(LineDef::new("632645", "632.3.2", "645.3.2").LineCode("mtx603").Length(500.0, LineUnits::ft))
+        .Load(LoadDef::new("632", "632.1.2.3", 4.16, 600.0).kvar(350.0))
+        .Load(LoadDef::new("645", "645.2", 2.4, 170.0).Phases(1).kvar(125.0))
+        .Load(LoadDef::new("646", "645.2.3", 4.16, 230.0).Phases(1).Conn(Connection::Delta).Model(LoadModels::ConstZ).kvar(132.0))
+        .Capacitor(CapacitorDef::new("cap1", "632", 4.16, 600.0))
+        .VoltageBases(&[115.0, 4.16])
+}
+
+fn build(dss: &IDSS) -> Result<(), DSSError> {
+    let circuit = feeder();
+    let script = circuit.Script()?;
+    assert!(script.contains(&"new Line.632645 phases=2 bus1=632.3.2 bus2=645.3.2 linecode=mtx603 length=500 units=ft".to_string()));
+
+    circuit.Apply(dss)?;
+    dss.ActiveCircuit.Solution.Solve()?;
+    assert!(dss.ActiveCircuit.Solution.Get_Converged()?);
+    assert_eq!(dss.ActiveCircuit.Loads.Count()?, 3);
+    let power = dss.ActiveCircuit.TotalPower()?;
+
+    circuit.ApplyObjects(dss)?;
+    dss.ActiveCircuit.Solution.Solve()?;
+    let power2 = dss.ActiveCircuit.TotalPower()?;
+    assert!((power.re - power2.re).abs() < 1e-6 * power.re.abs());
+    assert!((power.im - power2.im).abs() < 1e-6 * power.im.abs());
+
+    // Problems are reported before anything reaches the engine
+    let invalid = [
+        feeder().Line(LineDef::new("x1", "632.1.2.3", "680.1.2.3").LineCode("mtx602")),
+        feeder().Line(LineDef::new("x2", "632.1.2", "680.1.2").LineCode("mtx601")),
+        feeder().Load(LoadDef::new("x3", "632.1.2.3", 4.16, 10.0).Phases(1)),
+        feeder().Load(LoadDef::new("632", "632", 4.16, 10.0)),
+        feeder().Capacitor(CapacitorDef::new("x4", "632.1.1.2", 4.16, 100.0)),
+        feeder().Transformer(TransformerDef::new("x5").Winding(WindingDef::new("632", 4.16, 500.0))),
+        feeder().Load(LoadDef::new("x6", "632.1.2.3", 4.16, f64::NAN)),
+        feeder().Load(LoadDef::new("x7", "632.1.2.3", 4.16, 10.0).kvar(f64::INFINITY)),
+        feeder().Capacitor(CapacitorDef::new("x8", "632.1.2.3", 4.16, f64::NEG_INFINITY)),
+    ];
+    for circuit in invalid {
+        let err = circuit.Validate().unwrap_err();
+        assert_eq!(err.kind, DSSErrorKind::InvalidArgument);
+        assert_eq!(circuit.Apply(dss).unwrap_err().kind, DSSErrorKind::InvalidArgument);
+    }
+    let err = feeder().Line(LineDef::new("x1", "632", "680").LineCode("mtx602")).Validate().unwrap_err();
+    assert!(err.message.starts_with("Line.x1: "));
+    Ok(())
+}
+
+#[test]
+fn circuit_builder() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    build(&dss).unwrap();
+}
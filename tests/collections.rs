@@ -0,0 +1,98 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example iterates over the collections of the classic API, checking that
+/// the active element is restored afterwards.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSContext};
+use altdss::classic::IDSS;
+
+fn iterate(dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.test basekv=12.47
+        new line.l1 bus1=sourcebus bus2=b1 length=1 units=km
+        new line.l2 bus1=b1 bus2=b2 length=1 units=km
+        set voltagebases=[12.47]
+    ")?;
+    for i in 0..5 {
+        dss.Command(format!("new load.ld{} bus1=b2 kv=12.47 kw={} kvar=3", i, 10 * (i + 1)))?;
+    }
+    dss.Command("calcv")?;
+
+    let loads = &dss.ActiveCircuit.Loads;
+    let mut names = Vec::new();
+    let mut total_kW = 0.0;
+    for load in loads.iter() {
+        load?.With(|load| {
+            names.push(load.Get_Name()?);
+            total_kW += load.Get_kW()?;
+            Ok(())
+        })?;
+    }
+    assert_eq!(&names[..], &loads.AllNames()?[..]);
+    assert_eq!(total_kW, 150.0);
+
+    // Breaking out early keeps the previously active load
+    loads.Set_Name("ld3")?;
+    for load in loads.iter() {
+        if load?.Index() == 2 {
+            break;
+        }
+    }
+    assert_eq!(loads.Get_Name()?, "ld3");
+
+    // Elements can be activated again after the iterator moves on
+    let lines: Vec<_> = dss.ActiveCircuit.Lines.iter().collect::<Result<_, _>>()?;
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].Activate()?.Get_Name()?, "l1");
+
+    // The items activate their element again, even if another one was activated
+    let first = loads.iter().next().unwrap()?;
+    loads.Set_Name("ld4")?;
+    assert_eq!(first.With(|load| load.Get_Name())?, "ld0");
+
+    assert_eq!(dss.ActiveCircuit.Transformers.iter().count(), 0);
+
+    // Collections of several classes use the full names
+    let pd_names = dss.ActiveCircuit.PDElements.iter()
+        .map(|element| element?.With(|pd| pd.Get_Name()))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert!(pd_names.iter().any(|name| name.eq_ignore_ascii_case("Line.l2")));
+
+    let pc_elements = dss.ActiveCircuit.PCElements();
+    let pc_names = pc_elements.iter()
+        .map(|element| element?.With(|pc| pc.CktElement().Name()))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert!(pc_names.iter().any(|name| name.eq_ignore_ascii_case("Load.ld3")));
+    assert!(pc_names.iter().all(|name| !name.to_lowercase().starts_with("line.")));
+
+    dss.SetActiveClass("Line")?;
+    let class_names = dss.ActiveClass.iter()
+        .map(|element| element?.With(|class| class.Get_Name()))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(class_names, vec!["l1".to_string(), "l2".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn test_collections() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    iterate(&dss).unwrap();
+}
@@ -0,0 +1,95 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example forces a few convergence problems on a small feeder and checks
+/// the cause reported by the diagnostics.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSContext};
+use altdss::classic::IDSS;
+use altdss::diagnostics::{ConvergenceCause, ConvergenceReport};
+
+const CIRCUIT: &str = "
+    clear
+    new circuit.diag basekv=12.47
+    new transformer.reg1 phases=3 windings=2 buses=(sourcebus b0) conns=(wye wye) kvs=(12.47 12.47) kvas=(10000 10000) xhl=0.01
+    new regcontrol.reg1 transformer=reg1 winding=2 vreg=126 band=1 ptratio=57.3 maxtapchange=1
+    new line.l1 bus1=b0 bus2=b1 length=3 units=km
+    new line.l2 bus1=b1 bus2=b2 length=1 units=km
+    new load.ld1 bus1=b2 kv=12.47 kw=4000 kvar=1500
+    set voltagebases=[12.47]
+    calcv
+";
+
+fn diagnose(dss: &IDSS) -> Result<(), DSSError> {
+    let solution = &dss.ActiveCircuit.Solution;
+
+    dss.Command(CIRCUIT)?;
+    solution.Solve()?;
+    let report = ConvergenceReport::FromCircuit(dss)?.WithVoltageProbe(dss)?;
+    assert!(report.converged);
+    assert_eq!(report.cause, ConvergenceCause::None);
+    assert!(report.voltage_changes.is_empty());
+
+    // One tap per control iteration is not enough for the regulator
+    dss.Command(CIRCUIT)?;
+    solution.Set_MaxControlIterations(2)?;
+    let _ = solution.Solve();
+    let report = ConvergenceReport::FromCircuit(dss)?;
+    assert_eq!(report.cause, ConvergenceCause::ControlHunting);
+    assert!(report.control_iterations >= 2);
+    assert!(report.to_string().contains("ControlHunting"));
+
+    // A single power-flow iteration with a heavy load
+    dss.Command(CIRCUIT)?;
+    dss.Command("edit load.ld1 kw=20000 kvar=10000")?;
+    solution.Set_MaxIterations(1)?;
+    let _ = solution.Solve();
+    let report = ConvergenceReport::FromCircuit(dss)?;
+    assert!(!report.converged);
+    assert_eq!(report.cause, ConvergenceCause::PowerFlowDivergence);
+    // Without the probe, the solution is not touched
+    assert!(report.voltage_changes.is_empty());
+    assert_eq!(solution.Iterations()?, report.iterations);
+    let report = report.WithVoltageProbe(dss)?;
+    assert!(!report.voltage_changes.is_empty());
+    assert_eq!(solution.Get_MaxIterations()?, 1);
+
+    // An isolated part of the feeder
+    dss.Command(CIRCUIT)?;
+    dss.Command("
+        new line.l3 bus1=b5 bus2=b6 length=1 units=km
+        new load.ld2 bus1=b6 kv=12.47 kw=100
+    ")?;
+    // Same heavy load and single iteration as above, so it cannot converge
+    dss.Command("edit load.ld1 kw=20000 kvar=10000")?;
+    solution.Set_MaxIterations(1)?;
+    let _ = solution.Solve();
+    let report = ConvergenceReport::FromCircuit(dss)?;
+    assert!(!report.converged);
+    assert_eq!(report.cause, ConvergenceCause::Islanding);
+    assert!(report.isolated_loads.iter().any(|name| name.to_lowercase().contains("ld2")));
+    assert!(report.isolated_buses.contains(&"b6".to_string()));
+    Ok(())
+}
+
+#[test]
+fn convergence_report() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    diagnose(&dss).unwrap();
+}
@@ -0,0 +1,124 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "serde")]
+
+/// This example compares two versions of a small circuit, loaded in two contexts,
+/// and two JSON snapshots. Requires the `serde` feature.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSContext};
+use altdss::classic::IDSS;
+use altdss::diff::{CircuitDiff, ClassDiff, DiffOptions};
+use altdss::json::{CircuitModel, ObjectModel};
+
+const CIRCUIT: &str = "
+    clear
+    new circuit.diff_test basekv=12.47
+    new line.l1 bus1=sourcebus bus2=b1 length=1 units=km
+    new line.l2 bus1=b1 bus2=b2 length=1 units=km
+    new load.ld1 bus1=b1 kv=12.47 kw=400 kvar=120
+";
+
+fn compare(dss1: &IDSS, dss2: &IDSS) -> Result<(), DSSError> {
+    dss1.Command(CIRCUIT)?;
+    dss1.Command("set voltagebases=[12.47]\ncalcv")?;
+    dss2.Command(CIRCUIT)?;
+    dss2.Command("
+        new line.l3 bus1=b1 bus2=b3 length=1 units=km
+        edit line.l2 bus1=b3
+        edit load.ld1 kw=450
+        new load.ld2 bus1=b3 kv=12.47 kw=100
+        set voltagebases=[12.47, 0.48]
+        calcv
+    ")?;
+
+    let options = DiffOptions::new();
+    assert!(CircuitDiff::FromContexts(dss1, dss1, &options)?.IsEmpty());
+
+    let diff = CircuitDiff::FromContexts(dss1, dss2, &options)?;
+    let loads = diff.classes.iter().find(|cls| cls.class_name.eq_ignore_ascii_case("Load")).unwrap();
+    assert_eq!(loads.added, ["ld2"]);
+    assert!(loads.removed.is_empty());
+    assert_eq!(loads.changed.len(), 1);
+    let kW = loads.changed[0].properties.iter().find(|change| change.property.eq_ignore_ascii_case("kW")).unwrap();
+    assert_eq!(kW.before.as_ref().and_then(|v| v.as_f64()), Some(400.0));
+    assert_eq!(kW.after.as_ref().and_then(|v| v.as_f64()), Some(450.0));
+
+    let l2 = diff.topology.iter().find(|change| change.element.eq_ignore_ascii_case("Line.l2")).unwrap();
+    assert_eq!(l2.before, ["b1", "b2"]);
+    assert_eq!(l2.after, ["b3", "b2"]);
+    assert_eq!(diff.buses_added, ["b3"]);
+    assert!(diff.buses_removed.is_empty());
+    assert_eq!(diff.voltage_bases, Some((vec![12.47], vec![12.47, 0.48])));
+
+    let report = diff.to_string();
+    assert!(report.contains("  + ld2"));
+    assert!(report.contains("Voltage bases:"));
+
+    // A single class, and the reverse direction
+    let lines = ClassDiff::FromContexts(dss2, dss1, "Line", &options)?;
+    assert_eq!(lines.removed, ["l3"]);
+    assert_eq!(lines.changed.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn diff_contexts() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    let ctx2 = dss.NewContext().unwrap();
+    let dss2 = IDSS::new(&ctx2);
+    compare(&dss, &dss2).unwrap();
+}
+
+#[test]
+fn diff_snapshots() {
+    let before = CircuitModel::FromJSON(r#"{
+        "name": "snap",
+        "Bus": [{"name": "b1", "kVBase": 7.2}, {"name": "b2", "kVBase": 7.2}],
+        "Load": [{"name": "ld1", "bus1": "b1.1", "kW": 10.0, "conn": "wye"}],
+        "Capacitor": [{"name": "c1", "bus1": "b2", "kvar": [300]}]
+    }"#).unwrap();
+    let after = CircuitModel::FromJSON(r#"{
+        "name": "snap",
+        "Bus": [{"name": "b1", "kVBase": 7.2000000001}, {"name": "b2", "kVBase": 0.277}],
+        "Load": [{"name": "LD1", "bus1": "b2.1", "kW": 10.0, "conn": "Wye", "pf": 0.95}]
+    }"#).unwrap();
+
+    assert!(CircuitDiff::new(&before, &before, 1e-9).unwrap().IsEmpty());
+    let diff = CircuitDiff::new(&before, &after, 1e-6).unwrap();
+    assert_eq!(diff.classes.len(), 2);
+    let loads = &diff.classes[0];
+    assert_eq!(loads.changed[0].properties.iter().map(|change| change.property.as_str()).collect::<Vec<_>>(), ["bus1", "pf"]);
+    assert_eq!(diff.classes[1].removed, ["c1"]);
+    assert_eq!(diff.topology[0].after, ["b2"]);
+    assert_eq!(diff.bus_bases.len(), 1);
+    assert_eq!(diff.bus_bases[0].bus, "b2");
+    assert!(diff.voltage_bases.is_none());
+
+    // Names and property names are matched without case, regardless of the order
+    let loads: Vec<ObjectModel> = (0..2000).map(|idx| serde_json::from_value(serde_json::json!({
+        "name": format!("ld{}", idx), "kW": idx as f64
+    })).unwrap()).collect();
+    let reordered: Vec<ObjectModel> = loads.iter().rev().map(|obj| serde_json::from_value(serde_json::json!({
+        "name": obj.name.to_ascii_uppercase(), "KW": obj.properties["kW"]
+    })).unwrap()).collect();
+    assert!(ClassDiff::new("Load", &loads, &reordered, 1e-9).IsEmpty());
+    let diff = ClassDiff::new("Load", &loads, &reordered[1..], 1e-9);
+    assert_eq!((diff.removed, diff.added.len(), diff.changed.len()), (vec!["ld1999".to_string()], 0, 0));
+}
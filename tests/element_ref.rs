@@ -0,0 +1,86 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example keeps references to a few elements while other elements are
+/// activated, and checks that the references are invalidated by removing the element
+/// or clearing the circuit.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSErrorKind, DSSContext};
+use altdss::classic::IDSS;
+use altdss::element::ElementRef;
+use altdss::obj::IObj;
+
+fn element_refs(ctx: &DSSContext, dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.test basekv=12.47
+        new line.l1 bus1=sourcebus bus2=b1 length=1 units=km
+        new load.ld1 bus1=b1 kv=12.47 kw=100 kvar=30
+        new load.ld2 bus1=b1 kv=12.47 kw=200 kvar=60
+        set voltagebases=[12.47]
+        calcv
+    ")?;
+    dss.ActiveCircuit.Solution.Solve()?;
+
+    let ld2 = ElementRef::new(ctx, "load", "LD2")?;
+    assert_eq!(ld2.FullName(), "Load.ld2");
+    let line = ElementRef::from_index(ctx, "Line", 1)?;
+    assert_eq!(line.Name(), "l1");
+
+    let loads = &dss.ActiveCircuit.Loads;
+    loads.Set_Name("ld1")?;
+    assert_eq!(ld2.WithInterface(loads, |load| load.Get_kW())?, 200.0);
+    assert_eq!(loads.Get_Name()?, "ld2");
+
+    dss.ActiveCircuit.SetActiveElement("Load.ld1")?;
+    assert!(ld2.WithCktElement(|element| element.Name())?.eq_ignore_ascii_case("Load.ld2"));
+    assert_eq!(line.WithCktElement(|element| element.NumTerminals())?, 2);
+    assert!(line.WithDSSElement(|element| element.Name())?.eq_ignore_ascii_case("Line.l1"));
+
+    let err = line.WithInterface(loads, |load| load.Get_kW()).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::InvalidArgument);
+    let err = ElementRef::new(ctx, "Load", "ld3").err().unwrap();
+    assert_eq!(err.kind, DSSErrorKind::NotFound);
+
+    // Removed elements still resolve by name in the engine, but not through the reference
+    let ld1 = ElementRef::new(ctx, "Load", "ld1")?;
+    ld1.Object()?.Disable()?;
+    assert!(!ld1.IsValid());
+    let err = ld1.WithInterface(loads, |load| load.Get_kW()).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::StaleReference);
+    assert_eq!(IObj::new(ctx).Get("Load", "ld1")?.Name()?, "ld1");
+    assert!(ld2.IsValid());
+
+    // Same names, but new elements after the clear
+    dss.Command("
+        clear
+        new circuit.test basekv=12.47
+        new load.ld2 bus1=sourcebus kv=12.47 kw=50 kvar=10
+    ")?;
+    assert!(!ld2.IsValid());
+    let err = ld2.WithInterface(loads, |load| load.Get_kW()).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::StaleReference);
+    Ok(())
+}
+
+#[test]
+fn element_ref() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    element_refs(&ctx, &dss).unwrap();
+}
@@ -0,0 +1,59 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example stores owned engines in a struct, moves them to threads
+/// and back, without having to manage the DSS contexts separately.
+
+extern crate altdss;
+
+use altdss::common::DSSError;
+use altdss::engine::Engine;
+use std::thread;
+
+struct Study {
+    name: String,
+    engine: Engine,
+}
+
+fn build_study(name: &str) -> Result<Study, DSSError> {
+    let engine = Engine::new()?;
+    engine.dss().Command(format!("
+        new circuit.{name} basekv=12.47
+        new line.l1 bus1=sourcebus bus2=b1 length=1 units=km
+        new load.ld1 bus1=b1 kv=12.47 kw=1000 kvar=300
+        set voltagebases=[12.47]
+        calcv
+    "))?;
+    Ok(Study { name: name.to_string(), engine: engine })
+}
+
+#[test]
+fn engine_threads() {
+    let studies: Vec<Study> = ["study_a", "study_b"].iter().map(|name| build_study(name).unwrap()).collect();
+    let children: Vec<_> = studies.into_iter().map(|study| {
+        thread::spawn(move || {
+            study.engine.dss().ActiveCircuit.Solution.Solve().unwrap();
+            study
+        })
+    }).collect();
+
+    for child in children {
+        let study = child.join().unwrap();
+        let circ = &study.engine.dss().ActiveCircuit;
+        assert_eq!(circ.Name().unwrap(), study.name);
+        assert!(circ.Solution.Get_Converged().unwrap());
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example checks the classification of the errors reported by the engine,
+/// the command recorded for a list of commands, the strings with interior NULs and
+/// the decoding of enum codes.
+
+extern crate altdss;
+
+use altdss::common::{DSSContext, DSSError, DSSErrorCategory, DSSErrorKind};
+use altdss::classic::{IDSS, SolveModes};
+
+#[test]
+fn error_kinds() {
+    use DSSErrorCategory::*;
+    let kind = |number, message| DSSErrorKind::from_engine(number, message);
+
+    // Known numbers take precedence over the message
+    assert_eq!(kind(8888, "There is no active circuit! Create a circuit and retry."), DSSErrorKind::Engine(NoActiveCircuit));
+    assert_eq!(kind(8989, "No active Load object found! Activate one and retry."), DSSErrorKind::Engine(NoActiveElement));
+    assert_eq!(kind(485, "Some other text"), DSSErrorKind::Engine(Convergence));
+
+    // Whole words only
+    assert_eq!(kind(1, "Solution did not converge"), DSSErrorKind::Engine(Convergence));
+    assert_eq!(kind(1, "Redirect file \"x.dss\" not found"), DSSErrorKind::Engine(File));
+    assert_eq!(kind(1, "Error in the load profile"), DSSErrorKind::Engine(Other));
+    assert_eq!(kind(1, "Sparse matrix is singular"), DSSErrorKind::Engine(Other));
+    assert_eq!(kind(1, "Unknown Command: \"foo\""), DSSErrorKind::Engine(Parse));
+    assert_eq!(kind(1, "Unknown parameter \"kx\" for object \"load.ld1\""), DSSErrorKind::Engine(Property));
+    assert_eq!(kind(1, "Property \"kx\" not found"), DSSErrorKind::NotFound);
+    assert_eq!(kind(1, "Load \"ld9\" not found in the active circuit"), DSSErrorKind::NotFound);
+}
+
+#[test]
+fn enum_codes() {
+    let err = SolveModes::try_from(9999).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::InvalidEnumValue);
+    assert!(err.message.contains("SolveModes"));
+    assert!(err.message.contains("9999"));
+
+    // Known codes round-trip through the conversions and the names
+    for code in 0..=17 {
+        let mode = SolveModes::try_from(code).unwrap();
+        assert_eq!(i32::from(mode), code);
+        assert_eq!(SolveModes::try_from(i32::from(mode)).unwrap().to_string(), mode.to_string());
+    }
+    assert_eq!(SolveModes::Daily.to_string(), "Daily");
+    assert_eq!(SolveModes::try_from(16).unwrap().to_string(), "Time");
+}
+
+fn failing_command(dss: &IDSS) -> Result<(), DSSError> {
+    let commands = [
+        "new circuit.errors",
+        "new load.ld1 bus1=b1 kw=10",
+        "foo bar",
+        "new load.ld2 bus1=b1 kw=10",
+    ];
+    dss.ClearAll()?;
+    let err = dss.Text.Commands(&commands).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::Engine(DSSErrorCategory::Parse));
+    assert_eq!(err.origin, Some("IText::Commands"));
+
+    dss.ClearAll()?;
+    let err = dss.Text.CommandsEach(&commands).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::Engine(DSSErrorCategory::Parse));
+    assert_eq!(err.origin, Some("IText::CommandsEach"));
+    assert_eq!(err.command.as_deref(), Some("foo bar"));
+    // The commands after the one that failed did not run
+    assert_eq!(dss.ActiveCircuit.Loads.Count()?, 1);
+    Ok(())
+}
+
+fn interior_nul(dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.nul
+        new load.ld1 bus1=b1 kw=10
+    ")?;
+    // Strings with interior NULs are rejected before reaching the engine
+    let err = dss.ActiveCircuit.Loads.Set_Name("a\0b").unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::InteriorNul);
+    assert_eq!(err.origin, Some("ILoads::Set_Name"));
+
+    dss.ActiveCircuit.SetActiveElement("Load.ld1")?;
+    let err = dss.ActiveCircuit.ActiveCktElement.Set_BusNames(&["b2", "a\0b"]).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::InteriorNul);
+    assert_eq!(err.origin, Some("ICktElement::Set_BusNames"));
+    assert_eq!(dss.ActiveCircuit.ActiveCktElement.Get_BusNames()?[0], "b1");
+
+    let err = dss.Text.Commands(&["new load.ld2 bus1=b1", "a\0b"]).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::InteriorNul);
+    assert_eq!(dss.ActiveCircuit.Loads.Count()?, 1);
+    Ok(())
+}
+
+#[test]
+fn error_interior_nul() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    interior_nul(&dss).unwrap();
+}
+
+#[test]
+fn error_command() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    failing_command(&dss).unwrap();
+}
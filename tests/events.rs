@@ -0,0 +1,73 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example subscribes closures to the DSS events, counting how many
+/// times the control loop runs during a solution.
+
+extern crate altdss;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use altdss::common::{DSSError, DSSContext};
+use altdss::classic::{AltDSSEvent, IDSS};
+
+fn count_events(dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.test basekv=12.47
+        new line.l1 bus1=sourcebus bus2=b1 length=1 units=km
+        new load.ld1 bus1=b1 kv=12.47 kw=1000 kvar=300
+        set voltagebases=[12.47]
+        calcv
+    ")?;
+
+    let check_count = Arc::new(AtomicUsize::new(0));
+    let clear_count = Arc::new(AtomicUsize::new(0));
+    {
+        let counter = check_count.clone();
+        let _check = dss.Events.Subscribe(AltDSSEvent::Legacy_CheckControls, move |event, _step| {
+            assert_eq!(event, AltDSSEvent::Legacy_CheckControls);
+            counter.fetch_add(1, Ordering::SeqCst);
+        })?;
+        let counter = clear_count.clone();
+        let clear = dss.Events.Subscribe(AltDSSEvent::Clear, move |_event, _step| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        })?;
+        dss.ActiveCircuit.Solution.Solve()?;
+        assert!(check_count.load(Ordering::SeqCst) > 0);
+
+        clear.Unsubscribe();
+        dss.ClearAll()?;
+        assert_eq!(clear_count.load(Ordering::SeqCst), 0);
+    }
+
+    // The remaining subscription was dropped at the end of the block
+    let solved = check_count.load(Ordering::SeqCst);
+    dss.Command("
+        new circuit.test basekv=12.47
+        new load.ld1 bus1=sourcebus kv=12.47 kw=1000 kvar=300
+        solve
+    ")?;
+    assert_eq!(check_count.load(Ordering::SeqCst), solved);
+    Ok(())
+}
+
+#[test]
+fn test_events() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    count_events(&dss).unwrap();
+}
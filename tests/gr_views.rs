@@ -0,0 +1,61 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example compares the copying getters for the GR buffers with the
+/// `*_view` and `*_into` variants, which avoid allocations.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSErrorKind, DSSContext};
+use altdss::classic::IDSS;
+
+fn compare_views(dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.test basekv=12.47
+        new line.l1 bus1=sourcebus bus2=b1 length=1 units=km
+        new load.ld1 bus1=b1 kv=12.47 kw=1000 kvar=300
+        set voltagebases=[12.47]
+        calcv
+        solve
+    ")?;
+    let circ = &dss.ActiveCircuit;
+    let copied = circ.AllBusVmagPu()?;
+    let mut reused = Vec::new();
+    circ.AllBusVmagPu_into(&mut reused)?;
+    assert_eq!(&copied[..], &reused[..]);
+    {
+        let view = circ.AllBusVmagPu_view()?;
+        assert_eq!(&copied[..], &view[..]);
+
+        // The view is still alive, so the GR buffers cannot be overwritten
+        let err = circ.AllBusVolts().unwrap_err();
+        assert_eq!(err.kind, DSSErrorKind::GRBufferInUse);
+    }
+    // After dropping the view, the buffers can be used again
+    circ.AllBusVolts()?;
+    Ok(())
+}
+
+#[test]
+fn gr_views() {
+    // Create the context wrapper
+    let ctx = DSSContext::prime();
+    // Bind it to API structs
+    let dss = IDSS::new(&ctx);
+
+    compare_views(&dss).unwrap();
+}
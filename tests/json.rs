@@ -0,0 +1,90 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "serde")]
+
+/// This example exports a circuit as JSON, round-trips it through `CircuitModel`,
+/// and loads it into a new DSS context.
+/// Requires the `serde` feature.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSErrorKind, DSSContext};
+use altdss::classic::{DSSJSONFlags, IDSS};
+use altdss::json::{CircuitModel, DSSJSONOptions, DSSValueText};
+use serde_json::json;
+
+fn round_trip(dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.json_test basekv=12.47
+        new linecode.lc1 nphases=3 r1=0.1 x1=0.3 units=km
+        new line.l1 bus1=sourcebus bus2=b1 linecode=lc1 length=2 units=km
+        new line.l2 bus1=b1 bus2=b2 linecode=lc1 length=1 units=km
+        new load.ld1 bus1=b1 kv=12.47 kw=400 kvar=120
+        new load.ld2 bus1=b2 kv=12.47 kw=250 kvar=80
+        set voltagebases=[12.47]
+        calcv
+    ")?;
+    dss.ActiveCircuit.Solution.Solve()?;
+    let power = dss.ActiveCircuit.TotalPower()?;
+
+    let options = DSSJSONOptions::from_flags(&[DSSJSONFlags::SkipRedundant, DSSJSONFlags::ExcludeDisabled]);
+    assert!(options.Contains(DSSJSONFlags::SkipRedundant));
+    assert!(!options.Without(DSSJSONFlags::SkipRedundant).Contains(DSSJSONFlags::SkipRedundant));
+    assert_eq!(i32::from(options), 2 | 32);
+
+    let model = CircuitModel::FromCircuit(dss, options)?;
+    assert!(model.name.eq_ignore_ascii_case("json_test"));
+    let loads = model.Objects("Load")?;
+    assert_eq!(loads.len(), 2);
+    assert!(loads[0].name.eq_ignore_ascii_case("ld1"));
+
+    // The JSON data survives the round-trip through the typed model
+    let model2 = CircuitModel::FromJSON(&model.ToJSON()?)?;
+    assert_eq!(model, model2);
+
+    let ctx2 = model2.ToNewContext()?;
+    let dss2 = IDSS::new(&ctx2);
+    assert_eq!(dss2.ActiveCircuit.Loads.Count()?, 2);
+    dss2.ActiveCircuit.Loads.Set_Name("ld2")?;
+    assert_eq!(dss2.ActiveCircuit.Loads.Get_kW()?, 250.0);
+    dss2.ActiveCircuit.Solution.Solve()?;
+    let power2 = dss2.ActiveCircuit.TotalPower()?;
+    assert!((power.re - power2.re).abs() < 1e-6 * power.re.abs());
+    assert!((power.im - power2.im).abs() < 1e-6 * power.im.abs());
+    Ok(())
+}
+
+#[test]
+fn json_round_trip() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    round_trip(&dss).unwrap();
+}
+
+#[test]
+fn json_values() {
+    let options = DSSJSONOptions::from_flags(&[DSSJSONFlags::Full, DSSJSONFlags::Pretty]);
+    assert!(options.Contains(DSSJSONFlags::Pretty));
+    assert_eq!(options.Without(DSSJSONFlags::Pretty).Bits(), DSSJSONFlags::Full as i32);
+
+    assert_eq!(DSSValueText(&json!(1.5)).unwrap(), "1.5");
+    assert_eq!(DSSValueText(&json!([1, 2, 3])).unwrap(), "[1 2 3]");
+    assert_eq!(DSSValueText(&json!([[1, 2], [3, 4]])).unwrap(), "[1 2 | 3 4]");
+    assert_eq!(DSSValueText(&json!({"kW": 1})).unwrap_err().kind, DSSErrorKind::JSON);
+    assert_eq!(DSSValueText(&json!([1, {"kW": 1}])).unwrap_err().kind, DSSErrorKind::JSON);
+}
@@ -0,0 +1,118 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "serde")]
+
+/// This example reads the property metadata of a few DSS classes.
+/// Requires the `serde` feature.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSErrorKind, DSSContext};
+use altdss::classic::IDSS;
+use altdss::meta::{DSSPropertyShape, DSSPropertyType, DSSSchema};
+use altdss::obj::IObj;
+use std::sync::Arc;
+
+fn read_metadata(ctx: &DSSContext, dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.test
+        new storage.st1
+    ")?;
+
+    let schema = dss.Schema()?;
+    let storage = schema.Class("Storage")?;
+    // Same properties, in the same order, as the object API
+    let obj = IObj::new(ctx).Get("Storage", "st1")?;
+    let names = obj.PropertyNames()?;
+    assert_eq!(storage.properties.len(), names.len());
+    for (prop, name) in storage.properties.iter().zip(names.iter()) {
+        assert!(prop.name.eq_ignore_ascii_case(name));
+        assert_eq!(obj.PropertyIndex(name)?, prop.index);
+    }
+    assert_eq!(storage.Property("kWrated").unwrap().data_type, DSSPropertyType::Float64);
+    assert_eq!(schema.Class("nonexistent").unwrap_err().kind, DSSErrorKind::NotFound);
+
+    // Extracted once per context
+    assert!(Arc::ptr_eq(&schema, &dss.Schema()?));
+
+    let load = dss.ClassSchema("Load")?;
+    let model = load.Property("model").unwrap();
+    assert_eq!(model.shape, DSSPropertyShape::Scalar);
+    assert!(load.PropertyNames().contains(&"kW"));
+    let kW = load.Property("kW").unwrap();
+    assert_eq!((kW.data_type.clone(), kW.shape), (DSSPropertyType::Float64, DSSPropertyShape::Scalar));
+    assert_eq!(load.Property("bus1").unwrap().data_type, DSSPropertyType::String);
+    assert_eq!(load.Property("spectrum").unwrap().data_type, DSSPropertyType::Object);
+    assert!(!load.properties.iter().any(|prop| matches!(prop.data_type, DSSPropertyType::Other(_))));
+
+    dss.SetActiveClass("Line")?;
+    let line = dss.ActiveClass.Schema()?;
+    assert!(line.name.eq_ignore_ascii_case("Line"));
+    assert!(line.Property("bus1").unwrap().description.is_some());
+    Ok(())
+}
+
+#[test]
+fn class_schema() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    read_metadata(&ctx, &dss).unwrap();
+}
+
+#[test]
+fn class_schema_from_json() {
+    let schema = DSSSchema::FromJSON(r#"{
+        "enums": [{"name": "Connection", "values": {"wye": 0, "delta": 1}}],
+        "classes": [{
+            "name": "Capacitor",
+            "properties": [
+                {"name": "bus1", "type": "BusProperty"},
+                {"name": "conn", "type": "MappedStringEnumProperty", "enum": "Connection", "default": "wye"},
+                {"name": "kvar", "type": "DoubleArrayProperty", "units": "kvar", "default": [1200]},
+                {"name": "cmatrix", "type": "DoubleSymMatrixProperty", "flags": ["Deprecated"]},
+                {"name": "like", "type": "MakeLikeProperty", "redundantWith": "bus1"},
+                {"name": "future", "type": "FutureProperty"}
+            ]
+        }]
+    }"#).unwrap();
+    let cap = schema.Class("capacitor").unwrap();
+    assert_eq!(cap.PropertyNames(), ["bus1", "conn", "kvar", "cmatrix", "like", "future"]);
+    assert_eq!(cap.Property("bus1").unwrap().data_type, DSSPropertyType::String);
+    let conn = cap.Property("conn").unwrap();
+    assert_eq!(conn.data_type, DSSPropertyType::Enum);
+    assert_eq!(conn.choices, ["wye", "delta"]);
+    assert_eq!(conn.index, 2);
+    let kvar = cap.Property("kvar").unwrap();
+    assert_eq!((kvar.data_type.clone(), kvar.shape), (DSSPropertyType::Float64, DSSPropertyShape::Array));
+    assert_eq!(kvar.unit.as_deref(), Some("kvar"));
+    assert_eq!(kvar.default.as_deref(), Some("[1200]"));
+    let cmatrix = cap.Property("cmatrix").unwrap();
+    assert_eq!(cmatrix.shape, DSSPropertyShape::Matrix);
+    assert!(cmatrix.deprecated);
+    let like = cap.Property("like").unwrap();
+    assert_eq!(like.data_type, DSSPropertyType::Object);
+    assert_eq!(like.alias_of.as_deref(), Some("bus1"));
+    // Unknown types are kept by name, not guessed
+    assert_eq!(cap.Property("future").unwrap().data_type, DSSPropertyType::Other("FutureProperty".to_string()));
+
+    // Other layouts are rejected instead of guessed
+    let err = DSSSchema::FromJSON(r#"{"classes": {"Capacitor": {"properties": []}}}"#).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::JSON);
+    let err = DSSSchema::FromJSON(r#"{"enums": {"Connection": ["wye"]}, "classes": []}"#).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::JSON);
+}
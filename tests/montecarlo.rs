@@ -0,0 +1,179 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "montecarlo")]
+
+/// This example runs seeded Monte Carlo samples of a small feeder, sequentially and
+/// on a scenario pool, and replays a sample from its seed.
+/// Requires the `montecarlo` feature.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSErrorKind, DSSContext};
+use altdss::classic::IDSS;
+use altdss::montecarlo::{MonteCarlo, MonteCarloSample, MultiplierDistribution};
+use altdss::pool::ScenarioPool;
+
+const CIRCUIT: &str = "
+    clear
+    new circuit.mc basekv=12.47
+    new line.l1 bus1=sourcebus bus2=b1 length=2 units=km
+    new line.l2 bus1=b1 bus2=b2 length=1 units=km
+    new load.ld1 bus1=b1 kv=12.47 kw=1000 kvar=300
+    new load.ld2 bus1=b2 kv=12.47 kw=500 kvar=100
+    new load.ld3 bus1=b2 kv=12.47 kw=250 kvar=50
+    new generator.pv1 bus1=b2 kv=12.47 kw=400 pf=1
+    set voltagebases=[12.47]
+    calcv
+";
+
+fn config() -> MonteCarlo {
+    MonteCarlo::new(2023)
+        .Loads(MultiplierDistribution::Normal { mean: 1.0, std_dev: 0.2 })
+        .Generators(MultiplierDistribution::Uniform { min: 0.0, max: 1.0 })
+}
+
+fn losses(dss: &IDSS, _sample: &MonteCarloSample) -> Result<f64, DSSError> {
+    dss.ActiveCircuit.Solution.Solve()?;
+    Ok(dss.ActiveCircuit.Losses()?.re)
+}
+
+fn sample_losses(dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command(CIRCUIT)?;
+    let mc = config();
+    let results = mc.Run(dss, 0..20, losses)?;
+    assert_eq!(results.len(), 20);
+    assert_eq!(results[3].sample.index, Some(3));
+    assert_eq!(results[3].sample.seed, mc.SampleSeed(3));
+    assert_eq!(results[3].sample.loads.len(), 3);
+    assert_eq!(results[3].sample.generators.len(), 1);
+    assert!(results.iter().any(|result| result.output != results[0].output));
+
+    // The base values are restored after each sample
+    dss.ActiveCircuit.Loads.Set_Name("ld2")?;
+    assert_eq!(dss.ActiveCircuit.Loads.Get_kW()?, 500.0);
+    assert_eq!(dss.ActiveCircuit.Loads.Get_kvar()?, 100.0);
+
+    // A single sample, from its index or its seed
+    let again = mc.RunSample(dss, 11, losses)?;
+    assert_eq!(again, results[11]);
+    let replay = mc.Replay(dss, results[11].sample.seed, losses)?;
+    assert_eq!(replay.sample.index, None);
+    assert_eq!(replay.sample.loads, results[11].sample.loads);
+    assert_eq!(replay.output, results[11].output);
+
+    // Same samples on other contexts, in any order
+    let mut pool = ScenarioPool::new(2, CIRCUIT)?;
+    let pool_results = pool.Run((0..20u64).rev(), |dss, index| mc.RunSample(dss, index, losses));
+    for (result, pool_result) in results.iter().rev().zip(pool_results.into_iter()) {
+        assert_eq!(*result, pool_result?);
+    }
+
+    // Errors in the closure still restore the base values
+    let err = mc.RunSample(dss, 0, |_dss, _sample| -> Result<f64, DSSError> {
+        Err(DSSError::wrapper(DSSErrorKind::InvalidArgument, "Failed".to_string()))
+    }).err().unwrap();
+    assert_eq!(err.kind, DSSErrorKind::InvalidArgument);
+    dss.ActiveCircuit.Loads.Set_Name("ld1")?;
+    assert_eq!(dss.ActiveCircuit.Loads.Get_kW()?, 1000.0);
+
+    // The base values are captured once per run, so changes from a sample
+    // do not leak into the next ones
+    let results = mc.Run(dss, 0..3, |dss, sample| {
+        if sample.index == Some(0) {
+            dss.Command("new load.extra bus1=b2 kv=12.47 kw=10")?;
+        }
+        losses(dss, sample)
+    })?;
+    assert!(results.iter().all(|result| result.sample.loads.len() == 3));
+    Ok(())
+}
+
+const SPECS_CIRCUIT: &str = "
+    clear
+    new circuit.mcspecs basekv=12.47
+    new line.l1 bus1=sourcebus bus2=b1 length=2 units=km
+    new load.kvar bus1=b1 kv=12.47 kw=1000 kvar=300
+    new load.pf bus1=b1 kv=12.47 kw=200 pf=0.9
+    new load.kva bus1=b1 kv=12.47 kva=300 pf=0.95
+    set voltagebases=[12.47]
+    calcv
+";
+
+fn load_specs(dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command(SPECS_CIRCUIT)?;
+    let loads = &dss.ActiveCircuit.Loads;
+    let mc = MonteCarlo::new(1).Loads(MultiplierDistribution::Fixed(2.0));
+    mc.RunSample(dss, 0, |dss, _sample| {
+        // The power is scaled, keeping the power factor
+        let loads = &dss.ActiveCircuit.Loads;
+        loads.Set_Name("pf")?;
+        assert_eq!((loads.Get_kW()?, loads.Get_PF()?), (400.0, 0.9));
+        loads.Set_Name("kva")?;
+        assert_eq!((loads.Get_kva()?, loads.Get_PF()?), (600.0, 0.95));
+        loads.Set_Name("kvar")?;
+        assert_eq!((loads.Get_kW()?, loads.Get_kvar()?), (2000.0, 600.0));
+        Ok(())
+    })?;
+
+    // Restored with the same specification
+    loads.Set_Name("pf")?;
+    assert_eq!((loads.Get_kW()?, loads.Get_PF()?), (200.0, 0.9));
+    loads.Set_Name("kva")?;
+    assert_eq!((loads.Get_kva()?, loads.Get_PF()?), (300.0, 0.95));
+    loads.Set_Name("kvar")?;
+    assert_eq!((loads.Get_kW()?, loads.Get_kvar()?), (1000.0, 300.0));
+
+    // If restoring fails too, the error from the closure is reported
+    let err = mc.RunSample(dss, 0, |dss, _sample| -> Result<(), DSSError> {
+        dss.ClearAll()?;
+        Err(DSSError::wrapper(DSSErrorKind::InvalidArgument, "Failed".to_string()))
+    }).unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::InvalidArgument);
+    Ok(())
+}
+
+#[test]
+fn montecarlo_samples() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    sample_losses(&dss).unwrap();
+}
+
+#[test]
+fn montecarlo_draw() {
+    let names: Vec<String> = ["a", "b", "c"].iter().map(|name| name.to_string()).collect();
+    let mc = config();
+    let sample = mc.Draw(mc.SampleSeed(5), &names, &names[..1]).unwrap();
+    assert_eq!(sample, mc.Draw(mc.SampleSeed(5), &names, &names[..1]).unwrap());
+    assert_ne!(mc.SampleSeed(5), mc.SampleSeed(6));
+    assert_ne!(mc.SampleSeed(5), MonteCarlo::new(2024).SampleSeed(5));
+    assert!(sample.loads.iter().all(|(_, mult)| *mult >= 0.0));
+    assert!(sample.generators.iter().all(|(_, mult)| (0.0..=1.0).contains(mult)));
+
+    let shared = mc.clone().Independent(false).Draw(1, &names, &[]).unwrap();
+    assert!(shared.loads.iter().all(|(_, mult)| *mult == shared.loads[0].1));
+
+    let invalid = MonteCarlo::new(1).Loads(MultiplierDistribution::Normal { mean: 1.0, std_dev: -1.0 });
+    assert_eq!(invalid.Draw(1, &names, &[]).unwrap_err().kind, DSSErrorKind::InvalidArgument);
+}
+
+#[test]
+fn montecarlo_load_specs() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    load_specs(&dss).unwrap();
+}
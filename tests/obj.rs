@@ -0,0 +1,117 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example uses the generic object API to create and edit objects of classes
+/// that are not exposed in the classic API, like XYcurve and InvControl. It also
+/// checks disabling circuit elements and the handles left after clearing the circuit.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSErrorKind, DSSContext};
+use altdss::classic::IDSS;
+use altdss::obj::IObj;
+
+fn edit_objects(dss: &IDSS, obj: &IObj) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.test basekv=12.47
+        new line.l1 bus1=sourcebus bus2=b1 length=1 units=km
+        new pvsystem.pv1 bus1=b1 kv=12.47 kva=500 pmpp=500
+        set voltagebases=[12.47]
+        calcv
+    ")?;
+
+    let classes = obj.Classes()?;
+    assert!(classes.iter().any(|name| name == "InvControl"));
+
+    let curve = obj.New("XYcurve", "vv")?;
+    curve.SetInt32("npts", 4)?;
+    curve.SetFloat64Array("xarray", &[0.5, 0.95, 1.05, 1.5])?;
+    curve.SetFloat64Array("yarray", &[1.0, 0.0, 0.0, -1.0])?;
+    assert_eq!(&curve.GetFloat64Array("yarray")?[..], &[1.0, 0.0, 0.0, -1.0]);
+
+    let invcontrol = obj.New("InvControl", "ic1")?;
+    invcontrol.SetStringArray("DERList", &["PVSystem.pv1"])?;
+    invcontrol.SetString("mode", "voltvar")?;
+    invcontrol.SetObject("vvc_curve1", Some(&curve))?;
+    assert_eq!(invcontrol.GetObject("vvc_curve1")?, Some(curve));
+    assert_eq!(invcontrol.GetStringArray("DERList")?.len(), 1);
+
+    // Properties can be used by name or index
+    let idx = invcontrol.PropertyIndex("mode")?;
+    assert!(invcontrol.GetAsString(idx)?.eq_ignore_ascii_case("voltvar"));
+
+    let class = obj.Class("invcontrol")?;
+    assert_eq!(class.Name(), "InvControl");
+    assert_eq!(class.Names()?, vec!["ic1".to_string()]);
+    assert_eq!(obj.GetByFullName("InvControl.IC1")?, invcontrol);
+
+    let err = obj.Get("InvControl", "missing").unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::NotFound);
+    let err = invcontrol.GetFloat64("missing").unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::NotFound);
+
+    dss.ActiveCircuit.Solution.Solve()?;
+    Ok(())
+}
+
+fn disable_and_clear(dss: &IDSS, obj: &IObj) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.test
+        new loadshape.ls1 npts=2 interval=1 mult=[1 2]
+        new load.ld1 bus1=sourcebus kw=10
+    ")?;
+
+    // Circuit elements can be disabled, but they are kept in the class
+    let load = obj.Get("Load", "ld1")?;
+    load.Disable()?;
+    load.Activate()?;
+    assert!(!dss.ActiveCircuit.ActiveCktElement.Get_Enabled()?);
+    assert_eq!(obj.Get("Load", "ld1")?, load);
+    let err = obj.Get("LoadShape", "ls1")?.Disable().unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::Unsupported);
+
+    // Handles and batches from before a clear are not usable anymore
+    let loads = obj.Class("Load")?.Batch()?;
+    dss.ClearAll()?;
+    dss.Command("new circuit.other\nnew load.ld1 bus1=sourcebus kw=20")?;
+    let err = load.GetFloat64("kW").unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::StaleReference);
+    assert_eq!(err.origin, Some("DSSObject::GetFloat64"));
+    assert_eq!(load.Handle().unwrap_err().kind, DSSErrorKind::StaleReference);
+    assert_eq!(loads.GetFloat64("kW").unwrap_err().kind, DSSErrorKind::StaleReference);
+
+    // New handles work as usual
+    assert_eq!(obj.Get("Load", "ld1")?.GetFloat64("kW")?, 20.0);
+    Ok(())
+}
+
+#[test]
+fn obj() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    let obj = IObj::new(&ctx);
+    edit_objects(&dss, &obj).unwrap();
+}
+
+#[test]
+fn obj_disable_and_clear() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    let obj = IObj::new(&ctx);
+    disable_and_clear(&dss, &obj).unwrap();
+}
@@ -0,0 +1,97 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example runs load multiplier scenarios of a small feeder on a pool of
+/// DSS contexts, similar to `tests/parallel.rs` without the thread management.
+
+extern crate altdss;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use altdss::common::{DSSError, DSSErrorKind};
+use altdss::classic::IDSS;
+use altdss::pool::ScenarioPool;
+
+const CIRCUIT: &str = "
+    clear
+    new circuit.pool basekv=12.47
+    new line.l1 bus1=sourcebus bus2=b1 length=2 units=km
+    new load.ld1 bus1=b1 kv=12.47 kw=1000 kvar=300
+    set voltagebases=[12.47]
+    calcv
+";
+
+fn losses(dss: &IDSS, loadmult: f64) -> Result<f64, DSSError> {
+    if loadmult < 0.0 {
+        return Err(DSSError::wrapper(DSSErrorKind::InvalidArgument, "Negative load multiplier".to_string()));
+    }
+    dss.ActiveCircuit.Solution.Set_LoadMult(loadmult)?;
+    dss.ActiveCircuit.Solution.Solve()?;
+    Ok(dss.ActiveCircuit.Losses()?.re)
+}
+
+#[test]
+fn scenario_pool() {
+    let mut pool = ScenarioPool::new(3, CIRCUIT).unwrap();
+    assert_eq!(pool.Workers(), 3);
+
+    // Results in input order, independent of the worker that ran them
+    let loadmults: Vec<f64> = (0..30).map(|i| 0.5 + 0.05 * i as f64).collect();
+    let finished = AtomicUsize::new(0);
+    let results = pool.RunWithProgress(loadmults.clone(), losses, |done, total| {
+        assert_eq!(total, 30);
+        finished.fetch_max(done, Ordering::SeqCst);
+    });
+    assert_eq!(finished.load(Ordering::SeqCst), 30);
+    let results: Vec<f64> = results.into_iter().map(|result| result.unwrap()).collect();
+    assert!(results.windows(2).all(|pair| pair[0] < pair[1]));
+    let again = pool.Run(vec![loadmults[7]], losses);
+    assert!((again[0].as_ref().unwrap() - results[7]).abs() < 1e-9 * results[7]);
+
+    // Errors and panics stay in their scenarios
+    let results = pool.Run(vec![1.0, -1.0, f64::NAN, 1.2], |dss, loadmult| {
+        if loadmult.is_nan() {
+            panic!("invalid input");
+        }
+        losses(dss, loadmult)
+    });
+    assert!(results[0].is_ok() && results[3].is_ok());
+    assert_eq!(results[1].as_ref().unwrap_err().kind, DSSErrorKind::InvalidArgument);
+    let panic = results[2].as_ref().unwrap_err();
+    assert_eq!(panic.kind, DSSErrorKind::Panic);
+    assert!(panic.message.contains("invalid input"));
+
+    // Cancel from the progress callback
+    let cancel = pool.CancelToken();
+    let results = pool.RunWithProgress(loadmults.clone(), losses, |done, _total| {
+        if done == 5 {
+            cancel.Cancel();
+        }
+    });
+    assert_eq!(results.len(), 30);
+    let cancelled = results.iter().filter(|result| matches!(result, Err(err) if err.kind == DSSErrorKind::Cancelled)).count();
+    assert!(cancelled > 0 && cancelled <= 25);
+    assert!(results[..5].iter().all(|result| result.is_ok()));
+
+    // The token is reset for the next run
+    assert!(pool.Run(loadmults, losses).iter().all(|result| result.is_ok()));
+}
+
+#[test]
+fn scenario_pool_setup_error() {
+    let err = ScenarioPool::new(2, "redirect nonexistent_file.dss").err().unwrap();
+    assert!(matches!(err.kind, DSSErrorKind::Engine(_)));
+    assert_eq!(ScenarioPool::new(0, CIRCUIT).err().unwrap().kind, DSSErrorKind::InvalidArgument);
+}
@@ -0,0 +1,71 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-loading")]
+
+/// This example checks that failing to load the DSS C-API library at runtime is
+/// reported as errors, then loads the library and uses it.
+/// Requires the `runtime-loading` feature.
+///
+/// The library is loaded from `DSS_CAPI_LIB_DIR` or, if not set, from the folder
+/// used by the build scripts; it must not be in the search path of the system loader.
+
+extern crate altdss;
+
+use std::env;
+use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+use std::path::PathBuf;
+use altdss::common::{DSSContext, DSSErrorKind};
+use altdss::classic::IDSS;
+use altdss::dss_capi;
+
+fn library_path() -> PathBuf {
+    let lib_dir = match env::var_os("DSS_CAPI_LIB_DIR") {
+        Some(lib_dir) => PathBuf::from(lib_dir),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("dss_capi/lib/linux_x64"),
+    };
+    lib_dir.join(format!("{}dss_capi{}", DLL_PREFIX, DLL_SUFFIX))
+}
+
+// A single test, since the library and the environment are shared by the whole process
+#[test]
+fn runtime_loading() {
+    // A bad path
+    let err = dss_capi::load_library("/nonexistent/libdss_capi.so").unwrap_err();
+    assert_eq!(err.kind, DSSErrorKind::Library);
+    assert!(err.message.contains("/nonexistent/libdss_capi.so"));
+    assert!(!dss_capi::is_library_loaded());
+
+    // An invalid path in the environment variable
+    env::set_var(dss_capi::LIBRARY_PATH_ENV_VAR, "/nonexistent/libdss_capi.so");
+    assert_eq!(dss_capi::load_library_from_env().unwrap_err().kind, DSSErrorKind::Library);
+    assert_eq!(DSSContext::try_prime().err().unwrap().kind, DSSErrorKind::Library);
+
+    // Without the variable, the default name is not found by the system loader
+    env::remove_var(dss_capi::LIBRARY_PATH_ENV_VAR);
+    assert_eq!(dss_capi::load_library_from_env().unwrap_err().kind, DSSErrorKind::Library);
+    assert_eq!(DSSContext::try_prime().err().unwrap().kind, DSSErrorKind::Library);
+    assert!(!dss_capi::is_library_loaded());
+
+    // A valid library
+    dss_capi::load_library(library_path()).unwrap();
+    assert!(dss_capi::is_library_loaded());
+    assert_eq!(dss_capi::load_library(library_path()).unwrap_err().kind, DSSErrorKind::Library);
+    let ctx = DSSContext::try_prime().unwrap();
+    let dss = IDSS::new(&ctx);
+    dss.Command("clear\nnew circuit.runtime").unwrap();
+    assert_eq!(dss.ActiveCircuit.Name().unwrap(), "runtime");
+}
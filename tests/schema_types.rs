@@ -0,0 +1,60 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "schema-types")]
+
+/// This example reads a load into the typed struct generated from AltDSS-Schema,
+/// edits it, and pushes it back to the engine.
+/// Requires the `schema-types` feature.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSContext};
+use altdss::classic::IDSS;
+use altdss::schema::{DSSClassType, Load};
+
+fn edit_load(dss: &IDSS, ctx: &DSSContext) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.test basekv=12.47
+        new load.ld1 bus1=sourcebus kv=12.47 kw=1000 kvar=300
+    ")?;
+
+    let mut load = Load::Get(ctx, "ld1")?;
+    assert_eq!(load.kW, Some(1000.0));
+    assert_eq!(Load::Unit("kW"), Some("kW"));
+
+    // Round-trip through JSON
+    let json = load.ToJSON()?;
+    assert_eq!(Load::FromJSON(&json)?, load);
+
+    load.kW = Some(1200.0);
+    load.Push(ctx, "ld1")?;
+    dss.ActiveCircuit.Loads.Set_Name("ld1")?;
+    assert_eq!(dss.ActiveCircuit.Loads.Get_kW()?, 1200.0);
+
+    // Pushing to a missing object creates it
+    load.Push(ctx, "ld2")?;
+    assert_eq!(dss.ActiveCircuit.Loads.Count()?, 2);
+    Ok(())
+}
+
+#[test]
+fn schema_types() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    edit_load(&dss, &ctx).unwrap();
+}
@@ -0,0 +1,81 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example exports a circuit as DSS script text, without touching the disk,
+/// and runs the script again in another context.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSContext};
+use altdss::classic::{DSSSaveFlags, IDSS};
+use altdss::script::{CircuitScript, DSSSaveOptions};
+
+fn export_script(dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.script_test basekv=12.47
+        new linecode.lc1 nphases=3 r1=0.1 x1=0.3 units=km
+        new line.l1 bus1=sourcebus bus2=b1 linecode=lc1 length=2 units=km
+        new line.l2 bus1=b1 bus2=b2 linecode=lc1 length=1 units=km
+        new load.ld1 bus1=b1 kv=12.47 kw=400 kvar=120
+        new load.ld2 bus1=b2 kv=12.47 kw=250 kvar=80
+        set voltagebases=[12.47]
+        calcv
+        setbusxy bus=b1 x=10 y=20
+        setbusxy bus=b2 x=30 y=40
+    ")?;
+    dss.ActiveCircuit.Solution.Solve()?;
+    let power = dss.ActiveCircuit.TotalPower()?;
+
+    let options = DSSSaveOptions::from_flags(&[DSSSaveFlags::SetVoltageBases, DSSSaveFlags::CalcVoltageBases]);
+    dss.ActiveCircuit.SetActiveBus("b1")?;
+    let script = CircuitScript::FromCircuit(dss, options)?;
+    // The active bus is kept
+    assert_eq!(dss.ActiveCircuit.ActiveBus.Name()?, "b1");
+    let files = script.Files();
+    assert_eq!(files[0].name, "Master.dss");
+    for class_name in ["LineCode", "Line", "Load"] {
+        let file = script.File(&format!("{}.dss", class_name)).unwrap();
+        assert!(files[0].text.to_lowercase().contains(&format!("redirect {}", file.name.to_lowercase())));
+        assert!(file.text.lines().all(|line| !line.to_lowercase().starts_with("new circuit")));
+    }
+    let coords = script.File("BusCoords.dss").unwrap();
+    assert_eq!(coords.text.lines().count(), 2);
+
+    // The single text recreates the same circuit
+    let ctx2 = dss.NewContext()?;
+    let dss2 = IDSS::new(&ctx2);
+    dss2.ClearAll()?;
+    dss2.Command(script.Text())?;
+    assert_eq!(dss2.ActiveCircuit.Loads.Count()?, 2);
+    dss2.ActiveCircuit.Solution.Solve()?;
+    let power2 = dss2.ActiveCircuit.TotalPower()?;
+    assert!((power.re - power2.re).abs() < 1e-6 * power.re.abs());
+    assert!((power.im - power2.im).abs() < 1e-6 * power.im.abs());
+    dss2.ActiveCircuit.SetActiveBus("b2")?;
+    assert_eq!(dss2.ActiveCircuit.ActiveBus.Get_x()?, 30.0);
+
+    // The export is deterministic
+    assert_eq!(CircuitScript::FromCircuit(dss, options)?, script);
+    Ok(())
+}
+
+#[test]
+fn script_export() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    export_script(&dss).unwrap();
+}
@@ -0,0 +1,81 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example captures the power-flow results of two scenarios and compares
+/// them after clearing the engine.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSContext};
+use altdss::classic::IDSS;
+use altdss::snapshot::SolutionSnapshot;
+
+fn capture(dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command("
+        clear
+        new circuit.snap basekv=12.47
+        new line.l1 bus1=sourcebus bus2=b1 length=1 units=km
+        new line.l2 bus1=b1 bus2=b2 length=1 units=km
+        new load.ld1 bus1=b1 kv=12.47 kw=1000 kvar=300
+        new load.ld2 bus1=b2.1 phases=1 kv=7.2 kw=200 kvar=50
+        set voltagebases=[12.47]
+        calcv
+    ")?;
+    let circuit = &dss.ActiveCircuit;
+    circuit.Solution.Solve()?;
+    let base = SolutionSnapshot::FromCircuit(dss)?;
+    assert!(base.converged);
+    assert!(base.iterations > 0);
+    assert_eq!(base.total_power, circuit.TotalPower()?);
+    assert_eq!(base.buses.len(), circuit.NumBuses()? as usize);
+    assert_eq!(base.Nodes().count(), circuit.NumNodes()? as usize);
+    assert_eq!(base.elements.len(), circuit.NumCktElements()? as usize);
+
+    // Lookups by name match the engine
+    circuit.SetActiveBus("B2")?;
+    let b2 = base.Bus("b2").unwrap();
+    assert_eq!(b2.nodes.len(), 3);
+    assert_eq!(b2.nodes.iter().map(|node| node.voltage).collect::<Vec<_>>(), circuit.ActiveBus.Voltages()?.to_vec());
+    assert_eq!(base.Node("b2.1").unwrap().voltage, b2.nodes[0].voltage);
+    let l2 = base.Element("line.L2").unwrap();
+    circuit.SetActiveElement("Line.l2")?;
+    assert_eq!(l2.currents, circuit.ActiveCktElement.Currents()?.to_vec());
+    assert_eq!(l2.TerminalPower(1).unwrap(), circuit.ActiveCktElement.Powers()?[..3].iter().sum());
+    assert!(l2.TerminalPower(3).is_none());
+
+    // Compare with a heavier scenario, without the engine
+    assert!(base.Diff(&base, 1e-9).IsEmpty());
+    circuit.Solution.Set_LoadMult(1.5)?;
+    circuit.Solution.Solve()?;
+    let heavy = SolutionSnapshot::FromCircuit(dss)?;
+    dss.ClearAll()?;
+
+    let diff = base.Diff(&heavy, 1e-6);
+    assert!(!diff.IsEmpty());
+    assert!(diff.MaxVoltageChange() > 0.0);
+    assert!(diff.total_power.1.re.abs() > diff.total_power.0.re.abs());
+    let ld1 = diff.elements.iter().find(|element| element.name.eq_ignore_ascii_case("Load.ld1")).unwrap();
+    assert!(ld1.after.unwrap().power.re > ld1.before.unwrap().power.re);
+    assert!(diff.to_string().contains("Total power:"));
+    Ok(())
+}
+
+#[test]
+fn solution_snapshot() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    capture(&dss).unwrap();
+}
@@ -0,0 +1,98 @@
+// Copyright 2023 PMeira
+// Copyright 2023 DSS-Extensions Contributors
+// Copyright 2023 Electric Power Research Institute, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// This example runs a daily simulation of a small feeder with the time-series
+/// driver, stops it early and resumes it.
+
+extern crate altdss;
+
+use altdss::common::{DSSError, DSSErrorKind, DSSContext};
+use altdss::classic::{IDSS, SolveModes};
+use altdss::timeseries::{StepRecord, TimeSeriesRun};
+
+const CIRCUIT: &str = "
+    clear
+    new circuit.qsts basekv=12.47
+    new loadshape.daily npts=24 interval=1 mult=(0.3 0.3 0.3 0.35 0.4 0.5 0.6 0.7 0.8 0.8 0.85 0.9 0.9 0.85 0.85 0.9 1.0 1.0 0.95 0.9 0.8 0.6 0.5 0.4)
+    new line.l1 bus1=sourcebus bus2=b1 length=1 units=km
+    new load.ld1 bus1=b1 kv=12.47 kw=1000 kvar=300 daily=daily
+    new energymeter.m1 element=line.l1
+    set voltagebases=[12.47]
+    calcv
+";
+
+fn run(dss: &IDSS, start: Option<f64>, steps: usize, stop_at: Option<usize>) -> Result<Vec<StepRecord>, DSSError> {
+    let mut run = TimeSeriesRun::new(dss)
+        .Mode(SolveModes::Daily)
+        .StepSizeMin(15.0)
+        .Steps(steps)
+        .BusVoltagesPu("b1")
+        .ElementPowers("Load.ld1")
+        .MeterRegisters("m1")
+        .Custom("losses", |dss| Ok(vec![dss.ActiveCircuit.Losses()?.re]));
+    if let Some(hour) = start {
+        run = run.Resume(hour);
+    }
+    if let Some(stop_at) = stop_at {
+        run = run.StopWhen(move |record| record.step == stop_at);
+    }
+    run.Run()?.collect()
+}
+
+fn daily(dss: &IDSS) -> Result<(), DSSError> {
+    dss.Command(CIRCUIT)?;
+    let full = run(dss, None, 96, None)?;
+    assert_eq!(full.len(), 96);
+    assert!(full.iter().all(|record| record.converged));
+    assert!((full[0].hour - 0.25).abs() < 1e-9);
+    assert!((full[94].hour - 23.75).abs() < 1e-9);
+    assert_eq!(full[0].Names(), ["puVoltages(b1)", "Powers(Load.ld1)", "Registers(m1)", "losses"]);
+
+    // The load follows the load shape
+    let kW = |record: &StepRecord| record.Get("Powers(Load.ld1)").unwrap().AsComplex().unwrap().iter().map(|s| s.re).sum::<f64>();
+    assert!(kW(&full[3]) < kW(&full[67]));
+    let v = full[0].Get("puVoltages(b1)").unwrap().AsComplex().unwrap();
+    assert_eq!(v.len(), 3);
+    assert!(v.iter().all(|v| v.norm() > 0.9 && v.norm() < 1.1));
+    // Energy registers increase over the day
+    let kWh = |record: &StepRecord| record.Get("Registers(m1)").unwrap().AsReal().unwrap()[0];
+    assert!(kWh(&full[95]) > kWh(&full[0]));
+
+    // Stop early and resume from the last hour
+    dss.Command(CIRCUIT)?;
+    let first = run(dss, None, 96, Some(40))?;
+    assert_eq!(first.len(), 40);
+    let second = run(dss, Some(first[39].hour), 56, None)?;
+    assert_eq!(second.len(), 56);
+    for (a, b) in full.iter().zip(first.iter().chain(second.iter())) {
+        assert!((a.hour - b.hour).abs() < 1e-9);
+        assert!((kW(a) - kW(b)).abs() < 1e-6 * kW(a));
+        assert!((kWh(a) - kWh(b)).abs() < 1e-6 * kWh(a).abs().max(1.0));
+    }
+
+    // Unknown quantities end the iteration with an error
+    let mut steps = TimeSeriesRun::new(dss).BusVoltages("nonexistent").Run()?;
+    assert_eq!(steps.next().unwrap().unwrap_err().kind, DSSErrorKind::NotFound);
+    assert!(steps.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn timeseries_daily() {
+    let ctx = DSSContext::prime();
+    let dss = IDSS::new(&ctx);
+    daily(&dss).unwrap();
+}